@@ -0,0 +1,68 @@
+//! Account fetching and decoding.
+
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use std::fmt;
+
+/// Anything that can look up raw account data, such as an RPC client or a test bank.
+pub trait AccountSource {
+    type Error;
+
+    /// Returns the data of the account at `address`, or `None` if it does not exist.
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, Self::Error>;
+}
+
+#[derive(Debug)]
+pub enum FetchError<E> {
+    /// The account source failed.
+    Source(E),
+    /// No account exists at this address.
+    NotFound(Pubkey),
+    /// The account exists but could not be decoded as the requested type.
+    Decode(Pubkey, anchor_lang::error::Error),
+}
+
+impl<E: fmt::Display> fmt::Display for FetchError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Source(err) => write!(f, "account source error: {err}"),
+            FetchError::NotFound(address) => write!(f, "account {address} not found"),
+            FetchError::Decode(address, err) => write!(f, "cannot decode account {address}: {err}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for FetchError<E> {}
+
+/// Decodes account data, checking the account discriminator if the type has one.
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> anchor_lang::Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Fetches and decodes the account at `address`.
+pub fn fetch_account<T, S>(source: &S, address: &Pubkey) -> Result<T, FetchError<S::Error>>
+where
+    T: AccountDeserialize,
+    S: AccountSource + ?Sized,
+{
+    fetch_optional_account(source, address)?.ok_or(FetchError::NotFound(*address))
+}
+
+/// Fetches and decodes the account at `address`, returning `None` if it does not exist.
+pub fn fetch_optional_account<T, S>(
+    source: &S,
+    address: &Pubkey,
+) -> Result<Option<T>, FetchError<S::Error>>
+where
+    T: AccountDeserialize,
+    S: AccountSource + ?Sized,
+{
+    match source
+        .get_account_data(address)
+        .map_err(FetchError::Source)?
+    {
+        Some(data) => decode_account(&data)
+            .map(Some)
+            .map_err(|err| FetchError::Decode(*address, err)),
+        None => Ok(None),
+    }
+}
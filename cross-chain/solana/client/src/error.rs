@@ -0,0 +1,210 @@
+//! Decoding of custom program error codes.
+//!
+//! Anchor reports program errors as `InstructionError::Custom` codes. These helpers map the codes
+//! back to [TbtcError] and [WormholeGatewayError]. The variant lists below must be kept in sync
+//! with the programs' error enums, which the tests check against the programs' sources.
+
+use ::tbtc::error::TbtcError;
+use ::wormhole_gateway::error::WormholeGatewayError;
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::InstructionError};
+use std::fmt;
+
+const TBTC_ERRORS: &[TbtcError] = &[
+    TbtcError::IsNotAuthority,
+    TbtcError::IsNotPendingAuthority,
+    TbtcError::NoPendingAuthorityChange,
+    TbtcError::GuardianAlreadyExists,
+    TbtcError::GuardianNonexistent,
+    TbtcError::SignerNotGuardian,
+    TbtcError::GuardianInfoMismatch,
+    TbtcError::MinterAlreadyExists,
+    TbtcError::MinterNonexistent,
+    TbtcError::SignerNotMinter,
+    TbtcError::MinterAllowanceExceeded,
+    TbtcError::MinterRateLimitExceeded,
+    TbtcError::MinterBurnExceedsOutstanding,
+    TbtcError::MinterInfoMismatch,
+    TbtcError::MinterPaused,
+    TbtcError::IsPaused,
+    TbtcError::IsNotPaused,
+    TbtcError::PauseCooldown,
+    TbtcError::ZeroMaxPauseDuration,
+    TbtcError::IsNotDelegate,
+    TbtcError::InvalidMultisigThreshold,
+    TbtcError::TooManyMultisigSigners,
+    TbtcError::DuplicateMultisigSigner,
+    TbtcError::IsNotMultisigSigner,
+    TbtcError::ProposalAlreadyApproved,
+    TbtcError::ProposalStale,
+    TbtcError::ProposalThresholdNotMet,
+    TbtcError::TimelockRequired,
+    TbtcError::ScheduledChangeNotReady,
+    TbtcError::AccountNotMigratable,
+    TbtcError::MintBatchLengthMismatch,
+    TbtcError::MintCannotFreeze,
+    TbtcError::ZeroMaxFreezeDuration,
+    TbtcError::FreezeCooldown,
+    TbtcError::FreezeNotActive,
+];
+
+const WORMHOLE_GATEWAY_ERRORS: &[WormholeGatewayError] = &[
+    WormholeGatewayError::MintingLimitExceeded,
+    WormholeGatewayError::InboundLimitExceeded,
+    WormholeGatewayError::OutboundLimitExceeded,
+    WormholeGatewayError::IsNotAuthority,
+    WormholeGatewayError::IsNotPendingAuthority,
+    WormholeGatewayError::NoPendingAuthorityChange,
+    WormholeGatewayError::ZeroRecipient,
+    WormholeGatewayError::NotEnoughWrappedTbtc,
+    WormholeGatewayError::RecoveryExceedsSurplus,
+    WormholeGatewayError::TokenAccountNotRecoverable,
+    WormholeGatewayError::ZeroAmount,
+    WormholeGatewayError::TransferAmountTooSmall,
+    WormholeGatewayError::TransferAmountTooLarge,
+    WormholeGatewayError::AmountDoesNotCoverFee,
+    WormholeGatewayError::InvalidFeeBps,
+    WormholeGatewayError::InvalidTransferBounds,
+    WormholeGatewayError::GatewayDisabled,
+    WormholeGatewayError::GatewayNotRegistered,
+    WormholeGatewayError::TransferAlreadyRedeemed,
+    WormholeGatewayError::InvalidEthereumTbtc,
+    WormholeGatewayError::WrappedTbtcInCustody,
+    WormholeGatewayError::NoTbtcTransferred,
+    WormholeGatewayError::InvalidGatewayMessage,
+    WormholeGatewayError::RecipientZeroAddress,
+    WormholeGatewayError::MintedAmountUnderflow,
+    WormholeGatewayError::MintedAmountOverflow,
+    WormholeGatewayError::InvalidMultisigThreshold,
+    WormholeGatewayError::TooManyMultisigSigners,
+    WormholeGatewayError::DuplicateMultisigSigner,
+    WormholeGatewayError::IsNotMultisigSigner,
+    WormholeGatewayError::ProposalAlreadyApproved,
+    WormholeGatewayError::ProposalStale,
+    WormholeGatewayError::ProposalThresholdNotMet,
+    WormholeGatewayError::TimelockRequired,
+    WormholeGatewayError::ScheduledChangeNotReady,
+    WormholeGatewayError::AccountNotMigratable,
+    WormholeGatewayError::GuardianInfoMismatch,
+    WormholeGatewayError::InboundDisabled,
+    WormholeGatewayError::OutboundDisabled,
+    WormholeGatewayError::IsNotGatewayGuardian,
+    WormholeGatewayError::GatewayModeNotRestricted,
+    WormholeGatewayError::CallAccountsMismatch,
+];
+
+/// Error returned by one of the programs.
+#[derive(Debug, Clone, Copy)]
+pub enum ProgramError {
+    Tbtc(TbtcError),
+    WormholeGateway(WormholeGatewayError),
+}
+
+impl fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramError::Tbtc(err) => write!(f, "tbtc: {err}"),
+            ProgramError::WormholeGateway(err) => write!(f, "wormhole gateway: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ProgramError {}
+
+/// Maps a custom error code returned by the tBTC program to its error.
+pub fn decode_tbtc_error(code: u32) -> Option<TbtcError> {
+    TBTC_ERRORS
+        .iter()
+        .copied()
+        .find(|&err| u32::from(err) == code)
+}
+
+/// Maps a custom error code returned by the Wormhole Gateway program to its error.
+pub fn decode_wormhole_gateway_error(code: u32) -> Option<WormholeGatewayError> {
+    WORMHOLE_GATEWAY_ERRORS
+        .iter()
+        .copied()
+        .find(|&err| u32::from(err) == code)
+}
+
+/// Maps a custom error code to the error of the program that returned it. Codes from other
+/// programs, and Anchor's own framework errors, are not decoded.
+pub fn decode_error(program_id: &Pubkey, code: u32) -> Option<ProgramError> {
+    if *program_id == ::tbtc::ID {
+        decode_tbtc_error(code).map(ProgramError::Tbtc)
+    } else if *program_id == ::wormhole_gateway::ID {
+        decode_wormhole_gateway_error(code).map(ProgramError::WormholeGateway)
+    } else {
+        None
+    }
+}
+
+/// Like [decode_error], but takes the instruction error from a failed transaction.
+pub fn decode_instruction_error(
+    program_id: &Pubkey,
+    err: &InstructionError,
+) -> Option<ProgramError> {
+    match err {
+        InstructionError::Custom(code) => decode_error(program_id, *code),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Variant names and codes declared in a program's error enum, in order.
+    fn declared_errors(source: &str) -> Vec<(String, u32)> {
+        source
+            .lines()
+            .filter_map(|line| line.trim().strip_suffix(','))
+            .filter_map(|line| line.split_once(" = 0x"))
+            .map(|(name, code)| {
+                (
+                    name.to_string(),
+                    u32::from_str_radix(code, 16).unwrap() + anchor_lang::error::ERROR_CODE_OFFSET,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tbtc_errors_match_program() {
+        let declared = declared_errors(include_str!("../../programs/tbtc/src/error.rs"));
+        let listed: Vec<_> = TBTC_ERRORS
+            .iter()
+            .map(|&err| (err.name(), u32::from(err)))
+            .collect();
+
+        assert_eq!(listed, declared);
+    }
+
+    #[test]
+    fn wormhole_gateway_errors_match_program() {
+        let declared =
+            declared_errors(include_str!("../../programs/wormhole-gateway/src/error.rs"));
+        let listed: Vec<_> = WORMHOLE_GATEWAY_ERRORS
+            .iter()
+            .map(|&err| (err.name(), u32::from(err)))
+            .collect();
+
+        assert_eq!(listed, declared);
+    }
+
+    #[test]
+    fn decodes_by_program() {
+        let code = u32::from(TbtcError::IsNotAuthority);
+
+        assert!(matches!(
+            decode_error(&::tbtc::ID, code),
+            Some(ProgramError::Tbtc(TbtcError::IsNotAuthority))
+        ));
+        assert!(matches!(
+            decode_error(&::wormhole_gateway::ID, code),
+            Some(ProgramError::WormholeGateway(
+                WormholeGatewayError::IsNotAuthority
+            ))
+        ));
+        assert!(decode_error(&Pubkey::new_unique(), code).is_none());
+    }
+}
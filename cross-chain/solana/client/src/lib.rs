@@ -0,0 +1,20 @@
+//! Rust client for the tBTC and Wormhole Gateway programs.
+//!
+//! Each program module derives the program's PDAs, builds its instructions and fetches its
+//! accounts. The [wormhole] module derives the Token Bridge and Core Bridge accounts the gateway
+//! passes through, and [error] maps custom error codes back to the program error enums.
+//!
+//! Fetching is generic over an [AccountSource], so this crate does not pick an RPC client for you.
+
+#![allow(clippy::result_large_err)]
+
+pub mod account;
+pub use account::{AccountSource, FetchError};
+
+pub mod error;
+
+pub mod tbtc;
+
+pub mod wormhole;
+
+pub mod wormhole_gateway;
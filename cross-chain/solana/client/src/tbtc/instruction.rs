@@ -0,0 +1,774 @@
+//! Instruction builders for the tBTC program.
+//!
+//! Builders take the signers and the accounts that cannot be derived, and fill in every PDA and
+//! program account. Instructions an authorized role holder may call instead of the authority take
+//! an optional `role_assignment`, which can be derived with [pda::role_assignment].
+
+use super::pda;
+use ::tbtc::{
+    accounts, instruction as data, InitializeMultisigArgs, ProposalAction, Role, TimelockedAction,
+    UpdateMetadataArgs, UpdateMinterLimitsArgs, UpdatePauseParamsArgs,
+};
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::{instruction::Instruction, system_program, sysvar},
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{associated_token, token};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ::tbtc::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn build_with_remaining(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    let mut ix = build(accounts, data);
+    ix.accounts.extend_from_slice(remaining_accounts);
+    ix
+}
+
+pub fn initialize(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::Initialize {
+            mint: pda::mint(),
+            config: pda::config(),
+            authority: *authority,
+            tbtc_metadata: pda::metadata(),
+            rent: sysvar::rent::ID,
+            mpl_token_metadata_program: mpl_token_metadata::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        data::Initialize {},
+    )
+}
+
+pub fn migrate_config(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateConfig {
+            config: pda::config(),
+            authority: *authority,
+            mint: pda::mint(),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::MigrateConfig {},
+    )
+}
+
+pub fn change_authority(authority: &Pubkey, new_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::ChangeAuthority {
+            config: pda::config(),
+            authority: *authority,
+            new_authority: *new_authority,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ChangeAuthority {},
+    )
+}
+
+pub fn cancel_authority_change(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelAuthorityChange {
+            config: pda::config(),
+            authority: *authority,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::CancelAuthorityChange {},
+    )
+}
+
+pub fn take_authority(pending_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::TakeAuthority {
+            config: pda::config(),
+            pending_authority: *pending_authority,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::TakeAuthority {},
+    )
+}
+
+pub fn grant_role(authority: &Pubkey, role: Role, holder: &Pubkey) -> Instruction {
+    build(
+        accounts::GrantRole {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment: pda::role_assignment(role, holder),
+            holder: *holder,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::GrantRole { role },
+    )
+}
+
+pub fn revoke_role(authority: &Pubkey, role: Role, holder: &Pubkey) -> Instruction {
+    build(
+        accounts::RevokeRole {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment: pda::role_assignment(role, holder),
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::RevokeRole {},
+    )
+}
+
+pub fn initialize_multisig(authority: &Pubkey, args: InitializeMultisigArgs) -> Instruction {
+    build(
+        accounts::InitializeMultisig {
+            config: pda::config(),
+            authority: *authority,
+            multisig: pda::multisig(),
+            multisig_authority: pda::multisig_authority(),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::InitializeMultisig { args },
+    )
+}
+
+/// Creates proposal `index`, which must be the multisig's current `num_proposals`.
+pub fn create_proposal(proposer: &Pubkey, index: u64, action: ProposalAction) -> Instruction {
+    build(
+        accounts::CreateProposal {
+            multisig: pda::multisig(),
+            proposal: pda::proposal(index),
+            proposer: *proposer,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::CreateProposal { action },
+    )
+}
+
+pub fn approve_proposal(signer: &Pubkey, index: u64) -> Instruction {
+    build(
+        accounts::ApproveProposal {
+            multisig: pda::multisig(),
+            proposal: pda::proposal(index),
+            signer: *signer,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ApproveProposal {},
+    )
+}
+
+/// Executes proposal `index`. The remaining accounts are the accounts of the instruction the
+/// proposal carries out, in order.
+pub fn execute_proposal(
+    proposer: &Pubkey,
+    index: u64,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts::ExecuteProposal {
+            multisig: pda::multisig(),
+            proposal: pda::proposal(index),
+            proposer: *proposer,
+            multisig_authority: pda::multisig_authority(),
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ExecuteProposal {},
+        remaining_accounts,
+    )
+}
+
+pub fn update_timelock_delay(authority: &Pubkey, delay: u32) -> Instruction {
+    build(
+        accounts::UpdateTimelockDelay {
+            config: pda::config(),
+            authority: *authority,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::UpdateTimelockDelay { delay },
+    )
+}
+
+/// Schedules change `index`, which must be the config's current `num_scheduled_changes`.
+pub fn schedule_change(authority: &Pubkey, index: u64, action: TimelockedAction) -> Instruction {
+    build(
+        accounts::ScheduleChange {
+            config: pda::config(),
+            scheduled_change: pda::scheduled_change(index),
+            authority: *authority,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ScheduleChange { action },
+    )
+}
+
+pub fn cancel_scheduled_change(
+    guardian: &Pubkey,
+    index: u64,
+    scheduled_by: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CancelScheduledChange {
+            guardian_info: pda::guardian_info(guardian),
+            guardian: *guardian,
+            scheduled_change: pda::scheduled_change(index),
+            scheduled_by: *scheduled_by,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::CancelScheduledChange {},
+    )
+}
+
+/// Executes scheduled change `index`. The remaining accounts are the accounts of the instruction
+/// the change carries out, in order.
+pub fn execute_scheduled_change(
+    index: u64,
+    scheduled_by: &Pubkey,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts::ExecuteScheduledChange {
+            scheduled_change: pda::scheduled_change(index),
+            scheduled_by: *scheduled_by,
+            timelock_authority: pda::timelock_authority(),
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ExecuteScheduledChange {},
+        remaining_accounts,
+    )
+}
+
+/// Adds a minter to registry page `page`, which must be the config's `next_minter_page()`.
+pub fn add_minter(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    minter: &Pubkey,
+    page: u32,
+) -> Instruction {
+    build(
+        accounts::AddMinter {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            minters_page: pda::minters_page(page),
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            system_program: system_program::ID,
+        },
+        data::AddMinter {},
+    )
+}
+
+/// Removes a minter from registry page `page`, which is the minter info's `registry_page`. Pass
+/// `None` to remove a minter that is still in the legacy minters account.
+pub fn remove_minter(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    minter: &Pubkey,
+    page: Option<u32>,
+) -> Instruction {
+    build(
+        accounts::RemoveMinter {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            minters_page: page.map(pda::minters_page),
+            minters: page.is_none().then(pda::minters),
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            system_program: system_program::ID,
+        },
+        data::RemoveMinter {},
+    )
+}
+
+/// Upgrades a minter info, opening it with `minted_total` outstanding.
+pub fn migrate_minter_info(authority: &Pubkey, minter: &Pubkey, minted_total: u64) -> Instruction {
+    build(
+        accounts::MigrateMinterInfo {
+            config: pda::config(),
+            authority: *authority,
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::MigrateMinterInfo { minted_total },
+    )
+}
+
+/// Moves minters from the legacy registry into page `page`, which must be the config's
+/// `next_minter_page()`. The remaining accounts are the writable minter infos of the minters being
+/// moved, in registry order.
+pub fn migrate_minters(
+    authority: &Pubkey,
+    page: u32,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts::MigrateMinters {
+            config: pda::config(),
+            authority: *authority,
+            minters: pda::minters(),
+            minters_page: pda::minters_page(page),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::MigrateMinters {},
+        remaining_accounts,
+    )
+}
+
+pub fn update_metadata(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    args: UpdateMetadataArgs,
+) -> Instruction {
+    build(
+        accounts::UpdateMetadata {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            tbtc_metadata: pda::metadata(),
+            mpl_token_metadata_program: mpl_token_metadata::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::UpdateMetadata { args },
+    )
+}
+
+pub fn update_minter_limits(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    minter: &Pubkey,
+    args: UpdateMinterLimitsArgs,
+) -> Instruction {
+    build(
+        accounts::UpdateMinterLimits {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::UpdateMinterLimits { args },
+    )
+}
+
+/// Adds a guardian to registry page `page`, which must be the config's `next_guardian_page()`.
+pub fn add_guardian(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    guardian: &Pubkey,
+    page: u32,
+) -> Instruction {
+    build(
+        accounts::AddGuardian {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            guardians_page: pda::guardians_page(page),
+            guardian_info: pda::guardian_info(guardian),
+            guardian: *guardian,
+            system_program: system_program::ID,
+        },
+        data::AddGuardian {},
+    )
+}
+
+/// Removes a guardian from registry page `page`, which is the guardian info's `registry_page`. Pass
+/// `None` to remove a guardian that is still in the legacy guardians account.
+pub fn remove_guardian(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    guardian: &Pubkey,
+    page: Option<u32>,
+) -> Instruction {
+    build(
+        accounts::RemoveGuardian {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            guardians_page: page.map(pda::guardians_page),
+            guardians: page.is_none().then(pda::guardians),
+            guardian_info: pda::guardian_info(guardian),
+            guardian: *guardian,
+            system_program: system_program::ID,
+        },
+        data::RemoveGuardian {},
+    )
+}
+
+pub fn migrate_guardian_info(authority: &Pubkey, guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateGuardianInfo {
+            config: pda::config(),
+            authority: *authority,
+            guardian_info: pda::guardian_info(guardian),
+            guardian: *guardian,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::MigrateGuardianInfo {},
+    )
+}
+
+/// Moves guardians from the legacy registry into page `page`, which must be the config's
+/// `next_guardian_page()`. The remaining accounts are the writable guardian infos of the guardians
+/// being moved, in registry order.
+pub fn migrate_guardians(
+    authority: &Pubkey,
+    page: u32,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts::MigrateGuardians {
+            config: pda::config(),
+            authority: *authority,
+            guardians: pda::guardians(),
+            guardians_page: pda::guardians_page(page),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::MigrateGuardians {},
+        remaining_accounts,
+    )
+}
+
+pub fn pause(guardian: &Pubkey, reason: u8) -> Instruction {
+    build(
+        accounts::Pause {
+            config: pda::config(),
+            guardian_info: pda::guardian_info(guardian),
+            guardian: *guardian,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::Pause { reason },
+    )
+}
+
+pub fn unpause(authority: &Pubkey, role_assignment: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::Unpause {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::Unpause {},
+    )
+}
+
+pub fn pause_minter(guardian: &Pubkey, minter: &Pubkey) -> Instruction {
+    build(
+        accounts::PauseMinter {
+            guardian_info: pda::guardian_info(guardian),
+            guardian: *guardian,
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::PauseMinter {},
+    )
+}
+
+pub fn unpause_minter(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    minter: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::UnpauseMinter {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::UnpauseMinter {},
+    )
+}
+
+pub fn extend_pause(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    extension: u32,
+) -> Instruction {
+    build(
+        accounts::ExtendPause {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ExtendPause { extension },
+    )
+}
+
+pub fn update_pause_params(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    args: UpdatePauseParamsArgs,
+) -> Instruction {
+    build(
+        accounts::UpdatePauseParams {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::UpdatePauseParams { args },
+    )
+}
+
+pub fn freeze_account(guardian: &Pubkey, token: &Pubkey) -> Instruction {
+    build(
+        accounts::FreezeAccount {
+            config: pda::config(),
+            mint: pda::mint(),
+            guardian_info: pda::guardian_info(guardian),
+            guardian: *guardian,
+            token: *token,
+            frozen_account: pda::frozen_account(token),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::FreezeAccount {},
+    )
+}
+
+pub fn thaw_account(authority: &Pubkey, token: &Pubkey) -> Instruction {
+    build(
+        accounts::ThawAccount {
+            config: pda::config(),
+            mint: pda::mint(),
+            authority: *authority,
+            token: *token,
+            frozen_account: pda::frozen_account(token),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ThawAccount {},
+    )
+}
+
+pub fn extend_freeze(authority: &Pubkey, token: &Pubkey, extension: u32) -> Instruction {
+    build(
+        accounts::ExtendFreeze {
+            config: pda::config(),
+            authority: *authority,
+            token: *token,
+            frozen_account: pda::frozen_account(token),
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::ExtendFreeze { extension },
+    )
+}
+
+pub fn update_max_freeze_duration(
+    authority: &Pubkey,
+    role_assignment: Option<Pubkey>,
+    max_freeze_duration: u32,
+) -> Instruction {
+    build(
+        accounts::UpdateMaxFreezeDuration {
+            config: pda::config(),
+            authority: *authority,
+            role_assignment,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::UpdateMaxFreezeDuration {
+            max_freeze_duration,
+        },
+    )
+}
+
+pub fn recover_tokens(
+    authority: &Pubkey,
+    source_token: &Pubkey,
+    recipient_token: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::RecoverTokens {
+            config: pda::config(),
+            authority: *authority,
+            source_token: *source_token,
+            recipient_token: *recipient_token,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::RecoverTokens { amount },
+    )
+}
+
+pub fn mint(minter: &Pubkey, recipient_token: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::Mint {
+            mint: pda::mint(),
+            config: pda::config(),
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            recipient_token: *recipient_token,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::Mint { amount },
+    )
+}
+
+/// Mints `amounts[i]` to `recipient_tokens[i]`. The two slices must have the same length.
+pub fn mint_batch(minter: &Pubkey, recipient_tokens: &[Pubkey], amounts: Vec<u64>) -> Instruction {
+    let remaining_accounts = recipient_tokens
+        .iter()
+        .map(|token| AccountMeta::new(*token, false))
+        .collect::<Vec<_>>();
+
+    build_with_remaining(
+        accounts::MintBatch {
+            mint: pda::mint(),
+            config: pda::config(),
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::MintBatch { amounts },
+        &remaining_accounts,
+    )
+}
+
+/// Mints to the recipient's associated token account, which `payer` creates if it does not exist.
+pub fn mint_to_wallet(
+    minter: &Pubkey,
+    payer: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::MintToWallet {
+            mint: pda::mint(),
+            config: pda::config(),
+            minter_info: pda::minter_info(minter),
+            minter: *minter,
+            payer: *payer,
+            recipient: *recipient,
+            recipient_token: associated_token::get_associated_token_address(
+                recipient,
+                &pda::mint(),
+            ),
+            associated_token_program: associated_token::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::MintToWallet { amount },
+    )
+}
+
+/// Burns from the owner's token account. If `minter` is given, the burn counts against that
+/// minter's outstanding amount and the minter must sign.
+pub fn burn(
+    owner: &Pubkey,
+    owner_token: &Pubkey,
+    minter: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::Burn {
+            mint: pda::mint(),
+            config: pda::config(),
+            owner_token: *owner_token,
+            owner: *owner,
+            minter_info: minter.map(pda::minter_info),
+            minter: minter.copied(),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::Burn { amount },
+    )
+}
+
+/// Burns from a token account the spender is a delegate of. If `minter` is given, the burn counts
+/// against that minter's outstanding amount and the minter must sign.
+pub fn burn_from(
+    spender: &Pubkey,
+    from_token: &Pubkey,
+    minter: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::BurnFrom {
+            mint: pda::mint(),
+            config: pda::config(),
+            from_token: *from_token,
+            spender: *spender,
+            minter_info: minter.map(pda::minter_info),
+            minter: minter.copied(),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::BurnFrom { amount },
+    )
+}
+
+/// Compares the mint supply with the minted and burned totals. If a guardian is given and signs, a
+/// discrepancy also pauses the program.
+pub fn reconcile(guardian: Option<&Pubkey>) -> Instruction {
+    build(
+        accounts::Reconcile {
+            config: pda::config(),
+            mint: pda::mint(),
+            guardian_info: guardian.map(pda::guardian_info),
+            guardian: guardian.copied(),
+            event_authority: pda::event_authority(),
+            program: ::tbtc::ID,
+        },
+        data::Reconcile {},
+    )
+}
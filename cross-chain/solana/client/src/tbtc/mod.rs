@@ -0,0 +1,85 @@
+//! Client for the tBTC program.
+
+pub mod instruction;
+
+pub mod pda;
+
+use crate::account::{fetch_account, fetch_optional_account, AccountSource, FetchError};
+use ::tbtc::{
+    Config, FrozenAccount, GuardianInfo, Guardians, GuardiansPage, MinterInfo, Minters, MintersPage,
+};
+use anchor_lang::prelude::Pubkey;
+
+pub use ::tbtc::ID as PROGRAM_ID;
+
+pub fn fetch_config<S>(source: &S) -> Result<Config, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_account(source, &pda::config())
+}
+
+pub fn fetch_minter_info<S>(source: &S, minter: &Pubkey) -> Result<MinterInfo, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_account(source, &pda::minter_info(minter))
+}
+
+pub fn fetch_guardian_info<S>(
+    source: &S,
+    guardian: &Pubkey,
+) -> Result<GuardianInfo, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_account(source, &pda::guardian_info(guardian))
+}
+
+pub fn fetch_frozen_account<S>(
+    source: &S,
+    token: &Pubkey,
+) -> Result<FrozenAccount, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_account(source, &pda::frozen_account(token))
+}
+
+/// Collects the minters from every page of the minter registry, followed by those still in the
+/// legacy minters account.
+pub fn fetch_minters<S>(source: &S, config: &Config) -> Result<Vec<Pubkey>, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    let num_pages = config.minter_registry_len.div_ceil(MintersPage::MAX_KEYS);
+    let mut minters = Vec::with_capacity(config.num_minters as usize);
+    for index in 0..num_pages {
+        let page: MintersPage = fetch_account(source, &pda::minters_page(index))?;
+        minters.extend(page.keys);
+    }
+    if let Some(legacy) = fetch_optional_account::<Minters, _>(source, &pda::minters())? {
+        minters.extend(legacy.keys);
+    }
+    Ok(minters)
+}
+
+/// Collects the guardians from every page of the guardian registry, followed by those still in the
+/// legacy guardians account.
+pub fn fetch_guardians<S>(source: &S, config: &Config) -> Result<Vec<Pubkey>, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    let num_pages = config
+        .guardian_registry_len
+        .div_ceil(GuardiansPage::MAX_KEYS);
+    let mut guardians = Vec::with_capacity(config.num_guardians as usize);
+    for index in 0..num_pages {
+        let page: GuardiansPage = fetch_account(source, &pda::guardians_page(index))?;
+        guardians.extend(page.keys);
+    }
+    if let Some(legacy) = fetch_optional_account::<Guardians, _>(source, &pda::guardians())? {
+        guardians.extend(legacy.keys);
+    }
+    Ok(guardians)
+}
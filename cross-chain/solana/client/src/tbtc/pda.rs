@@ -0,0 +1,160 @@
+//! PDAs of the tBTC program.
+
+use ::tbtc::{
+    Config, FrozenAccount, GuardianInfo, Guardians, GuardiansPage, MinterInfo, Minters,
+    MintersPage, Multisig, Proposal, Role, RoleAssignment, ScheduledChange, SEED_PREFIX_TBTC_MINT,
+};
+use anchor_lang::prelude::Pubkey;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &::tbtc::ID).0
+}
+
+pub fn config() -> Pubkey {
+    find(&[Config::SEED_PREFIX])
+}
+
+pub fn mint() -> Pubkey {
+    find(&[SEED_PREFIX_TBTC_MINT])
+}
+
+/// Metaplex metadata account of the tBTC mint.
+pub fn metadata() -> Pubkey {
+    mpl_token_metadata::pda::find_metadata_account(&mint()).0
+}
+
+pub fn minter_info(minter: &Pubkey) -> Pubkey {
+    find(&[MinterInfo::SEED_PREFIX, minter.as_ref()])
+}
+
+pub fn minters_page(index: u32) -> Pubkey {
+    find(&[MintersPage::SEED_PREFIX, &index.to_le_bytes()])
+}
+
+/// Legacy minter registry, replaced by the minters pages.
+pub fn minters() -> Pubkey {
+    find(&[Minters::SEED_PREFIX])
+}
+
+pub fn guardian_info(guardian: &Pubkey) -> Pubkey {
+    find(&[GuardianInfo::SEED_PREFIX, guardian.as_ref()])
+}
+
+pub fn guardians_page(index: u32) -> Pubkey {
+    find(&[GuardiansPage::SEED_PREFIX, &index.to_le_bytes()])
+}
+
+/// Legacy guardian registry, replaced by the guardians pages.
+pub fn guardians() -> Pubkey {
+    find(&[Guardians::SEED_PREFIX])
+}
+
+pub fn role_assignment(role: Role, holder: &Pubkey) -> Pubkey {
+    find(&[RoleAssignment::SEED_PREFIX, &[role as u8], holder.as_ref()])
+}
+
+pub fn frozen_account(token: &Pubkey) -> Pubkey {
+    find(&[FrozenAccount::SEED_PREFIX, token.as_ref()])
+}
+
+pub fn multisig() -> Pubkey {
+    find(&[Multisig::SEED_PREFIX])
+}
+
+pub fn multisig_authority() -> Pubkey {
+    find(&[Multisig::AUTHORITY_SEED_PREFIX])
+}
+
+pub fn proposal(index: u64) -> Pubkey {
+    find(&[Proposal::SEED_PREFIX, &index.to_le_bytes()])
+}
+
+pub fn timelock_authority() -> Pubkey {
+    ScheduledChange::authority_address()
+}
+
+pub fn scheduled_change(index: u64) -> Pubkey {
+    find(&[ScheduledChange::SEED_PREFIX, &index.to_le_bytes()])
+}
+
+/// Authority the program signs with when emitting events through self-CPI.
+pub fn event_authority() -> Pubkey {
+    find(&[b"__event_authority"])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &::tbtc::ID).0
+    }
+
+    #[test]
+    fn singleton_pdas_use_program_seeds() {
+        assert_eq!(config(), expected(&[Config::SEED_PREFIX]));
+        assert_eq!(mint(), expected(&[SEED_PREFIX_TBTC_MINT]));
+        assert_eq!(minters(), expected(&[Minters::SEED_PREFIX]));
+        assert_eq!(guardians(), expected(&[Guardians::SEED_PREFIX]));
+        assert_eq!(multisig(), expected(&[Multisig::SEED_PREFIX]));
+        assert_eq!(
+            multisig_authority(),
+            expected(&[Multisig::AUTHORITY_SEED_PREFIX])
+        );
+        assert_eq!(
+            timelock_authority(),
+            expected(&[ScheduledChange::AUTHORITY_SEED_PREFIX])
+        );
+        assert_eq!(event_authority(), expected(&[b"__event_authority"]));
+    }
+
+    #[test]
+    fn keyed_pdas_use_program_seeds() {
+        let key = Pubkey::new_unique();
+
+        assert_eq!(
+            minter_info(&key),
+            expected(&[MinterInfo::SEED_PREFIX, key.as_ref()])
+        );
+        assert_eq!(
+            guardian_info(&key),
+            expected(&[GuardianInfo::SEED_PREFIX, key.as_ref()])
+        );
+        assert_eq!(
+            frozen_account(&key),
+            expected(&[FrozenAccount::SEED_PREFIX, key.as_ref()])
+        );
+        assert_eq!(
+            role_assignment(Role::MinterAdmin, &key),
+            expected(&[
+                RoleAssignment::SEED_PREFIX,
+                &[Role::MinterAdmin as u8],
+                key.as_ref()
+            ])
+        );
+        assert_eq!(
+            metadata(),
+            mpl_token_metadata::pda::find_metadata_account(&expected(&[SEED_PREFIX_TBTC_MINT])).0
+        );
+    }
+
+    #[test]
+    fn indexed_pdas_use_little_endian_indices() {
+        assert_eq!(
+            minters_page(1),
+            expected(&[MintersPage::SEED_PREFIX, &[1, 0, 0, 0]])
+        );
+        assert_eq!(
+            guardians_page(1),
+            expected(&[GuardiansPage::SEED_PREFIX, &[1, 0, 0, 0]])
+        );
+        assert_eq!(
+            proposal(1),
+            expected(&[Proposal::SEED_PREFIX, &[1, 0, 0, 0, 0, 0, 0, 0]])
+        );
+        assert_eq!(
+            scheduled_change(1),
+            expected(&[ScheduledChange::SEED_PREFIX, &[1, 0, 0, 0, 0, 0, 0, 0]])
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! PDAs of the Wormhole Token Bridge and Core Bridge programs used by the gateway.
+
+pub mod token_bridge {
+    use anchor_lang::prelude::Pubkey;
+    use wormhole_anchor_sdk::token_bridge as sdk;
+
+    fn find(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &sdk::program::ID).0
+    }
+
+    pub fn config() -> Pubkey {
+        find(&[sdk::Config::SEED_PREFIX])
+    }
+
+    pub fn authority_signer() -> Pubkey {
+        find(&[sdk::SEED_PREFIX_AUTHORITY_SIGNER])
+    }
+
+    pub fn custody_signer() -> Pubkey {
+        find(&[sdk::SEED_PREFIX_CUSTODY_SIGNER])
+    }
+
+    pub fn mint_authority() -> Pubkey {
+        find(&[sdk::SEED_PREFIX_MINT_AUTHORITY])
+    }
+
+    /// Emitter of the Token Bridge's Core Bridge messages.
+    pub fn emitter() -> Pubkey {
+        find(&[sdk::SEED_PREFIX_EMITTER])
+    }
+
+    /// Mint the Token Bridge uses for a token native to another chain. Note that the chain is
+    /// encoded big-endian here.
+    pub fn wrapped_mint(token_chain: u16, token_address: &[u8; 32]) -> Pubkey {
+        find(&[
+            sdk::WrappedMint::SEED_PREFIX,
+            &token_chain.to_be_bytes(),
+            token_address,
+        ])
+    }
+
+    pub fn wrapped_meta(wrapped_mint: &Pubkey) -> Pubkey {
+        find(&[sdk::WrappedMeta::SEED_PREFIX, wrapped_mint.as_ref()])
+    }
+
+    /// Registration of a foreign Token Bridge emitter.
+    pub fn endpoint(emitter_chain: u16, emitter_address: &[u8; 32]) -> Pubkey {
+        find(&[&emitter_chain.to_be_bytes(), emitter_address])
+    }
+
+    /// Claim created when the transfer with this emitter and sequence is redeemed.
+    pub fn claim(emitter_chain: u16, emitter_address: &[u8; 32], sequence: u64) -> Pubkey {
+        find(&[
+            emitter_address,
+            &emitter_chain.to_be_bytes(),
+            &sequence.to_be_bytes(),
+        ])
+    }
+}
+
+pub mod core_bridge {
+    use anchor_lang::prelude::Pubkey;
+    use wormhole_anchor_sdk::wormhole as sdk;
+
+    fn find(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &sdk::program::ID).0
+    }
+
+    pub fn bridge_data() -> Pubkey {
+        find(&[sdk::BridgeData::SEED_PREFIX])
+    }
+
+    pub fn fee_collector() -> Pubkey {
+        find(&[sdk::FeeCollector::SEED_PREFIX])
+    }
+
+    pub fn sequence_tracker(emitter: &Pubkey) -> Pubkey {
+        find(&[sdk::SequenceTracker::SEED_PREFIX, emitter.as_ref()])
+    }
+
+    pub fn posted_vaa(message_hash: &[u8; 32]) -> Pubkey {
+        find(&[sdk::SEED_PREFIX_POSTED_VAA, message_hash])
+    }
+}
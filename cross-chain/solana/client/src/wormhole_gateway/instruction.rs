@@ -0,0 +1,646 @@
+//! Instruction builders for the Wormhole Gateway program.
+//!
+//! Builders take the signers and the accounts that cannot be derived, and fill in every PDA and
+//! program account, including the tBTC, Token Bridge and Core Bridge accounts passed through to
+//! those programs. Builders that touch wrapped tBTC take the fetched [Custodian], which records
+//! the current wrapped tBTC mint and custody account.
+
+use super::pda;
+use crate::{tbtc::pda as tbtc_pda, wormhole};
+use ::wormhole_gateway::{
+    accounts, instruction as data, Custodian, GatewayMode, InitializeArgs, InitializeMultisigArgs,
+    ProposalAction, SendTbtcGatewayArgs, SendTbtcWrappedArgs, TimelockedAction,
+    UpdateGatewayAddressArgs, UpdateGatewayFeesArgs, UpdateGatewayLimitsArgs,
+    UpdateInboundLimitArgs, UpdateTbtcTokenArgs,
+};
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::{instruction::Instruction, system_program, sysvar},
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{associated_token, token};
+use wormhole_anchor_sdk::{token_bridge, wormhole as core_bridge};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ::wormhole_gateway::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn build_with_remaining(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    let mut ix = build(accounts, data);
+    ix.accounts.extend_from_slice(remaining_accounts);
+    ix
+}
+
+pub fn initialize(authority: &Pubkey, args: InitializeArgs) -> Instruction {
+    build(
+        accounts::Initialize {
+            authority: *authority,
+            custodian: pda::custodian(),
+            tbtc_mint: tbtc_pda::mint(),
+            wrapped_tbtc_mint: wormhole::token_bridge::wrapped_mint(
+                args.tbtc_token_chain,
+                &args.tbtc_token_address,
+            ),
+            wrapped_tbtc_token: pda::wrapped_tbtc_token(),
+            token_bridge_sender: pda::token_bridge_sender(),
+            system_program: system_program::ID,
+            token_program: token::ID,
+        },
+        data::Initialize { args },
+    )
+}
+
+/// Migrates a legacy custodian. `wrapped_tbtc_mint` is the mint recorded in the legacy custodian.
+pub fn migrate_custodian(authority: &Pubkey, wrapped_tbtc_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateCustodian {
+            custodian: pda::custodian(),
+            authority: *authority,
+            token_bridge_wrapped_asset: wormhole::token_bridge::wrapped_meta(wrapped_tbtc_mint),
+            system_program: system_program::ID,
+        },
+        data::MigrateCustodian {},
+    )
+}
+
+pub fn change_authority(authority: &Pubkey, new_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::ChangeAuthority {
+            custodian: pda::custodian(),
+            authority: *authority,
+            new_authority: *new_authority,
+        },
+        data::ChangeAuthority {},
+    )
+}
+
+pub fn cancel_authority_change(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelAuthorityChange {
+            custodian: pda::custodian(),
+            authority: *authority,
+        },
+        data::CancelAuthorityChange {},
+    )
+}
+
+pub fn take_authority(pending_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::TakeAuthority {
+            custodian: pda::custodian(),
+            pending_authority: *pending_authority,
+        },
+        data::TakeAuthority {},
+    )
+}
+
+pub fn initialize_multisig(authority: &Pubkey, args: InitializeMultisigArgs) -> Instruction {
+    build(
+        accounts::InitializeMultisig {
+            custodian: pda::custodian(),
+            authority: *authority,
+            multisig: pda::multisig(),
+            multisig_authority: pda::multisig_authority(),
+            system_program: system_program::ID,
+        },
+        data::InitializeMultisig { args },
+    )
+}
+
+/// Creates proposal `index`, which must be the multisig's current `num_proposals`.
+pub fn create_proposal(proposer: &Pubkey, index: u64, action: ProposalAction) -> Instruction {
+    build(
+        accounts::CreateProposal {
+            multisig: pda::multisig(),
+            proposal: pda::proposal(index),
+            proposer: *proposer,
+            system_program: system_program::ID,
+        },
+        data::CreateProposal { action },
+    )
+}
+
+pub fn approve_proposal(signer: &Pubkey, index: u64) -> Instruction {
+    build(
+        accounts::ApproveProposal {
+            multisig: pda::multisig(),
+            proposal: pda::proposal(index),
+            signer: *signer,
+        },
+        data::ApproveProposal {},
+    )
+}
+
+/// Executes proposal `index`. The remaining accounts are the accounts of the instruction the
+/// proposal carries out, in order.
+pub fn execute_proposal(
+    proposer: &Pubkey,
+    index: u64,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts::ExecuteProposal {
+            multisig: pda::multisig(),
+            proposal: pda::proposal(index),
+            proposer: *proposer,
+            multisig_authority: pda::multisig_authority(),
+            program: ::wormhole_gateway::ID,
+        },
+        data::ExecuteProposal {},
+        remaining_accounts,
+    )
+}
+
+pub fn update_timelock_delay(authority: &Pubkey, delay: u32) -> Instruction {
+    build(
+        accounts::UpdateTimelockDelay {
+            custodian: pda::custodian(),
+            authority: *authority,
+        },
+        data::UpdateTimelockDelay { delay },
+    )
+}
+
+/// Schedules change `index`, which must be the custodian's current `num_scheduled_changes`.
+pub fn schedule_change(authority: &Pubkey, index: u64, action: TimelockedAction) -> Instruction {
+    build(
+        accounts::ScheduleChange {
+            custodian: pda::custodian(),
+            scheduled_change: pda::scheduled_change(index),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        data::ScheduleChange { action },
+    )
+}
+
+/// Cancels a scheduled change. The guardian is a tBTC program guardian.
+pub fn cancel_scheduled_change(
+    guardian: &Pubkey,
+    index: u64,
+    scheduled_by: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CancelScheduledChange {
+            guardian_info: tbtc_pda::guardian_info(guardian),
+            guardian: *guardian,
+            scheduled_change: pda::scheduled_change(index),
+            scheduled_by: *scheduled_by,
+        },
+        data::CancelScheduledChange {},
+    )
+}
+
+/// Executes scheduled change `index`. The remaining accounts are the accounts of the instruction
+/// the change carries out, in order.
+pub fn execute_scheduled_change(
+    index: u64,
+    scheduled_by: &Pubkey,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    build_with_remaining(
+        accounts::ExecuteScheduledChange {
+            scheduled_change: pda::scheduled_change(index),
+            scheduled_by: *scheduled_by,
+            timelock_authority: pda::timelock_authority(),
+            program: ::wormhole_gateway::ID,
+        },
+        data::ExecuteScheduledChange {},
+        remaining_accounts,
+    )
+}
+
+pub fn update_gateway_address(authority: &Pubkey, args: UpdateGatewayAddressArgs) -> Instruction {
+    build(
+        accounts::UpdateGatewayAddress {
+            custodian: pda::custodian(),
+            gateway_info: pda::gateway_info(args.chain),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        data::UpdateGatewayAddress { args },
+    )
+}
+
+/// Disables the chain's gateway. Its limits and fees still apply to wrapped transfers.
+pub fn disable_gateway(authority: &Pubkey, chain: u16) -> Instruction {
+    build(
+        accounts::DisableGateway {
+            custodian: pda::custodian(),
+            gateway_info: pda::gateway_info(chain),
+            authority: *authority,
+        },
+        data::DisableGateway { chain },
+    )
+}
+
+pub fn update_gateway_limits(authority: &Pubkey, args: UpdateGatewayLimitsArgs) -> Instruction {
+    build(
+        accounts::UpdateGatewayLimits {
+            custodian: pda::custodian(),
+            gateway_info: pda::gateway_info(args.chain),
+            authority: *authority,
+        },
+        data::UpdateGatewayLimits { args },
+    )
+}
+
+pub fn update_gateway_fees(authority: &Pubkey, args: UpdateGatewayFeesArgs) -> Instruction {
+    build(
+        accounts::UpdateGatewayFees {
+            custodian: pda::custodian(),
+            gateway_info: pda::gateway_info(args.chain),
+            treasury_token: pda::treasury_token(),
+            authority: *authority,
+        },
+        data::UpdateGatewayFees { args },
+    )
+}
+
+/// Points the gateway at a different canonical tBTC token. The custody account in `custodian` must
+/// be empty.
+pub fn update_tbtc_token(
+    authority: &Pubkey,
+    custodian: &Custodian,
+    args: UpdateTbtcTokenArgs,
+) -> Instruction {
+    let new_wrapped_tbtc_mint = wormhole::token_bridge::wrapped_mint(args.chain, &args.address);
+
+    build(
+        accounts::UpdateTbtcToken {
+            custodian: pda::custodian(),
+            wrapped_tbtc_token: custodian.wrapped_tbtc_token,
+            new_wrapped_tbtc_mint,
+            new_wrapped_tbtc_token: pda::updated_wrapped_tbtc_token(&new_wrapped_tbtc_mint),
+            authority: *authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        data::UpdateTbtcToken { args },
+    )
+}
+
+pub fn migrate_gateway_info(authority: &Pubkey, chain: u16) -> Instruction {
+    build(
+        accounts::MigrateGatewayInfo {
+            custodian: pda::custodian(),
+            authority: *authority,
+            gateway_info: pda::gateway_info(chain),
+            system_program: system_program::ID,
+        },
+        data::MigrateGatewayInfo { chain },
+    )
+}
+
+pub fn update_minting_limit(authority: &Pubkey, new_limit: u64) -> Instruction {
+    build(
+        accounts::UpdateMintingLimit {
+            custodian: pda::custodian(),
+            authority: *authority,
+        },
+        data::UpdateMintingLimit { new_limit },
+    )
+}
+
+pub fn update_inbound_limit(authority: &Pubkey, args: UpdateInboundLimitArgs) -> Instruction {
+    build(
+        accounts::UpdateInboundLimit {
+            custodian: pda::custodian(),
+            authority: *authority,
+        },
+        data::UpdateInboundLimit { args },
+    )
+}
+
+pub fn add_gateway_guardian(authority: &Pubkey, guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::AddGatewayGuardian {
+            custodian: pda::custodian(),
+            authority: *authority,
+            gateway_guardian: pda::gateway_guardian(guardian),
+            guardian: *guardian,
+            system_program: system_program::ID,
+        },
+        data::AddGatewayGuardian {},
+    )
+}
+
+pub fn remove_gateway_guardian(authority: &Pubkey, guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::RemoveGatewayGuardian {
+            custodian: pda::custodian(),
+            authority: *authority,
+            gateway_guardian: pda::gateway_guardian(guardian),
+            guardian: *guardian,
+        },
+        data::RemoveGatewayGuardian {},
+    )
+}
+
+/// Sets the gateway mode. `as_guardian` is whether the signer acts as a gateway guardian rather
+/// than the authority, in which case it can only restrict the mode.
+pub fn set_gateway_mode(signer: &Pubkey, as_guardian: bool, mode: GatewayMode) -> Instruction {
+    build(
+        accounts::SetGatewayMode {
+            custodian: pda::custodian(),
+            signer: *signer,
+            gateway_guardian: as_guardian.then(|| pda::gateway_guardian(signer)),
+        },
+        data::SetGatewayMode { mode },
+    )
+}
+
+/// Creates the treasury collecting fees, which must exist before fees are set.
+pub fn initialize_treasury(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeTreasury {
+            custodian: pda::custodian(),
+            authority: *authority,
+            tbtc_mint: tbtc_pda::mint(),
+            treasury_token: pda::treasury_token(),
+            system_program: system_program::ID,
+            token_program: token::ID,
+        },
+        data::InitializeTreasury {},
+    )
+}
+
+pub fn withdraw_fees(authority: &Pubkey, recipient_token: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::WithdrawFees {
+            custodian: pda::custodian(),
+            authority: *authority,
+            treasury_token: pda::treasury_token(),
+            recipient_token: *recipient_token,
+            token_program: token::ID,
+        },
+        data::WithdrawFees { amount },
+    )
+}
+
+/// Allows inbound transfers to deliver tBTC into `program`. Creates the call escrow if it does not
+/// exist. While a timelock delay is set, this has to be scheduled as
+/// [TimelockedAction::AddCallTarget].
+pub fn add_call_target(authority: &Pubkey, program: &Pubkey) -> Instruction {
+    build(
+        accounts::AddCallTarget {
+            custodian: pda::custodian(),
+            authority: *authority,
+            call_target: pda::call_target(program),
+            program: *program,
+            tbtc_mint: tbtc_pda::mint(),
+            call_authority: pda::call_authority(),
+            call_escrow: pda::call_escrow(),
+            system_program: system_program::ID,
+            token_program: token::ID,
+        },
+        data::AddCallTarget {},
+    )
+}
+
+pub fn remove_call_target(authority: &Pubkey, program: &Pubkey) -> Instruction {
+    build(
+        accounts::RemoveCallTarget {
+            custodian: pda::custodian(),
+            authority: *authority,
+            call_target: pda::call_target(program),
+            program: *program,
+        },
+        data::RemoveCallTarget {},
+    )
+}
+
+pub fn recover_tokens(
+    authority: &Pubkey,
+    source_token: &Pubkey,
+    recipient_token: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::RecoverTokens {
+            custodian: pda::custodian(),
+            authority: *authority,
+            source_token: *source_token,
+            recipient_token: *recipient_token,
+            token_program: token::ID,
+        },
+        data::RecoverTokens { amount },
+    )
+}
+
+/// Redeems an inbound Token Bridge transfer to the recipient encoded in its payload. The emitter
+/// chain, emitter address and sequence are those of the posted VAA with hash `message_hash`.
+/// `payer` creates the recipient's associated token accounts if they do not exist, and is paid the
+/// relayer fee in the transfer's gateway message.
+///
+/// `call` is the target program named in the gateway message, if any, with the accounts to call it
+/// with after the call authority, call escrow and recipient. They must match the accounts hash in
+/// the message, as computed by [::wormhole_gateway::TargetCall::hash_accounts].
+#[allow(clippy::too_many_arguments)]
+pub fn receive_tbtc(
+    custodian: &Custodian,
+    payer: &Pubkey,
+    recipient: &Pubkey,
+    message_hash: [u8; 32],
+    emitter_chain: u16,
+    emitter_address: &[u8; 32],
+    sequence: u64,
+    call: Option<(&Pubkey, &[AccountMeta])>,
+) -> Instruction {
+    let tbtc_mint = tbtc_pda::mint();
+    let wrapped_tbtc_mint = custodian.wrapped_tbtc_mint;
+    let (target_program, call_accounts) = call.unzip();
+
+    build_with_remaining(
+        accounts::ReceiveTbtc {
+            payer: *payer,
+            custodian: pda::custodian(),
+            posted_vaa: wormhole::core_bridge::posted_vaa(&message_hash),
+            gateway_info: pda::gateway_info(emitter_chain),
+            treasury_token: pda::treasury_token(),
+            token_bridge_claim: wormhole::token_bridge::claim(
+                emitter_chain,
+                emitter_address,
+                sequence,
+            ),
+            wrapped_tbtc_token: custodian.wrapped_tbtc_token,
+            wrapped_tbtc_mint,
+            tbtc_mint,
+            recipient_token: associated_token::get_associated_token_address(recipient, &tbtc_mint),
+            recipient: *recipient,
+            payer_token: associated_token::get_associated_token_address(payer, &tbtc_mint),
+            recipient_wrapped_token: associated_token::get_associated_token_address(
+                recipient,
+                &wrapped_tbtc_mint,
+            ),
+            call_target: target_program.map(pda::call_target),
+            call_authority: call.map(|_| pda::call_authority()),
+            call_escrow: call.map(|_| pda::call_escrow()),
+            target_program: target_program.copied(),
+            tbtc_config: tbtc_pda::config(),
+            tbtc_minter_info: pda::tbtc_minter_info(),
+            tbtc_event_authority: tbtc_pda::event_authority(),
+            token_bridge_config: wormhole::token_bridge::config(),
+            token_bridge_registered_emitter: wormhole::token_bridge::endpoint(
+                emitter_chain,
+                emitter_address,
+            ),
+            token_bridge_wrapped_asset: wormhole::token_bridge::wrapped_meta(&wrapped_tbtc_mint),
+            token_bridge_mint_authority: wormhole::token_bridge::mint_authority(),
+            rent: sysvar::rent::ID,
+            tbtc_program: ::tbtc::ID,
+            token_bridge_program: token_bridge::program::ID,
+            core_bridge_program: core_bridge::program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        data::ReceiveTbtc { message_hash },
+        call_accounts.unwrap_or_default(),
+    )
+}
+
+/// Burns tBTC and sends wrapped tBTC to the gateway registered for `args.recipient_chain`.
+/// `sequence` is the Token Bridge emitter's next Core Bridge sequence, see
+/// [fetch_emitter_sequence](super::fetch_emitter_sequence).
+pub fn send_tbtc_gateway(
+    custodian: &Custodian,
+    sender: &Pubkey,
+    sender_token: &Pubkey,
+    sequence: u64,
+    args: SendTbtcGatewayArgs,
+) -> Instruction {
+    let wrapped_tbtc_mint = custodian.wrapped_tbtc_mint;
+    let token_bridge_emitter = wormhole::token_bridge::emitter();
+
+    build(
+        accounts::SendTbtcGateway {
+            custodian: pda::custodian(),
+            gateway_info: pda::gateway_info(args.recipient_chain),
+            wrapped_tbtc_token: custodian.wrapped_tbtc_token,
+            wrapped_tbtc_mint,
+            tbtc_mint: tbtc_pda::mint(),
+            sender_token: *sender_token,
+            sender: *sender,
+            treasury_token: pda::treasury_token(),
+            tbtc_config: tbtc_pda::config(),
+            tbtc_minter_info: pda::tbtc_minter_info(),
+            tbtc_event_authority: tbtc_pda::event_authority(),
+            token_bridge_config: wormhole::token_bridge::config(),
+            token_bridge_wrapped_asset: wormhole::token_bridge::wrapped_meta(&wrapped_tbtc_mint),
+            token_bridge_transfer_authority: wormhole::token_bridge::authority_signer(),
+            core_bridge_data: wormhole::core_bridge::bridge_data(),
+            core_message: pda::core_message(sequence),
+            token_bridge_core_emitter: token_bridge_emitter,
+            core_emitter_sequence: wormhole::core_bridge::sequence_tracker(&token_bridge_emitter),
+            core_fee_collector: wormhole::core_bridge::fee_collector(),
+            clock: sysvar::clock::ID,
+            token_bridge_sender: pda::token_bridge_sender(),
+            rent: sysvar::rent::ID,
+            tbtc_program: ::tbtc::ID,
+            token_bridge_program: token_bridge::program::ID,
+            core_bridge_program: core_bridge::program::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        data::SendTbtcGateway { args },
+    )
+}
+
+/// Burns tBTC and sends wrapped tBTC to a recipient on another chain. `sequence` is the Token
+/// Bridge emitter's next Core Bridge sequence, see
+/// [fetch_emitter_sequence](super::fetch_emitter_sequence).
+pub fn send_tbtc_wrapped(
+    custodian: &Custodian,
+    sender: &Pubkey,
+    sender_token: &Pubkey,
+    sequence: u64,
+    args: SendTbtcWrappedArgs,
+) -> Instruction {
+    let wrapped_tbtc_mint = custodian.wrapped_tbtc_mint;
+    let token_bridge_emitter = wormhole::token_bridge::emitter();
+
+    build(
+        accounts::SendTbtcWrapped {
+            custodian: pda::custodian(),
+            gateway_info: pda::gateway_info(args.recipient_chain),
+            wrapped_tbtc_token: custodian.wrapped_tbtc_token,
+            wrapped_tbtc_mint,
+            tbtc_mint: tbtc_pda::mint(),
+            sender_token: *sender_token,
+            sender: *sender,
+            treasury_token: pda::treasury_token(),
+            tbtc_config: tbtc_pda::config(),
+            tbtc_minter_info: pda::tbtc_minter_info(),
+            tbtc_event_authority: tbtc_pda::event_authority(),
+            token_bridge_config: wormhole::token_bridge::config(),
+            token_bridge_wrapped_asset: wormhole::token_bridge::wrapped_meta(&wrapped_tbtc_mint),
+            token_bridge_transfer_authority: wormhole::token_bridge::authority_signer(),
+            core_bridge_data: wormhole::core_bridge::bridge_data(),
+            core_message: pda::core_message(sequence),
+            token_bridge_core_emitter: token_bridge_emitter,
+            core_emitter_sequence: wormhole::core_bridge::sequence_tracker(&token_bridge_emitter),
+            core_fee_collector: wormhole::core_bridge::fee_collector(),
+            clock: sysvar::clock::ID,
+            rent: sysvar::rent::ID,
+            tbtc_program: ::tbtc::ID,
+            token_bridge_program: token_bridge::program::ID,
+            core_bridge_program: core_bridge::program::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        data::SendTbtcWrapped { args },
+    )
+}
+
+/// Exchanges the recipient's wrapped tBTC for tBTC, up to the gateway's minting limit.
+pub fn deposit_wormhole_tbtc(
+    custodian: &Custodian,
+    recipient: &Pubkey,
+    recipient_wrapped_token: &Pubkey,
+    recipient_token: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::DepositWormholeTbtc {
+            custodian: pda::custodian(),
+            wrapped_tbtc_token: custodian.wrapped_tbtc_token,
+            wrapped_tbtc_mint: custodian.wrapped_tbtc_mint,
+            tbtc_mint: tbtc_pda::mint(),
+            recipient_wrapped_token: *recipient_wrapped_token,
+            recipient_token: *recipient_token,
+            recipient: *recipient,
+            tbtc_config: tbtc_pda::config(),
+            tbtc_minter_info: pda::tbtc_minter_info(),
+            tbtc_event_authority: tbtc_pda::event_authority(),
+            token_program: token::ID,
+            tbtc_program: ::tbtc::ID,
+        },
+        data::DepositWormholeTbtc { amount },
+    )
+}
+
+/// Compares the custodian's minted amount with the gateway's outstanding amount in the tBTC
+/// program. If a tBTC guardian is given and signs, a discrepancy also pauses the tBTC program.
+pub fn reconcile(guardian: Option<&Pubkey>) -> Instruction {
+    build(
+        accounts::Reconcile {
+            custodian: pda::custodian(),
+            tbtc_config: tbtc_pda::config(),
+            tbtc_minter_info: pda::tbtc_minter_info(),
+            tbtc_guardian_info: guardian.map(tbtc_pda::guardian_info),
+            tbtc_guardian: guardian.copied(),
+            tbtc_event_authority: tbtc_pda::event_authority(),
+            tbtc_program: ::tbtc::ID,
+        },
+        data::Reconcile {},
+    )
+}
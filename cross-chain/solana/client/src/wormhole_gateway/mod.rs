@@ -0,0 +1,66 @@
+//! Client for the Wormhole Gateway program.
+
+pub mod instruction;
+
+pub mod pda;
+
+use crate::account::{fetch_account, fetch_optional_account, AccountSource, FetchError};
+use ::wormhole_gateway::{CallTarget, Custodian, GatewayGuardian, GatewayInfo};
+use anchor_lang::prelude::Pubkey;
+use wormhole_anchor_sdk::wormhole as core_bridge;
+
+pub use ::wormhole_gateway::ID as PROGRAM_ID;
+
+pub fn fetch_custodian<S>(source: &S) -> Result<Custodian, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_account(source, &pda::custodian())
+}
+
+/// Returns `None` if no gateway has been registered for the chain.
+pub fn fetch_gateway_info<S>(
+    source: &S,
+    chain: u16,
+) -> Result<Option<GatewayInfo>, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_optional_account(source, &pda::gateway_info(chain))
+}
+
+/// Returns `None` if the key is not a gateway guardian.
+pub fn fetch_gateway_guardian<S>(
+    source: &S,
+    guardian: &Pubkey,
+) -> Result<Option<GatewayGuardian>, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_optional_account(source, &pda::gateway_guardian(guardian))
+}
+
+/// Returns `None` if the program is not an allowed call target.
+pub fn fetch_call_target<S>(
+    source: &S,
+    program: &Pubkey,
+) -> Result<Option<CallTarget>, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    fetch_optional_account(source, &pda::call_target(program))
+}
+
+/// Fetches the Token Bridge emitter's next Core Bridge sequence, which outbound transfers need to
+/// derive their message account.
+pub fn fetch_emitter_sequence<S>(source: &S) -> Result<u64, FetchError<S::Error>>
+where
+    S: AccountSource + ?Sized,
+{
+    let emitter = crate::wormhole::token_bridge::emitter();
+    let tracker: core_bridge::SequenceTracker = fetch_account(
+        source,
+        &crate::wormhole::core_bridge::sequence_tracker(&emitter),
+    )?;
+    Ok(tracker.value())
+}
@@ -0,0 +1,181 @@
+//! PDAs of the Wormhole Gateway program.
+
+use ::wormhole_gateway::{
+    constants::{
+        CALL_ESCROW_SEED_PREFIX, MSG_SEED_PREFIX, TREASURY_SEED_PREFIX,
+        WRAPPED_TBTC_TOKEN_SEED_PREFIX,
+    },
+    CallTarget, Custodian, GatewayGuardian, GatewayInfo, Multisig, Proposal, ScheduledChange,
+};
+use anchor_lang::prelude::Pubkey;
+use wormhole_anchor_sdk::token_bridge;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &::wormhole_gateway::ID).0
+}
+
+/// Also the redeemer of inbound Token Bridge transfers.
+pub fn custodian() -> Pubkey {
+    find(&[Custodian::SEED_PREFIX])
+}
+
+/// Note that unlike the Token Bridge's seeds, the chain is encoded little-endian here.
+pub fn gateway_info(chain: u16) -> Pubkey {
+    find(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()])
+}
+
+pub fn gateway_guardian(guardian: &Pubkey) -> Pubkey {
+    find(&[GatewayGuardian::SEED_PREFIX, guardian.as_ref()])
+}
+
+/// Custody account created at initialization. The custodian records the current custody account,
+/// which changes when the tBTC token is updated.
+pub fn wrapped_tbtc_token() -> Pubkey {
+    find(&[WRAPPED_TBTC_TOKEN_SEED_PREFIX])
+}
+
+/// Custody account created when the tBTC token is updated to the one with this wrapped mint.
+pub fn updated_wrapped_tbtc_token(wrapped_tbtc_mint: &Pubkey) -> Pubkey {
+    find(&[WRAPPED_TBTC_TOKEN_SEED_PREFIX, wrapped_tbtc_mint.as_ref()])
+}
+
+/// tBTC token account collecting fees.
+pub fn treasury_token() -> Pubkey {
+    find(&[TREASURY_SEED_PREFIX])
+}
+
+/// Allow-list entry of a program that inbound transfers can deliver tBTC into.
+pub fn call_target(program: &Pubkey) -> Pubkey {
+    find(&[CallTarget::SEED_PREFIX, program.as_ref()])
+}
+
+/// Signs calls to target programs and owns the call escrow.
+pub fn call_authority() -> Pubkey {
+    find(&[CallTarget::AUTHORITY_SEED_PREFIX])
+}
+
+/// tBTC token account that target programs are called with.
+pub fn call_escrow() -> Pubkey {
+    find(&[CALL_ESCROW_SEED_PREFIX])
+}
+
+/// Signs outbound Token Bridge transfers with payload.
+pub fn token_bridge_sender() -> Pubkey {
+    find(&[token_bridge::SEED_PREFIX_SENDER])
+}
+
+/// Core Bridge message account of the outbound transfer with this emitter sequence.
+pub fn core_message(sequence: u64) -> Pubkey {
+    find(&[MSG_SEED_PREFIX, &sequence.to_le_bytes()])
+}
+
+/// The gateway's minter info in the tBTC program.
+pub fn tbtc_minter_info() -> Pubkey {
+    crate::tbtc::pda::minter_info(&custodian())
+}
+
+pub fn multisig() -> Pubkey {
+    find(&[Multisig::SEED_PREFIX])
+}
+
+pub fn multisig_authority() -> Pubkey {
+    find(&[Multisig::AUTHORITY_SEED_PREFIX])
+}
+
+pub fn proposal(index: u64) -> Pubkey {
+    find(&[Proposal::SEED_PREFIX, &index.to_le_bytes()])
+}
+
+pub fn timelock_authority() -> Pubkey {
+    ScheduledChange::authority_address()
+}
+
+pub fn scheduled_change(index: u64) -> Pubkey {
+    find(&[ScheduledChange::SEED_PREFIX, &index.to_le_bytes()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &::wormhole_gateway::ID).0
+    }
+
+    #[test]
+    fn singleton_pdas_use_program_seeds() {
+        assert_eq!(custodian(), expected(&[Custodian::SEED_PREFIX]));
+        assert_eq!(
+            wrapped_tbtc_token(),
+            expected(&[WRAPPED_TBTC_TOKEN_SEED_PREFIX])
+        );
+        assert_eq!(treasury_token(), expected(&[TREASURY_SEED_PREFIX]));
+        assert_eq!(
+            call_authority(),
+            expected(&[CallTarget::AUTHORITY_SEED_PREFIX])
+        );
+        assert_eq!(call_escrow(), expected(&[CALL_ESCROW_SEED_PREFIX]));
+        assert_eq!(
+            token_bridge_sender(),
+            expected(&[token_bridge::SEED_PREFIX_SENDER])
+        );
+        assert_eq!(multisig(), expected(&[Multisig::SEED_PREFIX]));
+        assert_eq!(
+            multisig_authority(),
+            expected(&[Multisig::AUTHORITY_SEED_PREFIX])
+        );
+        assert_eq!(
+            timelock_authority(),
+            expected(&[ScheduledChange::AUTHORITY_SEED_PREFIX])
+        );
+    }
+
+    #[test]
+    fn keyed_pdas_use_program_seeds() {
+        let key = Pubkey::new_unique();
+
+        assert_eq!(
+            gateway_guardian(&key),
+            expected(&[GatewayGuardian::SEED_PREFIX, key.as_ref()])
+        );
+        assert_eq!(
+            updated_wrapped_tbtc_token(&key),
+            expected(&[WRAPPED_TBTC_TOKEN_SEED_PREFIX, key.as_ref()])
+        );
+        assert_eq!(
+            call_target(&key),
+            expected(&[CallTarget::SEED_PREFIX, key.as_ref()])
+        );
+        assert_eq!(
+            tbtc_minter_info(),
+            Pubkey::find_program_address(
+                &[
+                    ::tbtc::MinterInfo::SEED_PREFIX,
+                    expected(&[Custodian::SEED_PREFIX]).as_ref()
+                ],
+                &::tbtc::ID
+            )
+            .0
+        );
+    }
+
+    #[test]
+    fn indexed_pdas_use_little_endian_indices() {
+        assert_eq!(
+            gateway_info(0x0102),
+            expected(&[GatewayInfo::SEED_PREFIX, &[0x02, 0x01]])
+        );
+        assert_eq!(
+            core_message(1),
+            expected(&[MSG_SEED_PREFIX, &[1, 0, 0, 0, 0, 0, 0, 0]])
+        );
+        assert_eq!(
+            proposal(1),
+            expected(&[Proposal::SEED_PREFIX, &[1, 0, 0, 0, 0, 0, 0, 0]])
+        );
+        assert_eq!(
+            scheduled_change(1),
+            expected(&[ScheduledChange::SEED_PREFIX, &[1, 0, 0, 0, 0, 0, 0, 0]])
+        );
+    }
+}
@@ -9,38 +9,57 @@ export const WH_BASE_CHAIN_ID = 30
 
 export const WH_SOLANA_CHAIN_ID = 1
 
+// Wormhole testnet chain IDs of the Sepolia-based networks, matching the ones
+// used by the cross-chain deploy scripts.
+
+export const WH_ETHEREUM_CHAIN_ID_TESTNET = 10002
+
+export const WH_ARBITRUM_CHAIN_ID_TESTNET = 10003
+
+export const WH_BASE_CHAIN_ID_TESTNET = 10004
+
+export const WH_OPTIMISM_CHAIN_ID_TESTNET = 10005
+
 // EVM addresses converted to 32 bytes. 0x is trimmed intentionally as the input
 // param requires it without leading 0x.
 
 export const ARBITRUM_GATEWAY_ADDRESS_TESTNET =
-  "00000000000000000000000031a15e213b59e230b45e8c5c99dafac3d1236ee2"
+  "000000000000000000000000c3D46e0266d95215589DE639cC4E93b79f88fc6C"
 
 export const ARBITRUM_GATEWAY_ADDRESS_MAINNET =
   "0000000000000000000000001293a54e160d1cd7075487898d65266081a15458"
 
 export const OPTIMISM_GATEWAY_ADDRESS_TESTNET =
-  "0000000000000000000000006449F4381f3d63bDfb36B3bDc375724aD3cD4621"
+  "0000000000000000000000005FB63D9e076a314023F2D1aB5dBFd7045C281EbA"
 export const OPTIMISM_GATEWAY_ADDRESS_MAINNET =
   "0000000000000000000000001293a54e160D1cd7075487898d65266081A15458"
 
-export const POLYGON_GATEWAY_ADDRESS_TESTNET =
-  "00000000000000000000000091Fe7128f74dBd4F031ea3D90FC5Ea4DCfD81818"
+// Wormhole does not support the Sepolia-based Amoy testnet, so there is no
+// Polygon gateway to register on testnet.
+export const POLYGON_GATEWAY_ADDRESS_TESTNET: string | null = null
 export const POLYGON_GATEWAY_ADDRESS_MAINNET =
   "00000000000000000000000009959798B95d00a3183d20FaC298E4594E599eab"
 
 export const BASE_GATEWAY_ADDRESS_TESTNET =
-  "000000000000000000000000e3e0511EEbD87F08FbaE4486419cb5dFB06e1343"
+  "000000000000000000000000c3D46e0266d95215589DE639cC4E93b79f88fc6C"
 export const BASE_GATEWAY_ADDRESS_MAINNET =
   "00000000000000000000000009959798B95d00a3183d20FaC298E4594E599eab"
 
+export const WH_ETHEREUM_CHAIN_ID = 2
+
+export const ETHEREUM_TBTC_ADDRESS_TESTNET =
+  "000000000000000000000000517f2982701695D4E52f1ECFBEf3ba31Df470161"
+export const ETHEREUM_TBTC_ADDRESS_MAINNET =
+  "00000000000000000000000018084fbA666a33d37592fA2633fD49a74DD93a88"
+
 export const SOLANA_GATEWAY_ADDRESS_TESTNET =
   "87MEvHZCXE3ML5rrmh5uX1FbShHmRXXS32xJDGbQ7h5t"
 export const SOLANA_GATEWAY_ADDRESS_MAINNET =
   "87MEvHZCXE3ML5rrmh5uX1FbShHmRXXS32xJDGbQ7h5t"
 
-// deriveWrappedMintKey("DZnkkTmCiFWfYTfT41X3Rd1kDgozqzxWaHqsw6W4x2oe", 2, "0x679874fbe6d4e7cc54a59e315ff1eb266686a937")
+// deriveWrappedMintKey("DZnkkTmCiFWfYTfT41X3Rd1kDgozqzxWaHqsw6W4x2oe", 10002, "0x517f2982701695D4E52f1ECFBEf3ba31Df470161")
 export const WRAPPED_TBTC_MINT_TESTNET =
-  "FMYvcyMJJ22whB9m3T5g1oPKwM6jpLnFBXnrY6eXmCrp"
+  "C6pQWmXy37yKLybzkbmBjCH5yiiGBigV4eeGpHnpbdHE"
 // deriveWrappedMintKey("wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb", 2, "0x18084fbA666a33d37592fA2633fD49a74DD93a88")
 export const WRAPPED_TBTC_MINT_MAINNET =
   "25rXTx9zDZcHyTav5sRqM6YBvTGu9pPH9yv83uAEqbgG"
@@ -30,13 +30,9 @@ async function run(): Promise<void> {
     tbtcProgram.programId
   )[0]
 
-  const guardians = PublicKey.findProgramAddressSync(
-    [Buffer.from("guardians")],
-    tbtcProgram.programId
-  )[0]
-
-  const minters = PublicKey.findProgramAddressSync(
-    [Buffer.from("minters")],
+  // The minter registry is empty after initialization, so the minter goes into page 0.
+  const mintersPage = PublicKey.findProgramAddressSync(
+    [Buffer.from("minters-page"), Buffer.alloc(4)],
     tbtcProgram.programId
   )[0]
 
@@ -53,8 +49,6 @@ async function run(): Promise<void> {
     .accounts({
       mint,
       config,
-      guardians,
-      minters,
       authority,
       tbtcMetadata,
       mplTokenMetadataProgram,
@@ -68,8 +62,12 @@ async function run(): Promise<void> {
 
   const mintingLimit = "18446744073709551615" // Max u64
   let WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_TESTNET
+  let ETHEREUM_TBTC = consts.ETHEREUM_TBTC_ADDRESS_TESTNET
+  let ETHEREUM_CHAIN_ID = consts.WH_ETHEREUM_CHAIN_ID_TESTNET
   if (process.env.CLUSTER === "mainnet") {
     WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_MAINNET
+    ETHEREUM_TBTC = consts.ETHEREUM_TBTC_ADDRESS_MAINNET
+    ETHEREUM_CHAIN_ID = consts.WH_ETHEREUM_CHAIN_ID
   }
   const WRAPPED_TBTC_MINT = new PublicKey(WRAPPED_TBTC)
 
@@ -90,7 +88,11 @@ async function run(): Promise<void> {
 
   // Initialize wormhole gateway
   await wormholeGatewayProgram.methods
-    .initialize(new anchor.BN(mintingLimit))
+    .initialize({
+      mintingLimit: new anchor.BN(mintingLimit),
+      tbtcTokenChain: ETHEREUM_CHAIN_ID,
+      tbtcTokenAddress: Array.from(Buffer.from(ETHEREUM_TBTC, "hex")),
+    })
     .accounts({
       authority,
       custodian: minter,
@@ -114,7 +116,8 @@ async function run(): Promise<void> {
     .accounts({
       config,
       authority,
-      minters,
+      roleAssignment: null,
+      mintersPage,
       minterInfo,
       minter,
     })
@@ -128,22 +131,28 @@ async function run(): Promise<void> {
   let POLYGON_GATEWAY = consts.POLYGON_GATEWAY_ADDRESS_TESTNET
   let BASE_GATEWAY = consts.BASE_GATEWAY_ADDRESS_TESTNET
   let SOLANA_GATEWAY = consts.SOLANA_GATEWAY_ADDRESS_TESTNET
+  let ARBITRUM_CHAIN_ID = consts.WH_ARBITRUM_CHAIN_ID_TESTNET
+  let OPTIMISM_CHAIN_ID = consts.WH_OPTIMISM_CHAIN_ID_TESTNET
+  let BASE_CHAIN_ID = consts.WH_BASE_CHAIN_ID_TESTNET
   if (process.env.CLUSTER === "mainnet") {
     ARBITRUM_GATEWAY = consts.ARBITRUM_GATEWAY_ADDRESS_MAINNET
     OPTIMISM_GATEWAY = consts.OPTIMISM_GATEWAY_ADDRESS_MAINNET
     POLYGON_GATEWAY = consts.POLYGON_GATEWAY_ADDRESS_MAINNET
     BASE_GATEWAY = consts.BASE_GATEWAY_ADDRESS_MAINNET
     SOLANA_GATEWAY = consts.SOLANA_GATEWAY_ADDRESS_MAINNET
+    ARBITRUM_CHAIN_ID = consts.WH_ARBITRUM_CHAIN_ID
+    OPTIMISM_CHAIN_ID = consts.WH_OPTIMISM_CHAIN_ID
+    BASE_CHAIN_ID = consts.WH_BASE_CHAIN_ID
   }
 
   // Updating with Arbitrum
   const arbiArgs = {
-    chain: consts.WH_ARBITRUM_CHAIN_ID,
+    chain: ARBITRUM_CHAIN_ID,
     address: Array.from(Buffer.alloc(32, ARBITRUM_GATEWAY, "hex")),
   }
 
   const encodedArbiChain = Buffer.alloc(2)
-  encodedArbiChain.writeUInt16LE(consts.WH_ARBITRUM_CHAIN_ID)
+  encodedArbiChain.writeUInt16LE(ARBITRUM_CHAIN_ID)
   const gatewayArbiInfo = PublicKey.findProgramAddressSync(
     [Buffer.from("gateway-info"), encodedArbiChain],
     wormholeGatewayProgram.programId
@@ -165,12 +174,12 @@ async function run(): Promise<void> {
 
   // Updating with Optimism
   const optiArgs = {
-    chain: consts.WH_OPTIMISM_CHAIN_ID,
+    chain: OPTIMISM_CHAIN_ID,
     address: Array.from(Buffer.alloc(32, OPTIMISM_GATEWAY, "hex")),
   }
 
   const encodedOptiChain = Buffer.alloc(2)
-  encodedOptiChain.writeUInt16LE(consts.WH_OPTIMISM_CHAIN_ID)
+  encodedOptiChain.writeUInt16LE(OPTIMISM_CHAIN_ID)
   const gatewayOptiInfo = PublicKey.findProgramAddressSync(
     [Buffer.from("gateway-info"), encodedOptiChain],
     wormholeGatewayProgram.programId
@@ -190,41 +199,43 @@ async function run(): Promise<void> {
     Array.from(new PublicKey(OPTIMISM_GATEWAY).toBuffer())
   )
 
-  // Updating with Polygon
-  const polyArgs = {
-    chain: consts.WH_POLYGON_CHAIN_ID,
-    address: Array.from(Buffer.alloc(32, POLYGON_GATEWAY, "hex")),
+  // Updating with Polygon, which has no testnet gateway
+  if (POLYGON_GATEWAY !== null) {
+    const polyArgs = {
+      chain: consts.WH_POLYGON_CHAIN_ID,
+      address: Array.from(Buffer.alloc(32, POLYGON_GATEWAY, "hex")),
+    }
+
+    const encodedPolyChain = Buffer.alloc(2)
+    encodedPolyChain.writeUInt16LE(consts.WH_POLYGON_CHAIN_ID)
+    const gatewayPolyInfo = PublicKey.findProgramAddressSync(
+      [Buffer.from("gateway-info"), encodedPolyChain],
+      wormholeGatewayProgram.programId
+    )[0]
+
+    await wormholeGatewayProgram.methods
+      .updateGatewayAddress(polyArgs)
+      .accounts({
+        custodian: minter,
+        gatewayInfo: gatewayPolyInfo,
+        authority,
+      })
+      .rpc()
+
+    console.log(
+      "Updated Solana gateway with Polygon..",
+      Array.from(new PublicKey(POLYGON_GATEWAY).toBuffer())
+    )
   }
 
-  const encodedPolyChain = Buffer.alloc(2)
-  encodedPolyChain.writeUInt16LE(consts.WH_POLYGON_CHAIN_ID)
-  const gatewayPolyInfo = PublicKey.findProgramAddressSync(
-    [Buffer.from("gateway-info"), encodedPolyChain],
-    wormholeGatewayProgram.programId
-  )[0]
-
-  await wormholeGatewayProgram.methods
-    .updateGatewayAddress(polyArgs)
-    .accounts({
-      custodian: minter,
-      gatewayInfo: gatewayPolyInfo,
-      authority,
-    })
-    .rpc()
-
-  console.log(
-    "Updated Solana gateway with Polygon..",
-    Array.from(new PublicKey(POLYGON_GATEWAY).toBuffer())
-  )
-
   // Updating with BASE
   const baseArgs = {
-    chain: consts.WH_BASE_CHAIN_ID,
+    chain: BASE_CHAIN_ID,
     address: Array.from(Buffer.alloc(32, BASE_GATEWAY, "hex")),
   }
 
   const encodedBaseChain = Buffer.alloc(2)
-  encodedBaseChain.writeUInt16LE(consts.WH_BASE_CHAIN_ID)
+  encodedBaseChain.writeUInt16LE(BASE_CHAIN_ID)
   const gatewayBaseInfo = PublicKey.findProgramAddressSync(
     [Buffer.from("gateway-info"), encodedBaseChain],
     wormholeGatewayProgram.programId
@@ -20,6 +20,11 @@ async function run(): Promise<void> {
     tbtcProgram.programId
   )[0]
 
+  const tbtcEventAuthority = PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    tbtcProgram.programId
+  )[0]
+
   const custodian = PublicKey.findProgramAddressSync(
     [Buffer.from("redeemer")],
     wormholeGatewayProgram.programId
@@ -31,6 +36,8 @@ async function run(): Promise<void> {
       config,
       authority,
       newAuthority,
+      eventAuthority: tbtcEventAuthority,
+      program: tbtcProgram.programId,
     })
     .rpc()
 
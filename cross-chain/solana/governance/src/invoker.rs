@@ -0,0 +1,78 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program},
+    InstructionData,
+};
+
+/// Carries out governance actions by invoking the program's own instructions with a governance PDA
+/// (the multisig or timelock authority) as signer. The accounts an action touches are passed to
+/// the executing instruction as remaining accounts.
+pub struct Invoker<'a, 'info> {
+    program_id: Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+    account_infos: Vec<AccountInfo<'info>>,
+    signer_seeds: &'a [&'a [u8]],
+}
+
+impl<'a, 'info> Invoker<'a, 'info> {
+    /// `accounts` are the accounts of the executing instruction that every action needs on top of
+    /// the remaining accounts, which are at least the signing PDA and the program itself.
+    pub fn new(
+        program_id: Pubkey,
+        remaining_accounts: &'a [AccountInfo<'info>],
+        accounts: &[AccountInfo<'info>],
+        signer_seeds: &'a [&'a [u8]],
+    ) -> Self {
+        let mut account_infos = remaining_accounts.to_vec();
+        account_infos.extend_from_slice(accounts);
+
+        Self {
+            program_id,
+            remaining_accounts,
+            account_infos,
+            signer_seeds,
+        }
+    }
+
+    pub fn find_address(&self, seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &self.program_id).0
+    }
+
+    /// Deserializes one of the remaining accounts, which is needed when an action's accounts depend
+    /// on the program's current state.
+    pub fn load_account<T>(&self, key: Pubkey) -> Result<T>
+    where
+        T: AccountSerialize + AccountDeserialize + Owner + Clone,
+    {
+        let info = self
+            .remaining_accounts
+            .iter()
+            .find(|info| info.key() == key)
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+
+        Ok(Account::<T>::try_from(info)?.into_inner())
+    }
+
+    pub fn invoke(&self, accounts: impl ToAccountMetas, data: impl InstructionData) -> Result<()> {
+        self.invoke_with_remaining_accounts(accounts, Vec::new(), data)
+    }
+
+    /// Like [Invoker::invoke], for instructions that take remaining accounts of their own.
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        accounts: impl ToAccountMetas,
+        remaining_accounts: Vec<AccountMeta>,
+        data: impl InstructionData,
+    ) -> Result<()> {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend(remaining_accounts);
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: metas,
+            data: data.data(),
+        };
+
+        program::invoke_signed(&ix, &self.account_infos, &[self.signer_seeds]).map_err(Into::into)
+    }
+}
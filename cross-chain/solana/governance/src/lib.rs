@@ -0,0 +1,14 @@
+//! Multisig and timelock logic shared by the tBTC and Wormhole Gateway programs.
+//!
+//! Each program still declares its own `Multisig`, `Proposal` and `ScheduledChange` accounts and
+//! the instructions that use them, because Anchor ties account ownership and IDL generation to the
+//! declaring program. What those declarations do lives here: [multisig] validates signer sets and
+//! tracks approvals, and [Invoker] carries out an approved action by invoking the program on
+//! itself with a governance PDA as signer.
+
+#![allow(clippy::result_large_err)]
+
+pub mod multisig;
+
+mod invoker;
+pub use invoker::*;
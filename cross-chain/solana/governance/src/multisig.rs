@@ -0,0 +1,47 @@
+use anchor_lang::prelude::Pubkey;
+
+/// Approvals are tracked in a `u16` bitmask, so a signer set cannot exceed this.
+pub const MAX_SIGNERS: usize = 16;
+
+/// Why a signer set was rejected. Each program maps these to its own error codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignerSetError {
+    TooManySigners,
+    InvalidThreshold,
+    DuplicateSigner,
+}
+
+/// A signer set must fit the approvals bitmask, contain no duplicates and have a threshold
+/// between one and the number of signers.
+pub fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<(), SignerSetError> {
+    if signers.len() > MAX_SIGNERS {
+        return Err(SignerSetError::TooManySigners);
+    }
+    if threshold == 0 || usize::from(threshold) > signers.len() {
+        return Err(SignerSetError::InvalidThreshold);
+    }
+    for (i, signer) in signers.iter().enumerate() {
+        if signers[..i].contains(signer) {
+            return Err(SignerSetError::DuplicateSigner);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn signer_index(signers: &[Pubkey], signer: &Pubkey) -> Option<usize> {
+    signers.iter().position(|key| key == signer)
+}
+
+/// Bit of the signer at `index` in an approvals bitmask.
+pub fn approval(index: usize) -> u16 {
+    1 << index
+}
+
+pub fn has_approved(approvals: u16, index: usize) -> bool {
+    approvals & approval(index) != 0
+}
+
+pub fn num_approvals(approvals: u16) -> u8 {
+    approvals.count_ones() as u8
+}
@@ -0,0 +1,52 @@
+//! Stands in for a program that the Wormhole Gateway delivers inbound tBTC into. It is only
+//! deployed for tests.
+
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+declare_id!("3MzZ8fm9CzoGwtaWx1Lm4ESXATzvTz8mCycsknRfHaUB");
+
+#[program]
+pub mod mock_call_target {
+    use super::*;
+
+    /// Moves part of the call escrow to the destination. The gateway sends whatever is left to
+    /// the recipient.
+    pub fn spend(ctx: Context<Spend>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.call_escrow.to_account_info(),
+                    to: ctx.accounts.destination_token.to_account_info(),
+                    authority: ctx.accounts.call_authority.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct Spend<'info> {
+    call_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        token::authority = call_authority,
+    )]
+    call_escrow: Account<'info, token::TokenAccount>,
+
+    /// CHECK: Recipient of the inbound transfer, which the gateway always passes.
+    recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = call_escrow.mint,
+    )]
+    destination_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
@@ -2,3 +2,7 @@ use anchor_lang::prelude::constant;
 
 #[constant]
 pub const SEED_PREFIX_TBTC_MINT: &[u8] = b"tbtc-mint";
+
+/// Reason recorded when a guardian pauses the program because reconciliation found a discrepancy.
+#[constant]
+pub const PAUSE_REASON_SUPPLY_DISCREPANCY: u8 = 0xff;
@@ -20,6 +20,9 @@ pub enum TbtcError {
     #[msg("Caller is not a guardian")]
     SignerNotGuardian = 0x34,
 
+    #[msg("Guardian info must be provided with its guardian")]
+    GuardianInfoMismatch = 0x36,
+
     #[msg("This address is already a minter")]
     MinterAlreadyExists = 0x40,
 
@@ -29,9 +32,78 @@ pub enum TbtcError {
     #[msg("Caller is not a minter")]
     SignerNotMinter = 0x44,
 
+    #[msg("Amount exceeds the minter's allowance")]
+    MinterAllowanceExceeded = 0x46,
+
+    #[msg("Amount exceeds the minter's rate limit")]
+    MinterRateLimitExceeded = 0x48,
+
+    #[msg("Amount exceeds what the minter has outstanding")]
+    MinterBurnExceedsOutstanding = 0x4a,
+
+    #[msg("Minter info must be provided with its minter")]
+    MinterInfoMismatch = 0x4c,
+
+    #[msg("Minter is paused")]
+    MinterPaused = 0x4e,
+
     #[msg("Program is paused")]
     IsPaused = 0x50,
 
     #[msg("Program is not paused")]
     IsNotPaused = 0x52,
+
+    #[msg("Guardian cannot pause again until its cooldown has passed")]
+    PauseCooldown = 0x54,
+
+    #[msg("Maximum pause duration must be nonzero")]
+    ZeroMaxPauseDuration = 0x56,
+
+    #[msg("Caller is not the delegate of the token account")]
+    IsNotDelegate = 0x60,
+
+    #[msg("Multisig threshold must be between 1 and the number of signers")]
+    InvalidMultisigThreshold = 0x70,
+
+    #[msg("Too many multisig signers")]
+    TooManyMultisigSigners = 0x72,
+
+    #[msg("Multisig signers must be unique")]
+    DuplicateMultisigSigner = 0x74,
+
+    #[msg("Caller is not a multisig signer")]
+    IsNotMultisigSigner = 0x76,
+
+    #[msg("Signer already approved this proposal")]
+    ProposalAlreadyApproved = 0x78,
+
+    #[msg("Multisig signers changed since this proposal was created")]
+    ProposalStale = 0x7a,
+
+    #[msg("Proposal does not have enough approvals")]
+    ProposalThresholdNotMet = 0x7c,
+
+    #[msg("Change must be scheduled through the timelock")]
+    TimelockRequired = 0x80,
+
+    #[msg("Scheduled change cannot be executed yet")]
+    ScheduledChangeNotReady = 0x82,
+
+    #[msg("Account is not in a layout that can be migrated")]
+    AccountNotMigratable = 0x90,
+
+    #[msg("Batch must have one amount per recipient token account")]
+    MintBatchLengthMismatch = 0xa0,
+
+    #[msg("Mint does not have the config as its freeze authority")]
+    MintCannotFreeze = 0xb0,
+
+    #[msg("Maximum freeze duration must be nonzero")]
+    ZeroMaxFreezeDuration = 0xb2,
+
+    #[msg("Account cannot be frozen again until the cooldown has passed")]
+    FreezeCooldown = 0xb4,
+
+    #[msg("Freeze has already ended")]
+    FreezeNotActive = 0xb6,
 }
@@ -1,5 +1,56 @@
+use crate::state::{ProposalAction, Role, TimelockedAction};
 use anchor_lang::prelude::*;
 
+#[event]
+pub struct TbtcMinted {
+    pub minter: Pubkey,
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct Paused {
+    pub guardian: Pubkey,
+    pub reason: u8,
+    pub paused_at: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct Unpaused {
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeRequested {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChanged {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub holder: Pubkey,
+}
+
 #[event]
 pub struct MinterAdded {
     pub minter: Pubkey,
@@ -10,6 +61,33 @@ pub struct MinterRemoved {
     pub minter: Pubkey,
 }
 
+#[event]
+pub struct MintersMigrated {
+    pub page: u32,
+    pub num_migrated: u32,
+    pub num_remaining: u32,
+}
+
+#[event]
+pub struct MinterLimitsUpdated {
+    pub minter: Pubkey,
+    pub allowance: u64,
+    pub rate_limit_amount: u64,
+    pub rate_limit_period: u32,
+}
+
+#[event]
+pub struct MinterPaused {
+    pub minter: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct MinterUnpaused {
+    pub minter: Pubkey,
+    pub authority: Pubkey,
+}
+
 #[event]
 pub struct GuardianAdded {
     pub guardian: Pubkey,
@@ -19,3 +97,136 @@ pub struct GuardianAdded {
 pub struct GuardianRemoved {
     pub guardian: Pubkey,
 }
+
+#[event]
+pub struct GuardiansMigrated {
+    pub page: u32,
+    pub num_migrated: u32,
+    pub num_remaining: u32,
+}
+
+#[event]
+pub struct TbtcBurned {
+    pub token: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TokensRecovered {
+    pub mint: Pubkey,
+    pub source_token: Pubkey,
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct PauseExtended {
+    pub authority: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct PauseParamsUpdated {
+    pub max_pause_duration: u32,
+    pub pause_cooldown: u32,
+}
+
+#[event]
+pub struct TimelockDelayUpdated {
+    pub delay: u32,
+}
+
+#[event]
+pub struct ChangeScheduled {
+    pub scheduled_change: Pubkey,
+    pub index: u64,
+    pub action: TimelockedAction,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct ScheduledChangeCancelled {
+    pub scheduled_change: Pubkey,
+    pub index: u64,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct ScheduledChangeExecuted {
+    pub scheduled_change: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct SupplyReconciled {
+    pub supply: u64,
+    pub minted_total: u64,
+    pub burned_total: u64,
+    pub discrepancy: bool,
+    pub paused_by: Option<Pubkey>,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub token: Pubkey,
+    pub guardian: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub token: Pubkey,
+    pub thawed_by: Pubkey,
+}
+
+#[event]
+pub struct FreezeExtended {
+    pub token: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct MaxFreezeDurationUpdated {
+    pub max_freeze_duration: u32,
+}
@@ -9,6 +9,9 @@ pub(crate) mod event;
 
 mod processor;
 pub(crate) use processor::*;
+pub use processor::{
+    InitializeMultisigArgs, UpdateMetadataArgs, UpdateMinterLimitsArgs, UpdatePauseParamsArgs,
+};
 
 mod state;
 pub use state::*;
@@ -34,6 +37,10 @@ pub mod tbtc {
         processor::initialize(ctx)
     }
 
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        processor::migrate_config(ctx)
+    }
+
     pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
         processor::change_authority(ctx)
     }
@@ -46,6 +53,53 @@ pub mod tbtc {
         processor::take_authority(ctx)
     }
 
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        processor::grant_role(ctx, role)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        processor::revoke_role(ctx)
+    }
+
+    pub fn initialize_multisig(
+        ctx: Context<InitializeMultisig>,
+        args: InitializeMultisigArgs,
+    ) -> Result<()> {
+        processor::initialize_multisig(ctx, args)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        processor::create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        processor::approve_proposal(ctx)
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        processor::execute_proposal(ctx)
+    }
+
+    pub fn update_timelock_delay(ctx: Context<UpdateTimelockDelay>, delay: u32) -> Result<()> {
+        processor::update_timelock_delay(ctx, delay)
+    }
+
+    pub fn schedule_change(ctx: Context<ScheduleChange>, action: TimelockedAction) -> Result<()> {
+        processor::schedule_change(ctx, action)
+    }
+
+    pub fn cancel_scheduled_change(ctx: Context<CancelScheduledChange>) -> Result<()> {
+        processor::cancel_scheduled_change(ctx)
+    }
+
+    pub fn execute_scheduled_change<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteScheduledChange<'info>>,
+    ) -> Result<()> {
+        processor::execute_scheduled_change(ctx)
+    }
+
     pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
         processor::add_minter(ctx)
     }
@@ -54,6 +108,27 @@ pub mod tbtc {
         processor::remove_minter(ctx)
     }
 
+    pub fn migrate_minter_info(ctx: Context<MigrateMinterInfo>, minted_total: u64) -> Result<()> {
+        processor::migrate_minter_info(ctx, minted_total)
+    }
+
+    pub fn migrate_minters<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateMinters<'info>>,
+    ) -> Result<()> {
+        processor::migrate_minters(ctx)
+    }
+
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+        processor::update_metadata(ctx, args)
+    }
+
+    pub fn update_minter_limits(
+        ctx: Context<UpdateMinterLimits>,
+        args: UpdateMinterLimitsArgs,
+    ) -> Result<()> {
+        processor::update_minter_limits(ctx, args)
+    }
+
     pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
         processor::add_guardian(ctx)
     }
@@ -62,15 +137,90 @@ pub mod tbtc {
         processor::remove_guardian(ctx)
     }
 
-    pub fn pause(ctx: Context<Pause>) -> Result<()> {
-        processor::pause(ctx)
+    pub fn migrate_guardian_info(ctx: Context<MigrateGuardianInfo>) -> Result<()> {
+        processor::migrate_guardian_info(ctx)
+    }
+
+    pub fn migrate_guardians<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateGuardians<'info>>,
+    ) -> Result<()> {
+        processor::migrate_guardians(ctx)
+    }
+
+    pub fn pause(ctx: Context<Pause>, reason: u8) -> Result<()> {
+        processor::pause(ctx, reason)
     }
 
     pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
         processor::unpause(ctx)
     }
 
+    pub fn pause_minter(ctx: Context<PauseMinter>) -> Result<()> {
+        processor::pause_minter(ctx)
+    }
+
+    pub fn unpause_minter(ctx: Context<UnpauseMinter>) -> Result<()> {
+        processor::unpause_minter(ctx)
+    }
+
+    pub fn extend_pause(ctx: Context<ExtendPause>, extension: u32) -> Result<()> {
+        processor::extend_pause(ctx, extension)
+    }
+
+    pub fn update_pause_params(
+        ctx: Context<UpdatePauseParams>,
+        args: UpdatePauseParamsArgs,
+    ) -> Result<()> {
+        processor::update_pause_params(ctx, args)
+    }
+
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        processor::freeze_account(ctx)
+    }
+
+    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+        processor::thaw_account(ctx)
+    }
+
+    pub fn extend_freeze(ctx: Context<ExtendFreeze>, extension: u32) -> Result<()> {
+        processor::extend_freeze(ctx, extension)
+    }
+
+    pub fn update_max_freeze_duration(
+        ctx: Context<UpdateMaxFreezeDuration>,
+        max_freeze_duration: u32,
+    ) -> Result<()> {
+        processor::update_max_freeze_duration(ctx, max_freeze_duration)
+    }
+
+    pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+        processor::recover_tokens(ctx, amount)
+    }
+
     pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<()> {
         processor::mint(ctx, amount)
     }
+
+    pub fn mint_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        processor::mint_batch(ctx, amounts)
+    }
+
+    pub fn mint_to_wallet(ctx: Context<MintToWallet>, amount: u64) -> Result<()> {
+        processor::mint_to_wallet(ctx, amount)
+    }
+
+    pub fn burn(ctx: Context<Burn>, amount: u64) -> Result<()> {
+        processor::burn(ctx, amount)
+    }
+
+    pub fn burn_from(ctx: Context<BurnFrom>, amount: u64) -> Result<()> {
+        processor::burn_from(ctx, amount)
+    }
+
+    pub fn reconcile(ctx: Context<Reconcile>) -> Result<()> {
+        processor::reconcile(ctx)
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     error::TbtcError,
-    state::{Config, GuardianInfo, Guardians},
+    state::{Config, GuardianInfo, GuardiansPage, Role, RoleAssignment, Versioned},
 };
 use anchor_lang::prelude::*;
 
@@ -10,22 +10,24 @@ pub struct AddGuardian<'info> {
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
-        has_one = authority @ TbtcError::IsNotAuthority
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
     #[account(mut)]
     authority: Signer<'info>,
 
+    /// Only needed if the signer is not the authority but holds the guardian-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
     #[account(
-        mut,
-        seeds = [Guardians::SEED_PREFIX],
-        bump = guardians.bump,
-        realloc = Guardians::compute_size(guardians.keys.len() + 1),
-        realloc::payer = authority,
-        realloc::zero = true,
+        init_if_needed,
+        payer = authority,
+        space = 8 + GuardiansPage::INIT_SPACE,
+        seeds = [GuardiansPage::SEED_PREFIX, &config.next_guardian_page().to_le_bytes()],
+        bump,
     )]
-    guardians: Account<'info, Guardians>,
+    guardians_page: Account<'info, GuardiansPage>,
 
     #[account(
         init,
@@ -37,26 +39,47 @@ pub struct AddGuardian<'info> {
     guardian_info: Account<'info, GuardianInfo>,
 
     /// CHECK: Required authority to pause contract. This pubkey lives in `GuardianInfo` and
-    /// `Guardians`.
+    /// `GuardiansPage`.
     guardian: AccountInfo<'info>,
 
     system_program: Program<'info, System>,
 }
 
+impl<'info> AddGuardian<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::GuardianAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[access_control(AddGuardian::constraints(&ctx))]
 pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
     let guardian = ctx.accounts.guardian.key();
+    let page_index = ctx.accounts.config.next_guardian_page();
 
     // Set account data.
     ctx.accounts.guardian_info.set_inner(GuardianInfo {
+        version: GuardianInfo::VERSION,
         bump: ctx.bumps["guardian_info"],
         guardian,
+        last_paused_at: 0,
+        registry_page: page_index,
     });
 
-    // Push pubkey to guardians account.
-    ctx.accounts.guardians.push(guardian);
+    // Push pubkey to the last guardians page. The page may have just been created, so set its
+    // bookkeeping too.
+    let page = &mut ctx.accounts.guardians_page;
+    page.bump = ctx.bumps["guardians_page"];
+    page.index = page_index;
+    page.push(guardian);
 
     // Update config.
-    ctx.accounts.config.num_guardians += 1;
+    let config = &mut ctx.accounts.config;
+    config.num_guardians += 1;
+    config.guardian_registry_len += 1;
 
     emit!(crate::event::GuardianAdded { guardian });
 
@@ -1,6 +1,6 @@
 use crate::{
     error::TbtcError,
-    state::{Config, MinterInfo, Minters},
+    state::{Config, MinterInfo, MintersPage, Role, RoleAssignment, Versioned},
 };
 use anchor_lang::prelude::*;
 
@@ -10,22 +10,24 @@ pub struct AddMinter<'info> {
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
-        has_one = authority @ TbtcError::IsNotAuthority
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
     #[account(mut)]
     authority: Signer<'info>,
 
+    /// Only needed if the signer is not the authority but holds the minter-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
     #[account(
-        mut,
-        seeds = [Minters::SEED_PREFIX],
-        bump = minters.bump,
-        realloc = Minters::compute_size(minters.keys.len() + 1),
-        realloc::payer = authority,
-        realloc::zero = true,
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintersPage::INIT_SPACE,
+        seeds = [MintersPage::SEED_PREFIX, &config.next_minter_page().to_le_bytes()],
+        bump,
     )]
-    minters: Account<'info, Minters>,
+    minters_page: Account<'info, MintersPage>,
 
     #[account(
         init,
@@ -42,20 +44,49 @@ pub struct AddMinter<'info> {
     system_program: Program<'info, System>,
 }
 
+impl<'info> AddMinter<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_timelocked_role(
+            &ctx.accounts.authority.key(),
+            Role::MinterAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[access_control(AddMinter::constraints(&ctx))]
 pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
     let minter = ctx.accounts.minter.key();
+    let page_index = ctx.accounts.config.next_minter_page();
 
-    // Set account data.
+    // Set account data. The minter starts without any caps, which can be set using the
+    // update_minter_limits instruction.
     ctx.accounts.minter_info.set_inner(MinterInfo {
+        version: MinterInfo::VERSION,
         bump: ctx.bumps["minter_info"],
         minter,
+        registry_page: page_index,
+        allowance: u64::MAX,
+        minted_total: 0,
+        burned_total: 0,
+        rate_limit_amount: 0,
+        rate_limit_period: 0,
+        window_start: 0,
+        window_minted: 0,
+        paused: false,
     });
 
-    // Push pubkey to minters account.
-    ctx.accounts.minters.push(minter);
+    // Push pubkey to the last minters page. The page may have just been created, so set its
+    // bookkeeping too.
+    let page = &mut ctx.accounts.minters_page;
+    page.bump = ctx.bumps["minters_page"];
+    page.index = page_index;
+    page.push(minter);
 
     // Update config.
-    ctx.accounts.config.num_minters += 1;
+    let config = &mut ctx.accounts.config;
+    config.num_minters += 1;
+    config.minter_registry_len += 1;
 
     emit!(crate::event::MinterAdded { minter });
 
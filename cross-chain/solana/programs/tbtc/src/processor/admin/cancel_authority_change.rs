@@ -1,15 +1,19 @@
-use crate::{error::TbtcError, state::Config};
+use crate::{
+    error::TbtcError,
+    state::{Config, Versioned},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
-
 pub struct CancelAuthorityChange<'info> {
     #[account(
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
         has_one = authority @ TbtcError::IsNotAuthority,
-        constraint = config.pending_authority.is_some() @ TbtcError::NoPendingAuthorityChange
+        constraint = config.pending_authority.is_some() @ TbtcError::NoPendingAuthorityChange,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
@@ -17,6 +21,13 @@ pub struct CancelAuthorityChange<'info> {
 }
 
 pub fn cancel_authority_change(ctx: Context<CancelAuthorityChange>) -> Result<()> {
-    ctx.accounts.config.pending_authority = None;
+    // It is safe to unwrap because the pending authority is checked in the account constraints.
+    let pending_authority = ctx.accounts.config.pending_authority.take().unwrap();
+
+    emit_cpi!(crate::event::AuthorityChangeCancelled {
+        authority: ctx.accounts.authority.key(),
+        pending_authority,
+    });
+
     Ok(())
 }
@@ -1,13 +1,18 @@
-use crate::{error::TbtcError, state::Config};
+use crate::{
+    error::TbtcError,
+    state::{Config, Versioned},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ChangeAuthority<'info> {
     #[account(
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
-        has_one = authority @ TbtcError::IsNotAuthority
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
@@ -18,6 +23,13 @@ pub struct ChangeAuthority<'info> {
 }
 
 pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
-    ctx.accounts.config.pending_authority = Some(ctx.accounts.new_authority.key());
+    let pending_authority = ctx.accounts.new_authority.key();
+    ctx.accounts.config.pending_authority = Some(pending_authority);
+
+    emit_cpi!(crate::event::AuthorityChangeRequested {
+        authority: ctx.accounts.authority.key(),
+        pending_authority,
+    });
+
     Ok(())
 }
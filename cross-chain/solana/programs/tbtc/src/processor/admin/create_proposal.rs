@@ -0,0 +1,80 @@
+use crate::{
+    error::TbtcError,
+    state::{Multisig, Proposal, ProposalAction},
+};
+use anchor_lang::prelude::*;
+use tbtc_governance::multisig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [Multisig::SEED_PREFIX],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [Proposal::SEED_PREFIX, &multisig.num_proposals.to_le_bytes()],
+        bump
+    )]
+    proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    proposer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposal<'info> {
+    fn constraints(ctx: &Context<Self>, action: &ProposalAction) -> Result<()> {
+        require!(
+            ctx.accounts
+                .multisig
+                .signer_index(&ctx.accounts.proposer.key())
+                .is_some(),
+            TbtcError::IsNotMultisigSigner
+        );
+
+        if let ProposalAction::UpdateMultisig { signers, threshold } = action {
+            Multisig::validate_signers(signers, *threshold)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(CreateProposal::constraints(&ctx, &action))]
+pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposer = ctx.accounts.proposer.key();
+
+    // It is safe to unwrap because the proposer was checked to be a signer.
+    let proposer_index = multisig.signer_index(&proposer).unwrap();
+    let index = multisig.num_proposals;
+
+    // Creating a proposal counts as the proposer's approval.
+    ctx.accounts.proposal.set_inner(Proposal {
+        bump: ctx.bumps["proposal"],
+        index,
+        proposer,
+        action: action.clone(),
+        signer_set_seqno: multisig.signer_set_seqno,
+        approvals: multisig::approval(proposer_index),
+    });
+
+    multisig.num_proposals += 1;
+
+    emit_cpi!(crate::event::ProposalCreated {
+        proposal: ctx.accounts.proposal.key(),
+        index,
+        proposer,
+        action,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,518 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{
+        Config, FrozenAccount, GuardianInfo, Guardians, GuardiansPage, MinterInfo, Minters,
+        MintersPage, Multisig, Proposal, ProposalAction, RoleAssignment, ScheduledChange,
+    },
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{metadata, token};
+use tbtc_governance::Invoker;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [Multisig::SEED_PREFIX],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer,
+        seeds = [Proposal::SEED_PREFIX, &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.signer_set_seqno == multisig.signer_set_seqno @ TbtcError::ProposalStale,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    /// CHECK: Receives the proposal's lamports. This pubkey lives in `Proposal`.
+    #[account(mut)]
+    proposer: AccountInfo<'info>,
+
+    /// CHECK: This PDA signs for the multisig when the proposal's action is invoked.
+    #[account(
+        mut,
+        seeds = [Multisig::AUTHORITY_SEED_PREFIX],
+        bump = multisig.authority_bump,
+    )]
+    multisig_authority: AccountInfo<'info>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require_gte!(
+            ctx.accounts.proposal.num_approvals(),
+            ctx.accounts.multisig.threshold,
+            TbtcError::ProposalThresholdNotMet
+        );
+
+        Ok(())
+    }
+}
+
+/// Executes an approved proposal. Actions on the program's state are invoked on this program with
+/// the multisig authority as signer, so the accounts they touch must be passed in as remaining
+/// accounts.
+#[access_control(ExecuteProposal::constraints(&ctx))]
+pub fn execute_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+    let bump = [ctx.accounts.multisig.authority_bump];
+    let signer_seeds: &[&[u8]] = &[Multisig::AUTHORITY_SEED_PREFIX, &bump];
+    let invoker = Invoker::new(
+        crate::ID,
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.multisig_authority.to_account_info(),
+            ctx.accounts.event_authority.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+        ],
+        signer_seeds,
+    );
+
+    let authority = ctx.accounts.multisig_authority.key();
+    let config = invoker.find_address(&[Config::SEED_PREFIX]);
+    let event_authority = invoker.find_address(&[b"__event_authority"]);
+
+    match ctx.accounts.proposal.action.clone() {
+        ProposalAction::AddMinter { minter } => {
+            // The minter is appended to the page the config currently points to.
+            let page = invoker.load_account::<Config>(config)?.next_minter_page();
+
+            invoker.invoke(
+                crate::accounts::AddMinter {
+                    config,
+                    authority,
+                    role_assignment: None,
+                    minters_page: invoker
+                        .find_address(&[MintersPage::SEED_PREFIX, &page.to_le_bytes()]),
+                    minter_info: invoker.find_address(&[MinterInfo::SEED_PREFIX, minter.as_ref()]),
+                    minter,
+                    system_program: System::id(),
+                },
+                crate::instruction::AddMinter {},
+            )?
+        }
+        ProposalAction::RemoveMinter { minter } => {
+            // The minter is removed from the page its info points to, or from the legacy minters
+            // account if it has not been migrated yet.
+            let minter_info = invoker.find_address(&[MinterInfo::SEED_PREFIX, minter.as_ref()]);
+            let page = invoker
+                .load_account::<MinterInfo>(minter_info)?
+                .registry_page;
+            let minters_page =
+                invoker.find_address(&[MintersPage::SEED_PREFIX, &page.to_le_bytes()]);
+            let in_page = invoker
+                .load_account::<MintersPage>(minters_page)
+                .is_ok_and(|page| page.contains(&minter));
+
+            invoker.invoke(
+                crate::accounts::RemoveMinter {
+                    config,
+                    authority,
+                    role_assignment: None,
+                    minters_page: in_page.then_some(minters_page),
+                    minters: (!in_page).then(|| invoker.find_address(&[Minters::SEED_PREFIX])),
+                    minter_info,
+                    minter,
+                    system_program: System::id(),
+                },
+                crate::instruction::RemoveMinter {},
+            )?
+        }
+        ProposalAction::ChangeAuthority { new_authority } => invoker.invoke(
+            crate::accounts::ChangeAuthority {
+                config,
+                authority,
+                new_authority,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::ChangeAuthority {},
+        )?,
+        ProposalAction::TakeAuthority => invoker.invoke(
+            crate::accounts::TakeAuthority {
+                config,
+                pending_authority: authority,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::TakeAuthority {},
+        )?,
+        ProposalAction::GrantRole { role, holder } => invoker.invoke(
+            crate::accounts::GrantRole {
+                config,
+                authority,
+                role_assignment: invoker.find_address(&[
+                    RoleAssignment::SEED_PREFIX,
+                    &[role as u8],
+                    holder.as_ref(),
+                ]),
+                holder,
+                system_program: System::id(),
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::GrantRole { role },
+        )?,
+        ProposalAction::RevokeRole { role, holder } => invoker.invoke(
+            crate::accounts::RevokeRole {
+                config,
+                authority,
+                role_assignment: invoker.find_address(&[
+                    RoleAssignment::SEED_PREFIX,
+                    &[role as u8],
+                    holder.as_ref(),
+                ]),
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::RevokeRole {},
+        )?,
+        ProposalAction::ScheduleChange { action } => {
+            // The scheduled change's address depends on the config's current count.
+            let index = invoker
+                .load_account::<Config>(config)?
+                .num_scheduled_changes;
+
+            invoker.invoke(
+                crate::accounts::ScheduleChange {
+                    config,
+                    scheduled_change: invoker
+                        .find_address(&[ScheduledChange::SEED_PREFIX, &index.to_le_bytes()]),
+                    authority,
+                    system_program: System::id(),
+                    event_authority,
+                    program: crate::ID,
+                },
+                crate::instruction::ScheduleChange { action },
+            )?
+        }
+        ProposalAction::UpdateMultisig { signers, threshold } => {
+            let multisig = &mut ctx.accounts.multisig;
+            multisig.signers = signers.clone();
+            multisig.threshold = threshold;
+            multisig.signer_set_seqno += 1;
+
+            emit_cpi!(crate::event::MultisigUpdated { signers, threshold });
+        }
+        ProposalAction::CancelAuthorityChange => invoker.invoke(
+            crate::accounts::CancelAuthorityChange {
+                config,
+                authority,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::CancelAuthorityChange {},
+        )?,
+        ProposalAction::AddGuardian { guardian } => {
+            // The guardian is appended to the page the config currently points to.
+            let page = invoker.load_account::<Config>(config)?.next_guardian_page();
+
+            invoker.invoke(
+                crate::accounts::AddGuardian {
+                    config,
+                    authority,
+                    role_assignment: None,
+                    guardians_page: invoker
+                        .find_address(&[GuardiansPage::SEED_PREFIX, &page.to_le_bytes()]),
+                    guardian_info: invoker
+                        .find_address(&[GuardianInfo::SEED_PREFIX, guardian.as_ref()]),
+                    guardian,
+                    system_program: System::id(),
+                },
+                crate::instruction::AddGuardian {},
+            )?
+        }
+        ProposalAction::RemoveGuardian { guardian } => {
+            // Like minters, the guardian is removed from the page its info points to, or from the
+            // legacy guardians account if it has not been migrated yet.
+            let guardian_info =
+                invoker.find_address(&[GuardianInfo::SEED_PREFIX, guardian.as_ref()]);
+            let page = invoker
+                .load_account::<GuardianInfo>(guardian_info)?
+                .registry_page;
+            let guardians_page =
+                invoker.find_address(&[GuardiansPage::SEED_PREFIX, &page.to_le_bytes()]);
+            let in_page = invoker
+                .load_account::<GuardiansPage>(guardians_page)
+                .is_ok_and(|page| page.contains(&guardian));
+
+            invoker.invoke(
+                crate::accounts::RemoveGuardian {
+                    config,
+                    authority,
+                    role_assignment: None,
+                    guardians_page: in_page.then_some(guardians_page),
+                    guardians: (!in_page).then(|| invoker.find_address(&[Guardians::SEED_PREFIX])),
+                    guardian_info,
+                    guardian,
+                    system_program: System::id(),
+                },
+                crate::instruction::RemoveGuardian {},
+            )?
+        }
+        ProposalAction::ExtendPause { extension } => invoker.invoke(
+            crate::accounts::ExtendPause {
+                config,
+                authority,
+                role_assignment: None,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::ExtendPause { extension },
+        )?,
+        ProposalAction::Unpause => invoker.invoke(
+            crate::accounts::Unpause {
+                config,
+                authority,
+                role_assignment: None,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::Unpause {},
+        )?,
+        ProposalAction::UnpauseMinter { minter } => invoker.invoke(
+            crate::accounts::UnpauseMinter {
+                config,
+                authority,
+                role_assignment: None,
+                minter_info: invoker.find_address(&[MinterInfo::SEED_PREFIX, minter.as_ref()]),
+                minter,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::UnpauseMinter {},
+        )?,
+        ProposalAction::UpdateMinterLimits {
+            minter,
+            allowance,
+            rate_limit_amount,
+            rate_limit_period,
+        } => invoker.invoke(
+            crate::accounts::UpdateMinterLimits {
+                config,
+                authority,
+                role_assignment: None,
+                minter_info: invoker.find_address(&[MinterInfo::SEED_PREFIX, minter.as_ref()]),
+                minter,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::UpdateMinterLimits {
+                args: crate::UpdateMinterLimitsArgs {
+                    allowance,
+                    rate_limit_amount,
+                    rate_limit_period,
+                },
+            },
+        )?,
+        ProposalAction::UpdatePauseParams {
+            max_pause_duration,
+            pause_cooldown,
+        } => invoker.invoke(
+            crate::accounts::UpdatePauseParams {
+                config,
+                authority,
+                role_assignment: None,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::UpdatePauseParams {
+                args: crate::UpdatePauseParamsArgs {
+                    max_pause_duration,
+                    pause_cooldown,
+                },
+            },
+        )?,
+        ProposalAction::UpdateMetadata { name, symbol, uri } => {
+            let mint = invoker.find_address(&[SEED_PREFIX_TBTC_MINT]);
+
+            invoker.invoke(
+                crate::accounts::UpdateMetadata {
+                    config,
+                    authority,
+                    role_assignment: None,
+                    tbtc_metadata: mpl_token_metadata::pda::find_metadata_account(&mint).0,
+                    mpl_token_metadata_program: metadata::Metadata::id(),
+                    event_authority,
+                    program: crate::ID,
+                },
+                crate::instruction::UpdateMetadata {
+                    args: crate::UpdateMetadataArgs { name, symbol, uri },
+                },
+            )?
+        }
+        ProposalAction::UpdateTimelockDelay { delay } => invoker.invoke(
+            crate::accounts::UpdateTimelockDelay {
+                config,
+                authority,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::UpdateTimelockDelay { delay },
+        )?,
+        ProposalAction::RecoverTokens {
+            source_token,
+            recipient_token,
+            amount,
+        } => invoker.invoke(
+            crate::accounts::RecoverTokens {
+                config,
+                authority,
+                source_token,
+                recipient_token,
+                token_program: token::ID,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::RecoverTokens { amount },
+        )?,
+        ProposalAction::ThawAccount { token } => invoker.invoke(
+            crate::accounts::ThawAccount {
+                config,
+                mint: invoker.find_address(&[SEED_PREFIX_TBTC_MINT]),
+                authority,
+                token,
+                frozen_account: invoker.find_address(&[FrozenAccount::SEED_PREFIX, token.as_ref()]),
+                token_program: token::ID,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::ThawAccount {},
+        )?,
+        ProposalAction::ExtendFreeze { token, extension } => invoker.invoke(
+            crate::accounts::ExtendFreeze {
+                config,
+                authority,
+                token,
+                frozen_account: invoker.find_address(&[FrozenAccount::SEED_PREFIX, token.as_ref()]),
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::ExtendFreeze { extension },
+        )?,
+        ProposalAction::UpdateMaxFreezeDuration {
+            max_freeze_duration,
+        } => invoker.invoke(
+            crate::accounts::UpdateMaxFreezeDuration {
+                config,
+                authority,
+                role_assignment: None,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::UpdateMaxFreezeDuration {
+                max_freeze_duration,
+            },
+        )?,
+        ProposalAction::MigrateMinterInfo {
+            minter,
+            minted_total,
+        } => invoker.invoke(
+            crate::accounts::MigrateMinterInfo {
+                config,
+                authority,
+                minter_info: invoker.find_address(&[MinterInfo::SEED_PREFIX, minter.as_ref()]),
+                minter,
+                system_program: System::id(),
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::MigrateMinterInfo { minted_total },
+        )?,
+        ProposalAction::MigrateMinters => {
+            // The keys that fit into the current page are moved from the end of the legacy list,
+            // and their infos are passed in the same order.
+            let config_data = invoker.load_account::<Config>(config)?;
+            let page = config_data.next_minter_page();
+            let minters = invoker.find_address(&[Minters::SEED_PREFIX]);
+            let legacy = invoker.load_account::<Minters>(minters)?;
+            let num_migrated = legacy
+                .len()
+                .min(config_data.next_minter_page_capacity() as usize);
+            let minter_infos = legacy[legacy.len() - num_migrated..]
+                .iter()
+                .map(|minter| {
+                    AccountMeta::new(
+                        invoker.find_address(&[MinterInfo::SEED_PREFIX, minter.as_ref()]),
+                        false,
+                    )
+                })
+                .collect();
+
+            invoker.invoke_with_remaining_accounts(
+                crate::accounts::MigrateMinters {
+                    config,
+                    authority,
+                    minters,
+                    minters_page: invoker
+                        .find_address(&[MintersPage::SEED_PREFIX, &page.to_le_bytes()]),
+                    system_program: System::id(),
+                    event_authority,
+                    program: crate::ID,
+                },
+                minter_infos,
+                crate::instruction::MigrateMinters {},
+            )?
+        }
+        ProposalAction::MigrateGuardianInfo { guardian } => invoker.invoke(
+            crate::accounts::MigrateGuardianInfo {
+                config,
+                authority,
+                guardian_info: invoker
+                    .find_address(&[GuardianInfo::SEED_PREFIX, guardian.as_ref()]),
+                guardian,
+                system_program: System::id(),
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::MigrateGuardianInfo {},
+        )?,
+        ProposalAction::MigrateGuardians => {
+            let config_data = invoker.load_account::<Config>(config)?;
+            let page = config_data.next_guardian_page();
+            let guardians = invoker.find_address(&[Guardians::SEED_PREFIX]);
+            let legacy = invoker.load_account::<Guardians>(guardians)?;
+            let num_migrated = legacy
+                .len()
+                .min(config_data.next_guardian_page_capacity() as usize);
+            let guardian_infos = legacy[legacy.len() - num_migrated..]
+                .iter()
+                .map(|guardian| {
+                    AccountMeta::new(
+                        invoker.find_address(&[GuardianInfo::SEED_PREFIX, guardian.as_ref()]),
+                        false,
+                    )
+                })
+                .collect();
+
+            invoker.invoke_with_remaining_accounts(
+                crate::accounts::MigrateGuardians {
+                    config,
+                    authority,
+                    guardians,
+                    guardians_page: invoker
+                        .find_address(&[GuardiansPage::SEED_PREFIX, &page.to_le_bytes()]),
+                    system_program: System::id(),
+                    event_authority,
+                    program: crate::ID,
+                },
+                guardian_infos,
+                crate::instruction::MigrateGuardians {},
+            )?
+        }
+    }
+
+    emit_cpi!(crate::event::ProposalExecuted {
+        proposal: ctx.accounts.proposal.key(),
+        index: ctx.accounts.proposal.index,
+    });
+
+    Ok(())
+}
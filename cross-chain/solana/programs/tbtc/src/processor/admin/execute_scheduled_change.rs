@@ -0,0 +1,105 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, MinterInfo, MintersPage, ScheduledChange, TimelockedAction},
+};
+use anchor_lang::prelude::*;
+use tbtc_governance::Invoker;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteScheduledChange<'info> {
+    #[account(
+        mut,
+        has_one = scheduled_by,
+        close = scheduled_by,
+        seeds = [ScheduledChange::SEED_PREFIX, &scheduled_change.index.to_le_bytes()],
+        bump = scheduled_change.bump,
+    )]
+    scheduled_change: Account<'info, ScheduledChange>,
+
+    /// CHECK: Receives the scheduled change's lamports. This pubkey lives in `ScheduledChange`.
+    #[account(mut)]
+    scheduled_by: AccountInfo<'info>,
+
+    /// CHECK: This PDA signs for the timelock when the scheduled action is invoked.
+    #[account(
+        mut,
+        seeds = [ScheduledChange::AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    timelock_authority: AccountInfo<'info>,
+}
+
+impl<'info> ExecuteScheduledChange<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require_gte!(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.scheduled_change.executable_at,
+            TbtcError::ScheduledChangeNotReady
+        );
+
+        Ok(())
+    }
+}
+
+/// Executes a scheduled change once its delay has passed. Anyone can execute it. The action is
+/// invoked on this program with the timelock authority as signer, so the accounts it touches must
+/// be passed in as remaining accounts.
+#[access_control(ExecuteScheduledChange::constraints(&ctx))]
+pub fn execute_scheduled_change<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteScheduledChange<'info>>,
+) -> Result<()> {
+    let bump = [ctx.bumps["timelock_authority"]];
+    let signer_seeds: &[&[u8]] = &[ScheduledChange::AUTHORITY_SEED_PREFIX, &bump];
+    let invoker = Invoker::new(
+        crate::ID,
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.timelock_authority.to_account_info(),
+            ctx.accounts.event_authority.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+        ],
+        signer_seeds,
+    );
+
+    let authority = ctx.accounts.timelock_authority.key();
+    let config = invoker.find_address(&[Config::SEED_PREFIX]);
+    let event_authority = invoker.find_address(&[b"__event_authority"]);
+
+    match ctx.accounts.scheduled_change.action {
+        TimelockedAction::AddMinter { minter } => {
+            // The minter is appended to the page the config currently points to.
+            let page = invoker.load_account::<Config>(config)?.next_minter_page();
+
+            invoker.invoke(
+                crate::accounts::AddMinter {
+                    config,
+                    authority,
+                    role_assignment: None,
+                    minters_page: invoker
+                        .find_address(&[MintersPage::SEED_PREFIX, &page.to_le_bytes()]),
+                    minter_info: invoker.find_address(&[MinterInfo::SEED_PREFIX, minter.as_ref()]),
+                    minter,
+                    system_program: System::id(),
+                },
+                crate::instruction::AddMinter {},
+            )?
+        }
+        TimelockedAction::UpdateTimelockDelay { delay } => invoker.invoke(
+            crate::accounts::UpdateTimelockDelay {
+                config,
+                authority,
+                event_authority,
+                program: crate::ID,
+            },
+            crate::instruction::UpdateTimelockDelay { delay },
+        )?,
+    }
+
+    emit_cpi!(crate::event::ScheduledChangeExecuted {
+        scheduled_change: ctx.accounts.scheduled_change.key(),
+        index: ctx.accounts.scheduled_change.index,
+    });
+
+    Ok(())
+}
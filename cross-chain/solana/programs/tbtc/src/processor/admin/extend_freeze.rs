@@ -0,0 +1,58 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, FrozenAccount, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendFreeze<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = token,
+        seeds = [FrozenAccount::SEED_PREFIX, token.key().as_ref()],
+        bump = frozen_account.bump,
+    )]
+    frozen_account: Account<'info, FrozenAccount>,
+}
+
+impl<'info> ExtendFreeze<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .frozen_account
+                .is_active(Clock::get()?.unix_timestamp),
+            TbtcError::FreezeNotActive
+        );
+
+        Ok(())
+    }
+}
+
+/// Keeps a token account frozen for longer. Guardians cannot do this, since a freeze is only meant
+/// to buy the authority time to act.
+#[access_control(ExtendFreeze::constraints(&ctx))]
+pub fn extend_freeze(ctx: Context<ExtendFreeze>, extension: u32) -> Result<()> {
+    let frozen_account = &mut ctx.accounts.frozen_account;
+    frozen_account.expires_at = frozen_account.expires_at.saturating_add(extension.into());
+
+    emit_cpi!(crate::event::FreezeExtended {
+        token: frozen_account.token,
+        expires_at: frozen_account.expires_at,
+    });
+
+    Ok(())
+}
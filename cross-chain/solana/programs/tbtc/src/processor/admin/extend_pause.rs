@@ -0,0 +1,52 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendPause<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Only needed if the signer is not the authority but holds the pause-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+impl<'info> ExtendPause<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::PauseAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )?;
+
+        require!(
+            ctx.accounts.config.is_paused(Clock::get()?.unix_timestamp),
+            TbtcError::IsNotPaused
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(ExtendPause::constraints(&ctx))]
+pub fn extend_pause(ctx: Context<ExtendPause>, extension: u32) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pause_expires_at = config.pause_expires_at.saturating_add(extension.into());
+
+    emit_cpi!(crate::event::PauseExtended {
+        authority: ctx.accounts.authority.key(),
+        expires_at: config.pause_expires_at,
+    });
+
+    Ok(())
+}
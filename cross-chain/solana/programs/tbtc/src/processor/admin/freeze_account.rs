@@ -0,0 +1,109 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{Config, FrozenAccount, GuardianInfo, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FreezeAccount<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        has_one = guardian,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump,
+        constraint = guardian_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    #[account(mut)]
+    guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        space = 8 + FrozenAccount::INIT_SPACE,
+        seeds = [FrozenAccount::SEED_PREFIX, token.key().as_ref()],
+        bump,
+    )]
+    frozen_account: Account<'info, FrozenAccount>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> FreezeAccount<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        // A mint created before the config held its freeze authority cannot be given one, so such
+        // deployments cannot freeze accounts until the mint is replaced.
+        require!(
+            ctx.accounts.mint.freeze_authority == Some(ctx.accounts.config.key()).into(),
+            TbtcError::MintCannotFreeze
+        );
+
+        // Only the authority can keep an account frozen for longer, using extend_freeze.
+        require!(
+            ctx.accounts.frozen_account.freeze_cooldown_passed(
+                ctx.accounts.config.pause_cooldown,
+                Clock::get()?.unix_timestamp
+            ),
+            TbtcError::FreezeCooldown
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(FreezeAccount::constraints(&ctx))]
+pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+    let token = ctx.accounts.token.key();
+    let guardian = ctx.accounts.guardian.key();
+    let frozen_at = Clock::get()?.unix_timestamp;
+    let expires_at = frozen_at.saturating_add(ctx.accounts.config.max_freeze_duration.into());
+
+    ctx.accounts.frozen_account.set_inner(FrozenAccount {
+        bump: ctx.bumps["frozen_account"],
+        token,
+        frozen_by: guardian,
+        frozen_at,
+        expires_at,
+    });
+
+    token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::FreezeAccount {
+            account: ctx.accounts.token.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+    ))?;
+
+    emit_cpi!(crate::event::AccountFrozen {
+        token,
+        guardian,
+        expires_at,
+    });
+
+    Ok(())
+}
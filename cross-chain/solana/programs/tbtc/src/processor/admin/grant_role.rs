@@ -0,0 +1,49 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [RoleAssignment::SEED_PREFIX, &[role as u8], holder.key().as_ref()],
+        bump
+    )]
+    role_assignment: Account<'info, RoleAssignment>,
+
+    /// CHECK: This pubkey lives in `RoleAssignment`.
+    holder: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    let holder = ctx.accounts.holder.key();
+
+    ctx.accounts.role_assignment.set_inner(RoleAssignment {
+        bump: ctx.bumps["role_assignment"],
+        role,
+        holder,
+    });
+
+    emit_cpi!(crate::event::RoleGranted { role, holder });
+
+    Ok(())
+}
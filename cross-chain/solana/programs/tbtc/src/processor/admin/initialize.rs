@@ -1,7 +1,4 @@
-use crate::{
-    constants::SEED_PREFIX_TBTC_MINT,
-    state::{Config, Guardians, Minters},
-};
+use crate::{constants::SEED_PREFIX_TBTC_MINT, state::Config};
 use anchor_lang::prelude::*;
 use anchor_spl::{metadata, token};
 
@@ -16,6 +13,7 @@ pub struct Initialize<'info> {
         payer = authority,
         mint::decimals = 8,
         mint::authority = config,
+        mint::freeze_authority = config,
     )]
     mint: Account<'info, token::Mint>,
 
@@ -28,24 +26,6 @@ pub struct Initialize<'info> {
     )]
     config: Account<'info, Config>,
 
-    #[account(
-        init,
-        payer = authority,
-        space = Guardians::compute_size(0),
-        seeds = [Guardians::SEED_PREFIX],
-        bump,
-    )]
-    guardians: Account<'info, Guardians>,
-
-    #[account(
-        init,
-        payer = authority,
-        space = Minters::compute_size(0),
-        seeds = [Minters::SEED_PREFIX],
-        bump,
-    )]
-    minters: Account<'info, Minters>,
-
     #[account(mut)]
     authority: Signer<'info>,
 
@@ -64,26 +44,28 @@ pub struct Initialize<'info> {
 pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     // Set Config account data.
     ctx.accounts.config.set_inner(Config {
+        version: Config::VERSION,
         bump: ctx.bumps["config"],
         authority: ctx.accounts.authority.key(),
         pending_authority: None,
         mint: ctx.accounts.mint.key(),
         mint_bump: ctx.bumps["mint"],
+        minted_total: 0,
+        burned_total: 0,
         num_minters: 0,
         num_guardians: 0,
         paused: false,
-    });
-
-    // Set Guardians account data with empty vec.
-    ctx.accounts.guardians.set_inner(Guardians {
-        bump: ctx.bumps["guardians"],
-        keys: Vec::new(),
-    });
-
-    // Set Guardians account data with empty vec.
-    ctx.accounts.minters.set_inner(Minters {
-        bump: ctx.bumps["minters"],
-        keys: Vec::new(),
+        minter_registry_len: 0,
+        guardian_registry_len: 0,
+        pause_guardian: None,
+        pause_reason: 0,
+        paused_at: 0,
+        pause_expires_at: 0,
+        max_pause_duration: Config::DEFAULT_MAX_PAUSE_DURATION,
+        pause_cooldown: Config::DEFAULT_PAUSE_COOLDOWN,
+        max_freeze_duration: Config::DEFAULT_MAX_FREEZE_DURATION,
+        timelock_delay: 0,
+        num_scheduled_changes: 0,
     });
 
     // Create metadata for tBTC.
@@ -0,0 +1,67 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Multisig, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeMultisig<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Multisig::INIT_SPACE,
+        seeds = [Multisig::SEED_PREFIX],
+        bump
+    )]
+    multisig: Account<'info, Multisig>,
+
+    /// CHECK: This PDA signs for the multisig. It becomes the program authority once
+    /// `Config.authority` is handed over to it.
+    #[account(
+        seeds = [Multisig::AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    multisig_authority: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeMultisigArgs {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+pub fn initialize_multisig(
+    ctx: Context<InitializeMultisig>,
+    args: InitializeMultisigArgs,
+) -> Result<()> {
+    let InitializeMultisigArgs { signers, threshold } = args;
+
+    Multisig::validate_signers(&signers, threshold)?;
+
+    ctx.accounts.multisig.set_inner(Multisig {
+        bump: ctx.bumps["multisig"],
+        authority_bump: ctx.bumps["multisig_authority"],
+        signers: signers.clone(),
+        threshold,
+        signer_set_seqno: 0,
+        num_proposals: 0,
+    });
+
+    emit_cpi!(crate::event::MultisigUpdated { signers, threshold });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{read_legacy, write_migrated, Config, ConfigV0},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Config in its legacy layout, which cannot be deserialized as `Config`. Its owner,
+    /// discriminator and size are checked when it is read.
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Upgrades the config from its legacy layout. This has to happen before any other account is
+/// migrated, since those migrations check the authority against the upgraded config. The legacy
+/// config did not track the minted total, so everything in circulation counts as minted.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config = read_legacy::<Config, ConfigV0>(&ctx.accounts.config)?
+        .into_current(Clock::get()?.unix_timestamp, ctx.accounts.mint.supply);
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        config.authority,
+        TbtcError::IsNotAuthority
+    );
+
+    write_migrated(
+        &ctx.accounts.config,
+        &config,
+        8 + Config::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit_cpi!(crate::event::AccountMigrated {
+        account: ctx.accounts.config.key(),
+        version: Config::VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::{
+    error::TbtcError,
+    state::{read_legacy, write_migrated, Config, GuardianInfo, GuardianInfoV0, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateGuardianInfo<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: Guardian info in its legacy layout, which cannot be deserialized as `GuardianInfo`.
+    /// Its owner, discriminator and size are checked when it is read.
+    #[account(
+        mut,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump,
+    )]
+    guardian_info: UncheckedAccount<'info>,
+
+    /// CHECK: This pubkey lives in `GuardianInfo`.
+    guardian: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Upgrades a guardian info from its legacy layout. This has to happen before the guardian is
+/// moved out of the legacy guardians account with `migrate_guardians`, which sets its registry
+/// page.
+pub fn migrate_guardian_info(ctx: Context<MigrateGuardianInfo>) -> Result<()> {
+    let legacy = read_legacy::<GuardianInfo, GuardianInfoV0>(&ctx.accounts.guardian_info)?;
+    require_keys_eq!(
+        legacy.guardian,
+        ctx.accounts.guardian.key(),
+        TbtcError::GuardianInfoMismatch
+    );
+
+    let guardian_info = GuardianInfo {
+        version: GuardianInfo::VERSION,
+        bump: legacy.bump,
+        guardian: legacy.guardian,
+        last_paused_at: 0,
+        registry_page: 0,
+    };
+
+    write_migrated(
+        &ctx.accounts.guardian_info,
+        &guardian_info,
+        8 + GuardianInfo::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit_cpi!(crate::event::AccountMigrated {
+        account: ctx.accounts.guardian_info.key(),
+        version: GuardianInfo::VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, GuardianInfo, Guardians, GuardiansPage, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateGuardians<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Legacy guardians account. This is closed once all of its keys have been migrated.
+    #[account(
+        mut,
+        seeds = [Guardians::SEED_PREFIX],
+        bump = guardians.bump,
+    )]
+    guardians: Account<'info, Guardians>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + GuardiansPage::INIT_SPACE,
+        seeds = [GuardiansPage::SEED_PREFIX, &config.next_guardian_page().to_le_bytes()],
+        bump,
+    )]
+    guardians_page: Account<'info, GuardiansPage>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Move as many keys from the legacy guardians account as fit into the last guardians page. Keys
+/// are taken from the end of the legacy list, and the `GuardianInfo` account of each moved guardian
+/// must be passed in as a remaining account (in the same order) so it can point to its new page.
+pub fn migrate_guardians<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateGuardians<'info>>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let page_index = config.next_guardian_page();
+
+    let capacity = config.next_guardian_page_capacity();
+    let legacy: &mut Vec<_> = &mut ctx.accounts.guardians;
+    let num_migrated = legacy.len().min(capacity as usize);
+    let keys = legacy.split_off(legacy.len() - num_migrated);
+
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        num_migrated,
+        ErrorCode::AccountNotEnoughKeys
+    );
+
+    for (&guardian, info) in keys.iter().zip(ctx.remaining_accounts) {
+        let mut guardian_info = Account::<GuardianInfo>::try_from(info)?;
+        require!(
+            guardian_info.is_current_version(),
+            TbtcError::AccountNotMigratable
+        );
+        require_keys_eq!(
+            guardian_info.guardian,
+            guardian,
+            TbtcError::GuardianInfoMismatch
+        );
+
+        guardian_info.registry_page = page_index;
+        guardian_info.exit(&crate::ID)?;
+    }
+
+    let page = &mut ctx.accounts.guardians_page;
+    page.bump = ctx.bumps["guardians_page"];
+    page.index = page_index;
+    page.extend(keys);
+
+    // Guardians were already counted when they were added, so only the registry length changes.
+    config.guardian_registry_len += num_migrated as u32;
+
+    let num_remaining = ctx.accounts.guardians.len();
+    if num_remaining == 0 {
+        ctx.accounts
+            .guardians
+            .close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    emit_cpi!(crate::event::GuardiansMigrated {
+        page: page_index,
+        num_migrated: num_migrated as u32,
+        num_remaining: num_remaining as u32,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::{
+    error::TbtcError,
+    state::{read_legacy, write_migrated, Config, MinterInfo, MinterInfoV0, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateMinterInfo<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: Minter info in its legacy layout, which cannot be deserialized as `MinterInfo`. Its
+    /// owner, discriminator and size are checked when it is read.
+    #[account(
+        mut,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump,
+    )]
+    minter_info: UncheckedAccount<'info>,
+
+    /// CHECK: This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Upgrades a minter info from its legacy layout. This has to happen before the minter is moved
+/// out of the legacy minters account with `migrate_minters`, which sets its registry page.
+///
+/// The legacy layout did not track what the minter minted, so the authority provides what the
+/// minter has outstanding as `minted_total`. For the Wormhole Gateway custodian this is its
+/// `minted_amount`, which lets it burn the tokens it minted before the migration.
+pub fn migrate_minter_info(ctx: Context<MigrateMinterInfo>, minted_total: u64) -> Result<()> {
+    let legacy = read_legacy::<MinterInfo, MinterInfoV0>(&ctx.accounts.minter_info)?;
+    require_keys_eq!(
+        legacy.minter,
+        ctx.accounts.minter.key(),
+        TbtcError::MinterInfoMismatch
+    );
+
+    // Like a newly added minter, the minter starts without any caps. Tokens it has outstanding
+    // count as minted, just like the config counts everything in circulation as minted.
+    let minter_info = MinterInfo {
+        version: MinterInfo::VERSION,
+        minter: legacy.minter,
+        bump: legacy.bump,
+        registry_page: 0,
+        allowance: u64::MAX,
+        minted_total,
+        burned_total: 0,
+        rate_limit_amount: 0,
+        rate_limit_period: 0,
+        window_start: 0,
+        window_minted: 0,
+        paused: false,
+    };
+
+    write_migrated(
+        &ctx.accounts.minter_info,
+        &minter_info,
+        8 + MinterInfo::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit_cpi!(crate::event::AccountMigrated {
+        account: ctx.accounts.minter_info.key(),
+        version: MinterInfo::VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, MinterInfo, Minters, MintersPage, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateMinters<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Legacy minters account. This is closed once all of its keys have been migrated.
+    #[account(
+        mut,
+        seeds = [Minters::SEED_PREFIX],
+        bump = minters.bump,
+    )]
+    minters: Account<'info, Minters>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintersPage::INIT_SPACE,
+        seeds = [MintersPage::SEED_PREFIX, &config.next_minter_page().to_le_bytes()],
+        bump,
+    )]
+    minters_page: Account<'info, MintersPage>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Move as many keys from the legacy minters account as fit into the last minters page. Keys are
+/// taken from the end of the legacy list, and the `MinterInfo` account of each moved minter must be
+/// passed in as a remaining account (in the same order) so it can point to its new page.
+pub fn migrate_minters<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateMinters<'info>>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let page_index = config.next_minter_page();
+
+    let capacity = config.next_minter_page_capacity();
+    let legacy: &mut Vec<_> = &mut ctx.accounts.minters;
+    let num_migrated = legacy.len().min(capacity as usize);
+    let keys = legacy.split_off(legacy.len() - num_migrated);
+
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        num_migrated,
+        ErrorCode::AccountNotEnoughKeys
+    );
+
+    for (&minter, info) in keys.iter().zip(ctx.remaining_accounts) {
+        let mut minter_info = Account::<MinterInfo>::try_from(info)?;
+        require!(
+            minter_info.is_current_version(),
+            TbtcError::AccountNotMigratable
+        );
+        require_keys_eq!(minter_info.minter, minter, TbtcError::MinterInfoMismatch);
+
+        minter_info.registry_page = page_index;
+        minter_info.exit(&crate::ID)?;
+    }
+
+    let page = &mut ctx.accounts.minters_page;
+    page.bump = ctx.bumps["minters_page"];
+    page.index = page_index;
+    page.extend(keys);
+
+    // Minters were already counted when they were added, so only the registry length changes.
+    config.minter_registry_len += num_migrated as u32;
+
+    let num_remaining = ctx.accounts.minters.len();
+    if num_remaining == 0 {
+        ctx.accounts
+            .minters
+            .close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    emit_cpi!(crate::event::MintersMigrated {
+        page: page_index,
+        num_migrated: num_migrated as u32,
+        num_remaining: num_remaining as u32,
+    });
+
+    Ok(())
+}
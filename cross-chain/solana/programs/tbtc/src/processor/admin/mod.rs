@@ -4,26 +4,104 @@ pub use add_guardian::*;
 mod add_minter;
 pub use add_minter::*;
 
+mod approve_proposal;
+pub use approve_proposal::*;
+
 mod cancel_authority_change;
 pub use cancel_authority_change::*;
 
+mod cancel_scheduled_change;
+pub use cancel_scheduled_change::*;
+
 mod change_authority;
 pub use change_authority::*;
 
+mod create_proposal;
+pub use create_proposal::*;
+
+mod execute_proposal;
+pub use execute_proposal::*;
+
+mod execute_scheduled_change;
+pub use execute_scheduled_change::*;
+
+mod extend_freeze;
+pub use extend_freeze::*;
+
+mod extend_pause;
+pub use extend_pause::*;
+
+mod freeze_account;
+pub use freeze_account::*;
+
+mod grant_role;
+pub use grant_role::*;
+
 mod initialize;
 pub use initialize::*;
 
+mod initialize_multisig;
+pub use initialize_multisig::*;
+
+mod migrate_config;
+pub use migrate_config::*;
+
+mod migrate_guardian_info;
+pub use migrate_guardian_info::*;
+
+mod migrate_guardians;
+pub use migrate_guardians::*;
+
+mod migrate_minter_info;
+pub use migrate_minter_info::*;
+
+mod migrate_minters;
+pub use migrate_minters::*;
+
 mod pause;
 pub use pause::*;
 
+mod pause_minter;
+pub use pause_minter::*;
+
+mod recover_tokens;
+pub use recover_tokens::*;
+
 mod remove_guardian;
 pub use remove_guardian::*;
 
 mod remove_minter;
 pub use remove_minter::*;
 
+mod revoke_role;
+pub use revoke_role::*;
+
+mod schedule_change;
+pub use schedule_change::*;
+
 mod take_authority;
 pub use take_authority::*;
 
+mod thaw_account;
+pub use thaw_account::*;
+
 mod unpause;
 pub use unpause::*;
+
+mod unpause_minter;
+pub use unpause_minter::*;
+
+mod update_max_freeze_duration;
+pub use update_max_freeze_duration::*;
+
+mod update_metadata;
+pub use update_metadata::*;
+
+mod update_minter_limits;
+pub use update_minter_limits::*;
+
+mod update_pause_params;
+pub use update_pause_params::*;
+
+mod update_timelock_delay;
+pub use update_timelock_delay::*;
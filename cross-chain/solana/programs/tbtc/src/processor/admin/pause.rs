@@ -1,22 +1,26 @@
 use crate::{
     error::TbtcError,
-    state::{Config, GuardianInfo},
+    state::{Config, GuardianInfo, Versioned},
 };
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Pause<'info> {
     #[account(
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
     #[account(
+        mut,
         has_one = guardian,
         seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
-        bump = guardian_info.bump
+        bump = guardian_info.bump,
+        constraint = guardian_info.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     guardian_info: Account<'info, GuardianInfo>,
 
@@ -25,14 +29,37 @@ pub struct Pause<'info> {
 
 impl<'info> Pause<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, TbtcError::IsPaused);
+        let config = &ctx.accounts.config;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!config.is_paused(now), TbtcError::IsPaused);
+
+        // Do not let the same guardian keep the program paused by re-pausing right away.
+        require!(
+            config.pause_cooldown_passed(&ctx.accounts.guardian_info, now),
+            TbtcError::PauseCooldown
+        );
 
         Ok(())
     }
 }
 
 #[access_control(Pause::constraints(&ctx))]
-pub fn pause(ctx: Context<Pause>) -> Result<()> {
-    ctx.accounts.config.paused = true;
+pub fn pause(ctx: Context<Pause>, reason: u8) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+    let paused_at = Clock::get()?.unix_timestamp;
+
+    let expires_at =
+        ctx.accounts
+            .config
+            .start_pause(&mut ctx.accounts.guardian_info, reason, paused_at);
+
+    emit_cpi!(crate::event::Paused {
+        guardian,
+        reason,
+        paused_at,
+        expires_at,
+    });
+
     Ok(())
 }
@@ -0,0 +1,51 @@
+use crate::{
+    error::TbtcError,
+    state::{GuardianInfo, MinterInfo, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseMinter<'info> {
+    #[account(
+        has_one = guardian,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump,
+        constraint = guardian_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+}
+
+impl<'info> PauseMinter<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.minter_info.paused, TbtcError::MinterPaused);
+
+        Ok(())
+    }
+}
+
+#[access_control(PauseMinter::constraints(&ctx))]
+pub fn pause_minter(ctx: Context<PauseMinter>) -> Result<()> {
+    ctx.accounts.minter_info.paused = true;
+
+    emit_cpi!(crate::event::MinterPaused {
+        minter: ctx.accounts.minter.key(),
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecoverTokens<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Token account owned by the config PDA holding the stranded tokens.
+    #[account(
+        mut,
+        token::authority = config,
+    )]
+    source_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = source_token.mint,
+    )]
+    recipient_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.source_token.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(crate::event::TokensRecovered {
+        mint: ctx.accounts.source_token.mint,
+        source_token: ctx.accounts.source_token.key(),
+        recipient_token: ctx.accounts.recipient_token.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use crate::{
     error::TbtcError,
-    state::{Config, GuardianInfo, Guardians},
+    state::{Config, GuardianInfo, Guardians, GuardiansPage, Role, RoleAssignment, Versioned},
 };
 use anchor_lang::prelude::*;
 
@@ -8,22 +8,32 @@ use anchor_lang::prelude::*;
 pub struct RemoveGuardian<'info> {
     #[account(
         mut,
-        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
     #[account(mut)]
     authority: Signer<'info>,
 
+    /// Only needed if the signer is not the authority but holds the guardian-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// The page the guardian is in. Only needed if the guardian has been moved out of the legacy
+    /// guardians account.
+    #[account(
+        mut,
+        seeds = [GuardiansPage::SEED_PREFIX, &guardian_info.registry_page.to_le_bytes()],
+        bump = guardians_page.bump,
+    )]
+    guardians_page: Option<Account<'info, GuardiansPage>>,
+
+    /// Legacy guardians account. Only needed if the guardian has not been migrated out of it yet.
     #[account(
         mut,
         seeds = [Guardians::SEED_PREFIX],
         bump = guardians.bump,
-        realloc = Guardians::compute_size(guardians.keys.len().saturating_sub(1)),
-        realloc::payer = authority,
-        realloc::zero = true,
     )]
-    guardians: Account<'info, Guardians>,
+    guardians: Option<Account<'info, Guardians>>,
 
     #[account(
         mut,
@@ -31,6 +41,7 @@ pub struct RemoveGuardian<'info> {
         close = authority,
         seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
         bump = guardian_info.bump,
+        constraint = guardian_info.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     guardian_info: Account<'info, GuardianInfo>,
 
@@ -40,18 +51,36 @@ pub struct RemoveGuardian<'info> {
     system_program: Program<'info, System>,
 }
 
+impl<'info> RemoveGuardian<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::GuardianAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[access_control(RemoveGuardian::constraints(&ctx))]
 pub fn remove_guardian(ctx: Context<RemoveGuardian>) -> Result<()> {
-    let guardians: &mut Vec<_> = &mut ctx.accounts.guardians;
     let removed = ctx.accounts.guardian.key();
 
-    // It is safe to unwrap because the key we are removing is guaranteed to exist since there is
-    // a guardian info account for it.
+    // A guardian whose info was migrated still points to the first page until the guardian itself is
+    // moved out of the legacy guardians account.
+    let guardians: &mut Vec<_> = match (
+        &mut ctx.accounts.guardians_page,
+        &mut ctx.accounts.guardians,
+    ) {
+        (Some(page), _) if page.contains(&removed) => &mut page.keys,
+        (_, Some(legacy)) => &mut legacy.keys,
+        _ => return err!(TbtcError::GuardianNonexistent),
+    };
     let index = guardians
         .iter()
         .position(|&guardian| guardian == removed)
-        .unwrap();
+        .ok_or(error!(TbtcError::GuardianNonexistent))?;
 
-    // Remove pubkey to guardians account.
+    // Remove pubkey from the guardians page or legacy account. A freed page slot is not reused.
     guardians.swap_remove(index);
 
     // Update config.
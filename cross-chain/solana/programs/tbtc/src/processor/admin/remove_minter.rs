@@ -1,6 +1,6 @@
 use crate::{
     error::TbtcError,
-    state::{Config, MinterInfo, Minters},
+    state::{Config, MinterInfo, Minters, MintersPage, Role, RoleAssignment, Versioned},
 };
 use anchor_lang::prelude::*;
 
@@ -10,22 +10,32 @@ pub struct RemoveMinter<'info> {
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
-        has_one = authority @ TbtcError::IsNotAuthority
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
     #[account(mut)]
     authority: Signer<'info>,
 
+    /// Only needed if the signer is not the authority but holds the minter-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// The page the minter is in. Only needed if the minter has been moved out of the legacy
+    /// minters account.
+    #[account(
+        mut,
+        seeds = [MintersPage::SEED_PREFIX, &minter_info.registry_page.to_le_bytes()],
+        bump = minters_page.bump,
+    )]
+    minters_page: Option<Account<'info, MintersPage>>,
+
+    /// Legacy minters account. Only needed if the minter has not been migrated out of it yet.
     #[account(
         mut,
         seeds = [Minters::SEED_PREFIX],
         bump = minters.bump,
-        realloc = Minters::compute_size(minters.keys.len().saturating_sub(1)),
-        realloc::payer = authority,
-        realloc::zero = true,
     )]
-    minters: Account<'info, Minters>,
+    minters: Option<Account<'info, Minters>>,
 
     #[account(
         mut,
@@ -33,6 +43,7 @@ pub struct RemoveMinter<'info> {
         close = authority,
         seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
         bump = minter_info.bump,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     minter_info: Account<'info, MinterInfo>,
 
@@ -42,18 +53,33 @@ pub struct RemoveMinter<'info> {
     system_program: Program<'info, System>,
 }
 
+impl<'info> RemoveMinter<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::MinterAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[access_control(RemoveMinter::constraints(&ctx))]
 pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
-    let minters: &mut Vec<_> = &mut ctx.accounts.minters;
     let removed = ctx.accounts.minter.key();
 
-    // It is safe to unwrap because the key we are removing is guaranteed to exist since there is
-    // a minter info account for it.
+    // A minter whose info was migrated still points to the first page until the minter itself is
+    // moved out of the legacy minters account.
+    let minters: &mut Vec<_> = match (&mut ctx.accounts.minters_page, &mut ctx.accounts.minters) {
+        (Some(page), _) if page.contains(&removed) => &mut page.keys,
+        (_, Some(legacy)) => &mut legacy.keys,
+        _ => return err!(TbtcError::MinterNonexistent),
+    };
     let index = minters
         .iter()
         .position(|&minter| minter == removed)
-        .unwrap();
+        .ok_or(error!(TbtcError::MinterNonexistent))?;
 
-    // Remove pubkey to minters account.
+    // Remove pubkey from the minters page or legacy account. A freed page slot is not reused.
     minters.swap_remove(index);
 
     // Update config.
@@ -0,0 +1,40 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &[role_assignment.role as u8],
+            role_assignment.holder.as_ref(),
+        ],
+        bump = role_assignment.bump,
+    )]
+    role_assignment: Account<'info, RoleAssignment>,
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+    let RoleAssignment { role, holder, .. } = *ctx.accounts.role_assignment;
+
+    emit_cpi!(crate::event::RoleRevoked { role, holder });
+
+    Ok(())
+}
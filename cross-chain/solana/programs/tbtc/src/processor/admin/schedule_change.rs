@@ -0,0 +1,59 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, ScheduledChange, TimelockedAction, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ScheduleChange<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ScheduledChange::INIT_SPACE,
+        seeds = [ScheduledChange::SEED_PREFIX, &config.num_scheduled_changes.to_le_bytes()],
+        bump
+    )]
+    scheduled_change: Account<'info, ScheduledChange>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn schedule_change(ctx: Context<ScheduleChange>, action: TimelockedAction) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let index = config.num_scheduled_changes;
+    let executable_at = Clock::get()?
+        .unix_timestamp
+        .saturating_add(config.timelock_delay.into());
+
+    ctx.accounts.scheduled_change.set_inner(ScheduledChange {
+        bump: ctx.bumps["scheduled_change"],
+        index,
+        scheduled_by: ctx.accounts.authority.key(),
+        action: action.clone(),
+        executable_at,
+    });
+
+    config.num_scheduled_changes += 1;
+
+    emit_cpi!(crate::event::ChangeScheduled {
+        scheduled_change: ctx.accounts.scheduled_change.key(),
+        index,
+        action,
+        executable_at,
+    });
+
+    Ok(())
+}
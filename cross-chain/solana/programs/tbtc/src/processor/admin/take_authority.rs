@@ -1,12 +1,17 @@
-use crate::{error::TbtcError, state::Config};
+use crate::{
+    error::TbtcError,
+    state::{Config, Versioned},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TakeAuthority<'info> {
     #[account(
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
@@ -32,7 +37,16 @@ impl<'info> TakeAuthority<'info> {
 
 #[access_control(TakeAuthority::constraints(&ctx))]
 pub fn take_authority(ctx: Context<TakeAuthority>) -> Result<()> {
-    ctx.accounts.config.authority = ctx.accounts.pending_authority.key();
+    let old_authority = ctx.accounts.config.authority;
+    let new_authority = ctx.accounts.pending_authority.key();
+
+    ctx.accounts.config.authority = new_authority;
     ctx.accounts.config.pending_authority = None;
+
+    emit_cpi!(crate::event::AuthorityChanged {
+        old_authority,
+        new_authority,
+    });
+
     Ok(())
 }
@@ -0,0 +1,84 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{Config, FrozenAccount, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ThawAccount<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    /// Must be the authority unless the freeze has expired.
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = token,
+        seeds = [FrozenAccount::SEED_PREFIX, token.key().as_ref()],
+        bump = frozen_account.bump,
+    )]
+    frozen_account: Account<'info, FrozenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> ThawAccount<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        // Anyone can thaw an account whose freeze has run its course.
+        if Clock::get()?.unix_timestamp >= ctx.accounts.frozen_account.expires_at {
+            return Ok(());
+        }
+
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            ctx.accounts.config.authority,
+            TbtcError::IsNotAuthority
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(ThawAccount::constraints(&ctx))]
+pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+    // The freeze record is kept, ending the freeze now so that the cooldown starts.
+    let frozen_account = &mut ctx.accounts.frozen_account;
+    frozen_account.expires_at = frozen_account.expires_at.min(Clock::get()?.unix_timestamp);
+
+    token::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::ThawAccount {
+            account: ctx.accounts.token.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+    ))?;
+
+    emit_cpi!(crate::event::AccountThawed {
+        token: ctx.accounts.token.key(),
+        thawed_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -1,21 +1,35 @@
-use crate::{error::TbtcError, state::Config};
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleAssignment, Versioned},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Unpause<'info> {
     #[account(
         mut,
-        has_one = authority @ TbtcError::IsNotAuthority,
         seeds = [Config::SEED_PREFIX],
         bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
     authority: Signer<'info>,
+
+    /// Only needed if the signer is not the authority but holds the pause-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
 impl<'info> Unpause<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::PauseAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )?;
+
+        // An expired pause is no longer in effect, but its flag can still be cleared.
         require!(ctx.accounts.config.paused, TbtcError::IsNotPaused);
 
         Ok(())
@@ -24,6 +38,16 @@ impl<'info> Unpause<'info> {
 
 #[access_control(Unpause::constraints(&ctx))]
 pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
-    ctx.accounts.config.paused = false;
+    let config = &mut ctx.accounts.config;
+    config.paused = false;
+    config.pause_guardian = None;
+
+    // The pause ends now unless it already expired, which is where the guardian's cooldown starts.
+    config.pause_expires_at = config.pause_expires_at.min(Clock::get()?.unix_timestamp);
+
+    emit_cpi!(crate::event::Unpaused {
+        authority: ctx.accounts.authority.key(),
+    });
+
     Ok(())
 }
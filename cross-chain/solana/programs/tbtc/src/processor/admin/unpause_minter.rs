@@ -0,0 +1,59 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, MinterInfo, Role, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnpauseMinter<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Only needed if the signer is not the authority but holds the pause-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+}
+
+impl<'info> UnpauseMinter<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::PauseAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )?;
+
+        require!(ctx.accounts.minter_info.paused, TbtcError::IsNotPaused);
+
+        Ok(())
+    }
+}
+
+#[access_control(UnpauseMinter::constraints(&ctx))]
+pub fn unpause_minter(ctx: Context<UnpauseMinter>) -> Result<()> {
+    ctx.accounts.minter_info.paused = false;
+
+    emit_cpi!(crate::event::MinterUnpaused {
+        minter: ctx.accounts.minter.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
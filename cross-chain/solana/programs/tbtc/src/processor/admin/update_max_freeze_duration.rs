@@ -0,0 +1,49 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMaxFreezeDuration<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Only needed if the signer is not the authority but holds the pause-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+impl<'info> UpdateMaxFreezeDuration<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::PauseAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[access_control(UpdateMaxFreezeDuration::constraints(&ctx))]
+pub fn update_max_freeze_duration(
+    ctx: Context<UpdateMaxFreezeDuration>,
+    max_freeze_duration: u32,
+) -> Result<()> {
+    require_gt!(max_freeze_duration, 0, TbtcError::ZeroMaxFreezeDuration);
+
+    // Only future freezes are affected.
+    ctx.accounts.config.max_freeze_duration = max_freeze_duration;
+
+    emit_cpi!(crate::event::MaxFreezeDurationUpdated {
+        max_freeze_duration
+    });
+
+    Ok(())
+}
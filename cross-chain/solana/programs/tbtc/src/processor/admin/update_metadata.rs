@@ -0,0 +1,78 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::metadata;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Only needed if the signer is not the authority but holds the metadata-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// CHECK: This account is needed for the MPL Token Metadata program, which checks that the
+    /// config PDA is its update authority.
+    #[account(mut)]
+    tbtc_metadata: UncheckedAccount<'info>,
+
+    mpl_token_metadata_program: Program<'info, metadata::Metadata>,
+}
+
+impl<'info> UpdateMetadata<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::MetadataAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[access_control(UpdateMetadata::constraints(&ctx))]
+pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+    let UpdateMetadataArgs { name, symbol, uri } = args;
+
+    metadata::update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.mpl_token_metadata_program.to_account_info(),
+            metadata::UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.tbtc_metadata.to_account_info(),
+                update_authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+        ),
+        None, // new_update_authority
+        Some(mpl_token_metadata::state::DataV2 {
+            symbol: symbol.clone(),
+            name: name.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }),
+        None, // primary_sale_happened
+        None, // is_mutable
+    )?;
+
+    emit_cpi!(crate::event::MetadataUpdated { name, symbol, uri });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, MinterInfo, Role, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMinterLimits<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Only needed if the signer is not the authority but holds the limit-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+}
+
+impl<'info> UpdateMinterLimits<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::LimitAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMinterLimitsArgs {
+    pub allowance: u64,
+    pub rate_limit_amount: u64,
+    pub rate_limit_period: u32,
+}
+
+#[access_control(UpdateMinterLimits::constraints(&ctx))]
+pub fn update_minter_limits(
+    ctx: Context<UpdateMinterLimits>,
+    args: UpdateMinterLimitsArgs,
+) -> Result<()> {
+    let UpdateMinterLimitsArgs {
+        allowance,
+        rate_limit_amount,
+        rate_limit_period,
+    } = args;
+
+    let minter_info = &mut ctx.accounts.minter_info;
+
+    // Changing the rate limit starts a new window.
+    if minter_info.rate_limit_amount != rate_limit_amount
+        || minter_info.rate_limit_period != rate_limit_period
+    {
+        minter_info.window_start = Clock::get()?.unix_timestamp;
+        minter_info.window_minted = 0;
+    }
+
+    minter_info.allowance = allowance;
+    minter_info.rate_limit_amount = rate_limit_amount;
+    minter_info.rate_limit_period = rate_limit_period;
+
+    emit_cpi!(crate::event::MinterLimitsUpdated {
+        minter: minter_info.minter,
+        allowance,
+        rate_limit_amount,
+        rate_limit_period,
+    });
+
+    Ok(())
+}
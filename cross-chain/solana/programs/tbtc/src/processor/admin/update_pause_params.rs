@@ -0,0 +1,63 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleAssignment, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePauseParams<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Only needed if the signer is not the authority but holds the pause-admin role.
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+impl<'info> UpdatePauseParams<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts.config.require_role(
+            &ctx.accounts.authority.key(),
+            Role::PauseAdmin,
+            ctx.accounts.role_assignment.as_deref(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePauseParamsArgs {
+    pub max_pause_duration: u32,
+    pub pause_cooldown: u32,
+}
+
+#[access_control(UpdatePauseParams::constraints(&ctx))]
+pub fn update_pause_params(
+    ctx: Context<UpdatePauseParams>,
+    args: UpdatePauseParamsArgs,
+) -> Result<()> {
+    let UpdatePauseParamsArgs {
+        max_pause_duration,
+        pause_cooldown,
+    } = args;
+
+    require_gt!(max_pause_duration, 0, TbtcError::ZeroMaxPauseDuration);
+
+    // Only future pauses are affected. The current one can be extended with extend_pause.
+    let config = &mut ctx.accounts.config;
+    config.max_pause_duration = max_pause_duration;
+    config.pause_cooldown = pause_cooldown;
+
+    emit_cpi!(crate::event::PauseParamsUpdated {
+        max_pause_duration,
+        pause_cooldown,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, ScheduledChange, Versioned},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateTimelockDelay<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> UpdateTimelockDelay<'info> {
+    fn constraints(ctx: &Context<Self>, delay: u32) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let authority = ctx.accounts.authority.key();
+
+        // The authority can lengthen the delay right away, but shortening it has to go through the
+        // timelock.
+        if authority != ScheduledChange::authority_address() {
+            require_keys_eq!(authority, config.authority, TbtcError::IsNotAuthority);
+            require_gte!(delay, config.timelock_delay, TbtcError::TimelockRequired);
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateTimelockDelay::constraints(&ctx, delay))]
+pub fn update_timelock_delay(ctx: Context<UpdateTimelockDelay>, delay: u32) -> Result<()> {
+    ctx.accounts.config.timelock_delay = delay;
+
+    emit_cpi!(crate::event::TimelockDelayUpdated { delay });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{Config, MinterInfo, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Burn<'info> {
+    // Use the correct token mint for the program.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    owner_token: Account<'info, token::TokenAccount>,
+
+    owner: Signer<'info>,
+
+    /// Info of the minter whose outstanding amount this burn retires. This account must be
+    /// provided together with its signing minter.
+    #[account(
+        mut,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    minter_info: Option<Account<'info, MinterInfo>>,
+
+    minter: Option<Signer<'info>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> Burn<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        validate_burn(
+            &ctx.accounts.config,
+            &ctx.accounts.minter_info,
+            &ctx.accounts.minter,
+        )
+    }
+}
+
+#[access_control(Burn::constraints(&ctx))]
+pub fn burn(ctx: Context<Burn>, amount: u64) -> Result<()> {
+    record_burn(
+        &mut ctx.accounts.config,
+        ctx.accounts.minter_info.as_mut(),
+        amount,
+    )?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.owner_token.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(crate::event::TbtcBurned {
+        token: ctx.accounts.owner_token.key(),
+        authority: ctx.accounts.owner.key(),
+        minter: ctx.accounts.minter.as_ref().map(|minter| minter.key()),
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Burning is not allowed when the program is paused. If a minter info is provided, its minter
+/// must sign so that nobody else can move its accounting.
+pub(crate) fn validate_burn(
+    config: &Config,
+    minter_info: &Option<Account<'_, MinterInfo>>,
+    minter: &Option<Signer<'_>>,
+) -> Result<()> {
+    require!(
+        !config.is_paused(Clock::get()?.unix_timestamp),
+        TbtcError::IsPaused
+    );
+
+    match (minter_info, minter) {
+        (Some(minter_info), Some(minter)) => {
+            require_keys_eq!(
+                minter_info.minter,
+                minter.key(),
+                TbtcError::MinterInfoMismatch
+            );
+
+            Ok(())
+        }
+        (None, None) => Ok(()),
+        _ => err!(TbtcError::MinterInfoMismatch),
+    }
+}
+
+pub(crate) fn record_burn(
+    config: &mut Config,
+    minter_info: Option<&mut Account<'_, MinterInfo>>,
+    amount: u64,
+) -> Result<()> {
+    if let Some(minter_info) = minter_info {
+        minter_info.record_burn(amount)?;
+    }
+
+    config.burned_total = config.burned_total.saturating_add(amount);
+
+    Ok(())
+}
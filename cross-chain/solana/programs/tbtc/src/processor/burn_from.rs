@@ -0,0 +1,90 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{Config, MinterInfo, Versioned},
+};
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BurnFrom<'info> {
+    // Use the correct token mint for the program.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    // The spender must have been approved as this token account's delegate.
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = from_token.delegate == COption::Some(spender.key()) @ TbtcError::IsNotDelegate,
+    )]
+    from_token: Account<'info, token::TokenAccount>,
+
+    spender: Signer<'info>,
+
+    /// Info of the minter whose outstanding amount this burn retires. This account must be
+    /// provided together with its signing minter.
+    #[account(
+        mut,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    minter_info: Option<Account<'info, MinterInfo>>,
+
+    minter: Option<Signer<'info>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> BurnFrom<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        super::validate_burn(
+            &ctx.accounts.config,
+            &ctx.accounts.minter_info,
+            &ctx.accounts.minter,
+        )
+    }
+}
+
+#[access_control(BurnFrom::constraints(&ctx))]
+pub fn burn_from(ctx: Context<BurnFrom>, amount: u64) -> Result<()> {
+    super::record_burn(
+        &mut ctx.accounts.config,
+        ctx.accounts.minter_info.as_mut(),
+        amount,
+    )?;
+
+    // The Token program checks and decrements the delegated amount.
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.from_token.to_account_info(),
+                authority: ctx.accounts.spender.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(crate::event::TbtcBurned {
+        token: ctx.accounts.from_token.key(),
+        authority: ctx.accounts.spender.key(),
+        minter: ctx.accounts.minter.as_ref().map(|minter| minter.key()),
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,12 @@
 use crate::{
     constants::SEED_PREFIX_TBTC_MINT,
     error::TbtcError,
-    state::{Config, MinterInfo},
+    state::{Config, MinterInfo, Versioned},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Mint<'info> {
     // Use the correct token mint for the program.
@@ -18,16 +19,20 @@ pub struct Mint<'info> {
     mint: Account<'info, token::Mint>,
 
     #[account(
+        mut,
         seeds = [Config::SEED_PREFIX],
         bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     config: Account<'info, Config>,
 
     // Require the signing minter to match a valid minter info.
     #[account(
+        mut,
         has_one = minter,
         seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
         bump = minter_info.bump,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
     )]
     minter_info: Account<'info, MinterInfo>,
 
@@ -45,15 +50,24 @@ pub struct Mint<'info> {
 
 impl<'info> Mint<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
-        // Can not mint when paused.
-        require!(!ctx.accounts.config.paused, TbtcError::IsPaused);
-
-        Ok(())
+        validate_mint(&ctx.accounts.config, &ctx.accounts.minter_info)
     }
 }
 
 #[access_control(Mint::constraints(&ctx))]
 pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<()> {
+    record_mint(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.minter_info,
+        amount,
+    )?;
+
+    emit_cpi!(crate::event::TbtcMinted {
+        minter: ctx.accounts.minter.key(),
+        recipient_token: ctx.accounts.recipient_token.key(),
+        amount,
+    });
+
     token::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -67,3 +81,27 @@ pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<()> {
         amount,
     )
 }
+
+/// Minting is not allowed when the program or the minter is paused.
+pub(crate) fn validate_mint(config: &Config, minter_info: &MinterInfo) -> Result<()> {
+    require!(
+        !config.is_paused(Clock::get()?.unix_timestamp),
+        TbtcError::IsPaused
+    );
+    require!(!minter_info.paused, TbtcError::MinterPaused);
+
+    Ok(())
+}
+
+/// Enforce the minter's allowance and rate limit, and account for the amount minted.
+pub(crate) fn record_mint(
+    config: &mut Config,
+    minter_info: &mut MinterInfo,
+    amount: u64,
+) -> Result<()> {
+    minter_info.record_mint(amount, Clock::get()?.unix_timestamp)?;
+
+    config.minted_total = config.minted_total.saturating_add(amount);
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{Config, MinterInfo, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintBatch<'info> {
+    // Use the correct token mint for the program.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+        mint::authority = config,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    // Require the signing minter to match a valid minter info.
+    #[account(
+        mut,
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    minter: Signer<'info>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> MintBatch<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        super::validate_mint(&ctx.accounts.config, &ctx.accounts.minter_info)
+    }
+}
+
+/// Mint to many recipients at once. The recipient token accounts are passed in as remaining
+/// accounts, in the same order as their amounts. The minter's allowance and rate limit are checked
+/// against the total.
+#[access_control(MintBatch::constraints(&ctx))]
+pub fn mint_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    require_eq!(
+        amounts.len(),
+        ctx.remaining_accounts.len(),
+        TbtcError::MintBatchLengthMismatch
+    );
+
+    let total = amounts
+        .iter()
+        .try_fold(0u64, |total, &amount| total.checked_add(amount))
+        .ok_or(TbtcError::MinterAllowanceExceeded)?;
+    super::record_mint(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.minter_info,
+        total,
+    )?;
+
+    let minter = ctx.accounts.minter.key();
+    let config_seeds = &[Config::SEED_PREFIX, &[ctx.accounts.config.bump]];
+
+    for (&amount, info) in amounts.iter().zip(ctx.remaining_accounts) {
+        let recipient_token = Account::<token::TokenAccount>::try_from(info)?;
+        require_keys_eq!(
+            recipient_token.mint,
+            ctx.accounts.mint.key(),
+            ErrorCode::ConstraintTokenMint
+        );
+
+        emit_cpi!(crate::event::TbtcMinted {
+            minter,
+            recipient_token: info.key(),
+            amount,
+        });
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: info.clone(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
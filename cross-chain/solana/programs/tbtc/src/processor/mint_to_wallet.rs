@@ -0,0 +1,95 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    error::TbtcError,
+    state::{Config, MinterInfo, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintToWallet<'info> {
+    // Use the correct token mint for the program.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+        mint::authority = config,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    // Require the signing minter to match a valid minter info.
+    #[account(
+        mut,
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump = minter_info.bump,
+        constraint = minter_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    minter: Signer<'info>,
+
+    /// Pays for the recipient's associated token account if it does not exist yet.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Wallet receiving tBTC. This account only needs to own the associated token account.
+    recipient: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    recipient_token: Account<'info, token::TokenAccount>,
+
+    associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MintToWallet<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        super::validate_mint(&ctx.accounts.config, &ctx.accounts.minter_info)
+    }
+}
+
+/// Same as mint, but takes the recipient's wallet and creates its associated token account if it
+/// is missing.
+#[access_control(MintToWallet::constraints(&ctx))]
+pub fn mint_to_wallet(ctx: Context<MintToWallet>, amount: u64) -> Result<()> {
+    super::record_mint(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.minter_info,
+        amount,
+    )?;
+
+    emit_cpi!(crate::event::TbtcMinted {
+        minter: ctx.accounts.minter.key(),
+        recipient_token: ctx.accounts.recipient_token.key(),
+        amount,
+    });
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+        ),
+        amount,
+    )
+}
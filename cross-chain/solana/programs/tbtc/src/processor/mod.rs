@@ -1,5 +1,20 @@
 mod admin;
 pub use admin::*;
 
+mod burn;
+pub use burn::*;
+
+mod burn_from;
+pub use burn_from::*;
+
 mod mint;
 pub use mint::*;
+
+mod mint_batch;
+pub use mint_batch::*;
+
+mod mint_to_wallet;
+pub use mint_to_wallet::*;
+
+mod reconcile;
+pub use reconcile::*;
@@ -0,0 +1,101 @@
+use crate::{
+    constants::{PAUSE_REASON_SUPPLY_DISCREPANCY, SEED_PREFIX_TBTC_MINT},
+    error::TbtcError,
+    state::{Config, GuardianInfo, Versioned},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Reconcile<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    /// Only needed for a guardian to pause the program if a discrepancy is found. This account
+    /// must be provided together with its signing guardian.
+    #[account(
+        mut,
+        constraint = guardian_info.is_current_version() @ TbtcError::AccountNotMigratable,
+    )]
+    guardian_info: Option<Account<'info, GuardianInfo>>,
+
+    guardian: Option<Signer<'info>>,
+}
+
+impl<'info> Reconcile<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        match (&ctx.accounts.guardian_info, &ctx.accounts.guardian) {
+            (Some(guardian_info), Some(guardian)) => {
+                require_keys_eq!(
+                    guardian_info.guardian,
+                    guardian.key(),
+                    TbtcError::GuardianInfoMismatch
+                );
+
+                Ok(())
+            }
+            (None, None) => Ok(()),
+            _ => err!(TbtcError::GuardianInfoMismatch),
+        }
+    }
+}
+
+/// Compare the mint's supply against the minted and burned totals. Anyone can call this, but only
+/// a supply above the expected one pauses the program, and only if a guardian signs, the program is
+/// not already paused and the guardian's cooldown has passed. Otherwise the discrepancy is only
+/// reported. A supply below the expected one is always only reported, since holders can burn their
+/// tokens through the token program without it being recorded.
+///
+/// This only covers the totals over all minters. A minter program checks its own accounting against
+/// its minter's totals, as the Wormhole Gateway's reconcile does for its minted amount, and the two
+/// together tie the supply back to what the minter holds.
+#[access_control(Reconcile::constraints(&ctx))]
+pub fn reconcile(ctx: Context<Reconcile>) -> Result<()> {
+    let supply = ctx.accounts.mint.supply;
+    let now = Clock::get()?.unix_timestamp;
+
+    let config = &mut ctx.accounts.config;
+    let expected_supply = config.expected_supply();
+    let discrepancy = supply != expected_supply;
+
+    let mut paused_by = None;
+    if let Some(guardian_info) = ctx.accounts.guardian_info.as_mut() {
+        if supply > expected_supply
+            && !config.is_paused(now)
+            && config.pause_cooldown_passed(guardian_info, now)
+        {
+            let expires_at =
+                config.start_pause(guardian_info, PAUSE_REASON_SUPPLY_DISCREPANCY, now);
+            paused_by = Some(guardian_info.guardian);
+
+            emit_cpi!(crate::event::Paused {
+                guardian: guardian_info.guardian,
+                reason: PAUSE_REASON_SUPPLY_DISCREPANCY,
+                paused_at: now,
+                expires_at,
+            });
+        }
+    }
+
+    emit_cpi!(crate::event::SupplyReconciled {
+        supply,
+        minted_total: config.minted_total,
+        burned_total: config.burned_total,
+        discrepancy,
+        paused_by,
+    });
+
+    Ok(())
+}
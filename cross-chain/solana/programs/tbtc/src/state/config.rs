@@ -1,8 +1,15 @@
+use crate::{
+    error::TbtcError,
+    state::{
+        GuardianInfo, GuardiansPage, MintersPage, Role, RoleAssignment, ScheduledChange, Versioned,
+    },
+};
 use anchor_lang::prelude::*;
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Config {
+    pub version: u8,
     pub bump: u8,
 
     /// The authority over this program.
@@ -12,13 +19,165 @@ pub struct Config {
     // Mint info.
     pub mint: Pubkey,
     pub mint_bump: u8,
+    /// Amount minted by all minters so far, including minters that have since been removed.
+    pub minted_total: u64,
+    /// Amount burned so far, whether or not the burn was attributed to a minter.
+    pub burned_total: u64,
 
     // Admin info.
     pub num_minters: u32,
     pub num_guardians: u32,
     pub paused: bool,
+
+    // Registry info. These count slots handed out in the paged registries, including slots freed by
+    // removals, so they also determine which page the next key goes into.
+    pub minter_registry_len: u32,
+    pub guardian_registry_len: u32,
+
+    // Pause info. A pause is only in effect until `pause_expires_at`.
+    pub pause_guardian: Option<Pubkey>,
+    pub pause_reason: u8,
+    pub paused_at: i64,
+    pub pause_expires_at: i64,
+    pub max_pause_duration: u32,
+    /// Seconds a guardian has to wait after its pause ends before it can pause again. Freezing a
+    /// token account again is subject to the same cooldown from the end of its latest freeze.
+    pub pause_cooldown: u32,
+
+    // Freeze info. Token accounts frozen by a guardian can be thawed by anyone after this long.
+    pub max_freeze_duration: u32,
+
+    // Timelock info.
+    /// Delay in seconds before a scheduled change can be executed. While this is nonzero, sensitive
+    /// changes can only be made through the timelock.
+    pub timelock_delay: u32,
+    pub num_scheduled_changes: u64,
 }
 
 impl Config {
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    pub const VERSION: u8 = 3;
+
+    /// Pauses expire after a week unless the authority configures otherwise.
+    pub const DEFAULT_MAX_PAUSE_DURATION: u32 = 7 * 24 * 60 * 60;
+
+    /// Guardians have to wait a day after their pause ends before pausing again unless the
+    /// authority configures otherwise.
+    pub const DEFAULT_PAUSE_COOLDOWN: u32 = 24 * 60 * 60;
+
+    /// Freezes also expire after a week unless the authority configures otherwise.
+    pub const DEFAULT_MAX_FREEZE_DURATION: u32 = 7 * 24 * 60 * 60;
+
+    /// Index of the minters page the next minter is appended to.
+    pub fn next_minter_page(&self) -> u32 {
+        self.minter_registry_len / MintersPage::MAX_KEYS
+    }
+
+    /// Index of the guardians page the next guardian is appended to.
+    pub fn next_guardian_page(&self) -> u32 {
+        self.guardian_registry_len / GuardiansPage::MAX_KEYS
+    }
+
+    /// Number of minters that still fit into the page the next minter is appended to. Slots freed by
+    /// removals are not reused, so this is determined by the registry length rather than the number
+    /// of keys in the page.
+    pub fn next_minter_page_capacity(&self) -> u32 {
+        MintersPage::MAX_KEYS - self.minter_registry_len % MintersPage::MAX_KEYS
+    }
+
+    /// Like [Config::next_minter_page_capacity], for guardians.
+    pub fn next_guardian_page_capacity(&self) -> u32 {
+        GuardiansPage::MAX_KEYS - self.guardian_registry_len % GuardiansPage::MAX_KEYS
+    }
+
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.paused && now < self.pause_expires_at
+    }
+
+    /// Supply the mint should have according to the minted and burned totals.
+    pub fn expected_supply(&self) -> u64 {
+        self.minted_total.saturating_sub(self.burned_total)
+    }
+
+    /// Whether the guardian's re-pause cooldown has passed. The cooldown runs from the end of the
+    /// guardian's last pause. If that is the latest pause, it ended at `pause_expires_at`, which
+    /// unpausing moves up. Otherwise it ended before the latest pause started.
+    pub fn pause_cooldown_passed(&self, guardian_info: &GuardianInfo, now: i64) -> bool {
+        if guardian_info.last_paused_at == 0 {
+            return true;
+        }
+
+        let pause_ended_at = if guardian_info.last_paused_at == self.paused_at {
+            self.pause_expires_at
+        } else {
+            self.paused_at
+        };
+
+        now >= pause_ended_at.saturating_add(self.pause_cooldown.into())
+    }
+
+    /// Record a pause by the guardian and return when it expires.
+    pub(crate) fn start_pause(
+        &mut self,
+        guardian_info: &mut GuardianInfo,
+        reason: u8,
+        now: i64,
+    ) -> i64 {
+        let expires_at = now.saturating_add(self.max_pause_duration.into());
+
+        self.paused = true;
+        self.pause_guardian = Some(guardian_info.guardian);
+        self.pause_reason = reason;
+        self.paused_at = now;
+        self.pause_expires_at = expires_at;
+
+        guardian_info.last_paused_at = now;
+
+        expires_at
+    }
+
+    /// Checks whether the signer is the authority or has been granted the role. The role assignment
+    /// only needs to be passed in if the signer is not the authority.
+    pub(crate) fn require_role(
+        &self,
+        signer: &Pubkey,
+        role: Role,
+        role_assignment: Option<&RoleAssignment>,
+    ) -> Result<()> {
+        if *signer == self.authority {
+            return Ok(());
+        }
+
+        match role_assignment {
+            Some(assignment) if assignment.holder == *signer && assignment.role == role => Ok(()),
+            _ => err!(TbtcError::IsNotAuthority),
+        }
+    }
+
+    /// Checks whether the signer can make a timelocked change. The timelock authority always can,
+    /// and the program authority or a holder of the role can only if no timelock delay is set.
+    pub(crate) fn require_timelocked_role(
+        &self,
+        signer: &Pubkey,
+        role: Role,
+        role_assignment: Option<&RoleAssignment>,
+    ) -> Result<()> {
+        if *signer == ScheduledChange::authority_address() {
+            return Ok(());
+        }
+
+        self.require_role(signer, role, role_assignment)?;
+        require_eq!(self.timelock_delay, 0, TbtcError::TimelockRequired);
+
+        Ok(())
+    }
+}
+
+impl Versioned for Config {
+    const CURRENT_VERSION: u8 = Self::VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
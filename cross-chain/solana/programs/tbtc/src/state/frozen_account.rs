@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Record of the latest freeze of a token account by a guardian. It is kept after the account is
+/// thawed, so that guardians cannot keep an account frozen by freezing it again right away.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct FrozenAccount {
+    pub bump: u8,
+    pub token: Pubkey,
+    /// Guardian that froze the account most recently.
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+    /// Once this has passed, anyone can thaw the account. Thawing the account early moves this up,
+    /// so it is always when the latest freeze ended or will end.
+    pub expires_at: i64,
+}
+
+impl FrozenAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"frozen-account";
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    /// Whether a guardian can freeze the account again. The cooldown runs from the end of the
+    /// latest freeze, whether it expired or the account was thawed early.
+    pub fn freeze_cooldown_passed(&self, cooldown: u32, now: i64) -> bool {
+        self.frozen_at == 0 || now >= self.expires_at.saturating_add(cooldown.into())
+    }
+}
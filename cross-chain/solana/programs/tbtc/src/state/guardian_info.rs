@@ -1,12 +1,27 @@
+use crate::state::Versioned;
 use anchor_lang::prelude::*;
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct GuardianInfo {
+    pub version: u8,
     pub bump: u8,
     pub guardian: Pubkey,
+    pub last_paused_at: i64,
+    /// Index of the `GuardiansPage` holding this guardian.
+    pub registry_page: u32,
 }
 
 impl GuardianInfo {
     pub const SEED_PREFIX: &'static [u8] = b"guardian-info";
+
+    pub const VERSION: u8 = 1;
+}
+
+impl Versioned for GuardianInfo {
+    const CURRENT_VERSION: u8 = Self::VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+/// Legacy registry holding every key in a single, reallocated account. Superseded by
+/// `GuardiansPage`, and only kept so existing accounts can be migrated.
 #[account]
 #[derive(Debug)]
 pub struct Guardians {
@@ -9,10 +11,6 @@ pub struct Guardians {
 
 impl Guardians {
     pub const SEED_PREFIX: &'static [u8] = b"guardians";
-
-    pub(crate) fn compute_size(num_guardians: usize) -> usize {
-        8 + 1 + 4 + num_guardians * 32
-    }
 }
 
 impl std::ops::Deref for Guardians {
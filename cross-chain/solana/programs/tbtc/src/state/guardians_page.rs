@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Fixed-size page of the guardian registry. Pages are filled in order and never reallocated, so
+/// the registry can grow without a ceiling on account size. Removing a guardian frees a slot in its
+/// page, but new guardians are always appended to the last page.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct GuardiansPage {
+    pub bump: u8,
+    pub index: u32,
+    #[max_len(32)]
+    pub keys: Vec<Pubkey>,
+}
+
+impl GuardiansPage {
+    pub const SEED_PREFIX: &'static [u8] = b"guardians-page";
+
+    /// Must match the `max_len` of `keys`.
+    pub const MAX_KEYS: u32 = 32;
+}
+
+impl std::ops::Deref for GuardiansPage {
+    type Target = Vec<Pubkey>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.keys
+    }
+}
+
+impl std::ops::DerefMut for GuardiansPage {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.keys
+    }
+}
@@ -0,0 +1,130 @@
+//! Superseded account layouts. These are only used to migrate existing accounts in place. Layouts
+//! from before accounts carried a version are suffixed V0. Versioned layouts store their version
+//! right after the discriminator.
+
+use crate::{error::TbtcError, state::Config};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+/// Layout of `Config` before versioning.
+#[derive(AnchorDeserialize, InitSpace)]
+pub(crate) struct ConfigV0 {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub mint: Pubkey,
+    pub mint_bump: u8,
+    pub num_minters: u32,
+    pub num_guardians: u32,
+    pub paused: bool,
+}
+
+impl ConfigV0 {
+    /// Upgrades to the current layout. A pause carried over from the legacy config expires like any
+    /// new one, and registry lengths start at zero. They grow as keys are moved out of the legacy
+    /// minters and guardians accounts. Nothing was burned through the legacy config, so the minted
+    /// total is the supply of the mint.
+    pub fn into_current(self, now: i64, minted_total: u64) -> Config {
+        let (paused_at, pause_expires_at) = if self.paused {
+            (
+                now,
+                now.saturating_add(Config::DEFAULT_MAX_PAUSE_DURATION.into()),
+            )
+        } else {
+            (0, 0)
+        };
+
+        Config {
+            version: Config::VERSION,
+            bump: self.bump,
+            authority: self.authority,
+            pending_authority: self.pending_authority,
+            mint: self.mint,
+            mint_bump: self.mint_bump,
+            minted_total,
+            burned_total: 0,
+            num_minters: self.num_minters,
+            num_guardians: self.num_guardians,
+            paused: self.paused,
+            minter_registry_len: 0,
+            guardian_registry_len: 0,
+            pause_guardian: None,
+            pause_reason: 0,
+            paused_at,
+            pause_expires_at,
+            max_pause_duration: Config::DEFAULT_MAX_PAUSE_DURATION,
+            pause_cooldown: Config::DEFAULT_PAUSE_COOLDOWN,
+            max_freeze_duration: Config::DEFAULT_MAX_FREEZE_DURATION,
+            timelock_delay: 0,
+            num_scheduled_changes: 0,
+        }
+    }
+}
+
+/// Layout of `MinterInfo` before versioning.
+#[derive(AnchorDeserialize, InitSpace)]
+pub(crate) struct MinterInfoV0 {
+    pub minter: Pubkey,
+    pub bump: u8,
+}
+
+/// Layout of `GuardianInfo` before versioning.
+#[derive(AnchorDeserialize, InitSpace)]
+pub(crate) struct GuardianInfoV0 {
+    pub bump: u8,
+    pub guardian: Pubkey,
+}
+
+/// Reads an account of type `T` stored in the legacy layout `L`. Legacy accounts are recognized
+/// by their size, so an account that has already been migrated is rejected.
+pub(crate) fn read_legacy<T, L>(info: &AccountInfo) -> Result<L>
+where
+    T: Discriminator + Owner,
+    L: AnchorDeserialize + Space,
+{
+    require_keys_eq!(
+        *info.owner,
+        T::owner(),
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == T::discriminator(),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    require_eq!(
+        data.len(),
+        8 + L::INIT_SPACE,
+        TbtcError::AccountNotMigratable
+    );
+
+    L::deserialize(&mut &data[8..]).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+}
+
+/// Writes an account in its current layout, growing it to `space` bytes and topping up its rent
+/// from the payer first.
+pub(crate) fn write_migrated<'info, T: AccountSerialize>(
+    info: &AccountInfo<'info>,
+    account: &T,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    info.realloc(space, false)?;
+    account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+}
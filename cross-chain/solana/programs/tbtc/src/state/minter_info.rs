@@ -1,12 +1,94 @@
+use crate::{error::TbtcError, state::Versioned};
 use anchor_lang::prelude::*;
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct MinterInfo {
+    pub version: u8,
     pub minter: Pubkey,
     pub bump: u8,
+    /// Index of the `MintersPage` holding this minter.
+    pub registry_page: u32,
+
+    /// Maximum amount this minter is allowed to have outstanding (minted minus burned).
+    pub allowance: u64,
+    /// Amount minted by this minter so far.
+    pub minted_total: u64,
+    /// Amount burned on behalf of this minter so far.
+    pub burned_total: u64,
+
+    // Rate limit info. A period of zero disables the rate limit.
+    pub rate_limit_amount: u64,
+    pub rate_limit_period: u32,
+    pub window_start: i64,
+    pub window_minted: u64,
+
+    /// Set by a guardian to stop this minter from minting. Only the authority can clear it.
+    pub paused: bool,
 }
 
 impl MinterInfo {
     pub const SEED_PREFIX: &'static [u8] = b"minter-info";
+
+    pub const VERSION: u8 = 1;
+
+    /// Amount minted by this minter that has not been burned yet.
+    pub fn outstanding(&self) -> u64 {
+        self.minted_total.saturating_sub(self.burned_total)
+    }
+
+    /// Account for an amount minted at the given timestamp. Fails if this amount would exceed the
+    /// minter's allowance or the amount left in its current rate limit window.
+    pub(crate) fn record_mint(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.outstanding()
+            .checked_add(amount)
+            .filter(|&outstanding| outstanding <= self.allowance)
+            .ok_or(TbtcError::MinterAllowanceExceeded)?;
+
+        if self.rate_limit_period > 0 {
+            // Start a new window if the current one has elapsed.
+            let window_end = self
+                .window_start
+                .saturating_add(self.rate_limit_period.into());
+            if now >= window_end {
+                self.window_start = now;
+                self.window_minted = 0;
+            }
+
+            self.window_minted = self
+                .window_minted
+                .checked_add(amount)
+                .filter(|&window_minted| window_minted <= self.rate_limit_amount)
+                .ok_or(TbtcError::MinterRateLimitExceeded)?;
+        }
+
+        self.minted_total = self
+            .minted_total
+            .checked_add(amount)
+            .ok_or(TbtcError::MinterAllowanceExceeded)?;
+
+        Ok(())
+    }
+
+    /// Account for an amount burned on behalf of this minter. A minter cannot burn more than it
+    /// has outstanding.
+    pub(crate) fn record_burn(&mut self, amount: u64) -> Result<()> {
+        require_gte!(
+            self.outstanding(),
+            amount,
+            TbtcError::MinterBurnExceedsOutstanding
+        );
+
+        self.burned_total += amount;
+
+        Ok(())
+    }
+}
+
+impl Versioned for MinterInfo {
+    const CURRENT_VERSION: u8 = Self::VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+/// Legacy registry holding every key in a single, reallocated account. Superseded by
+/// `MintersPage`, and only kept so existing accounts can be migrated.
 #[account]
 #[derive(Debug)]
 pub struct Minters {
@@ -9,10 +11,6 @@ pub struct Minters {
 
 impl Minters {
     pub const SEED_PREFIX: &'static [u8] = b"minters";
-
-    pub(crate) fn compute_size(num_minters: usize) -> usize {
-        8 + 1 + 4 + num_minters * 32
-    }
 }
 
 impl std::ops::Deref for Minters {
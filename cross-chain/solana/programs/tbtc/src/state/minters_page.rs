@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Fixed-size page of the minter registry. Pages are filled in order and never reallocated, so
+/// the registry can grow without a ceiling on account size. Removing a minter frees a slot in its
+/// page, but new minters are always appended to the last page.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct MintersPage {
+    pub bump: u8,
+    pub index: u32,
+    #[max_len(32)]
+    pub keys: Vec<Pubkey>,
+}
+
+impl MintersPage {
+    pub const SEED_PREFIX: &'static [u8] = b"minters-page";
+
+    /// Must match the `max_len` of `keys`.
+    pub const MAX_KEYS: u32 = 32;
+}
+
+impl std::ops::Deref for MintersPage {
+    type Target = Vec<Pubkey>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.keys
+    }
+}
+
+impl std::ops::DerefMut for MintersPage {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.keys
+    }
+}
@@ -1,14 +1,41 @@
 mod config;
 pub use config::*;
 
+mod frozen_account;
+pub use frozen_account::*;
+
 mod guardian_info;
 pub use guardian_info::*;
 
 mod guardians;
 pub use guardians::*;
 
+mod guardians_page;
+pub use guardians_page::*;
+
+mod legacy;
+pub(crate) use legacy::*;
+
 mod minter_info;
 pub use minter_info::*;
 
 mod minters;
 pub use minters::*;
+
+mod minters_page;
+pub use minters_page::*;
+
+mod multisig;
+pub use multisig::*;
+
+mod proposal;
+pub use proposal::*;
+
+mod role;
+pub use role::*;
+
+mod scheduled_change;
+pub use scheduled_change::*;
+
+mod versioned;
+pub use versioned::*;
@@ -0,0 +1,46 @@
+use crate::error::TbtcError;
+use anchor_lang::prelude::*;
+use tbtc_governance::multisig::{self, SignerSetError};
+
+/// Optional threshold authority. Once `Config.authority` is handed over to this multisig's
+/// authority PDA, admin actions are carried out by creating proposals that need `threshold`
+/// approvals from `signers` before anyone can execute them.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Multisig {
+    pub bump: u8,
+    pub authority_bump: u8,
+
+    #[max_len(16)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+
+    /// Incremented whenever the signer set changes, which invalidates outstanding proposals.
+    pub signer_set_seqno: u32,
+    pub num_proposals: u64,
+}
+
+impl Multisig {
+    pub const SEED_PREFIX: &'static [u8] = b"multisig";
+
+    /// Seed for the system-owned PDA that signs on behalf of the multisig. It must hold enough
+    /// lamports to pay for any accounts created by executed proposals.
+    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = b"multisig-authority";
+
+    pub const MAX_SIGNERS: usize = multisig::MAX_SIGNERS;
+
+    pub(crate) fn signer_index(&self, signer: &Pubkey) -> Option<usize> {
+        multisig::signer_index(&self.signers, signer)
+    }
+
+    pub(crate) fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        multisig::validate_signers(signers, threshold).map_err(|err| {
+            match err {
+                SignerSetError::TooManySigners => TbtcError::TooManyMultisigSigners,
+                SignerSetError::InvalidThreshold => TbtcError::InvalidMultisigThreshold,
+                SignerSetError::DuplicateSigner => TbtcError::DuplicateMultisigSigner,
+            }
+            .into()
+        })
+    }
+}
@@ -0,0 +1,119 @@
+use crate::state::{Role, TimelockedAction};
+use anchor_lang::prelude::*;
+use tbtc_governance::multisig;
+
+/// Admin actions that can be carried out by the multisig. Every instruction the authority can call
+/// has one, except `initialize_multisig` and `migrate_config`, which cannot be reached once the
+/// authority is the multisig.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum ProposalAction {
+    AddMinter {
+        minter: Pubkey,
+    },
+    RemoveMinter {
+        minter: Pubkey,
+    },
+    ChangeAuthority {
+        new_authority: Pubkey,
+    },
+    TakeAuthority,
+    GrantRole {
+        role: Role,
+        holder: Pubkey,
+    },
+    RevokeRole {
+        role: Role,
+        holder: Pubkey,
+    },
+    ScheduleChange {
+        action: TimelockedAction,
+    },
+    UpdateMultisig {
+        #[max_len(16)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    CancelAuthorityChange,
+    AddGuardian {
+        guardian: Pubkey,
+    },
+    RemoveGuardian {
+        guardian: Pubkey,
+    },
+    ExtendPause {
+        extension: u32,
+    },
+    Unpause,
+    UnpauseMinter {
+        minter: Pubkey,
+    },
+    UpdateMinterLimits {
+        minter: Pubkey,
+        allowance: u64,
+        rate_limit_amount: u64,
+        rate_limit_period: u32,
+    },
+    UpdatePauseParams {
+        max_pause_duration: u32,
+        pause_cooldown: u32,
+    },
+    /// Lengths are capped at what the Token Metadata program allows.
+    UpdateMetadata {
+        #[max_len(32)]
+        name: String,
+        #[max_len(10)]
+        symbol: String,
+        #[max_len(200)]
+        uri: String,
+    },
+    UpdateTimelockDelay {
+        delay: u32,
+    },
+    RecoverTokens {
+        source_token: Pubkey,
+        recipient_token: Pubkey,
+        amount: u64,
+    },
+    ThawAccount {
+        token: Pubkey,
+    },
+    ExtendFreeze {
+        token: Pubkey,
+        extension: u32,
+    },
+    UpdateMaxFreezeDuration {
+        max_freeze_duration: u32,
+    },
+    MigrateMinterInfo {
+        minter: Pubkey,
+        minted_total: u64,
+    },
+    MigrateMinters,
+    MigrateGuardianInfo {
+        guardian: Pubkey,
+    },
+    MigrateGuardians,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Proposal {
+    pub bump: u8,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+
+    /// Signer set this proposal was created for. Approvals are only valid for this set.
+    pub signer_set_seqno: u32,
+
+    /// Bitmask of approvals, indexed by the signer's position in `Multisig.signers`.
+    pub approvals: u16,
+}
+
+impl Proposal {
+    pub const SEED_PREFIX: &'static [u8] = b"proposal";
+
+    pub(crate) fn num_approvals(&self) -> u8 {
+        multisig::num_approvals(self.approvals)
+    }
+}
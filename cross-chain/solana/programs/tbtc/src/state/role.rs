@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Admin roles the authority can delegate. Each role covers a subset of the admin instructions,
+/// which the authority can always call itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum Role {
+    /// Can add and remove minters.
+    MinterAdmin,
+    /// Can add and remove guardians.
+    GuardianAdmin,
+    /// Can update the token metadata.
+    MetadataAdmin,
+    /// Can update minter allowances and rate limits.
+    LimitAdmin,
+    /// Can unpause the program and minters, and manage pause parameters.
+    PauseAdmin,
+}
+
+/// Grants a role to its holder. The account only exists while the role is granted.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RoleAssignment {
+    pub bump: u8,
+    pub role: Role,
+    pub holder: Pubkey,
+}
+
+impl RoleAssignment {
+    pub const SEED_PREFIX: &'static [u8] = b"role";
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Sensitive admin actions that are subject to the timelock delay.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum TimelockedAction {
+    AddMinter { minter: Pubkey },
+    UpdateTimelockDelay { delay: u32 },
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ScheduledChange {
+    pub bump: u8,
+    pub index: u64,
+    pub scheduled_by: Pubkey,
+    pub action: TimelockedAction,
+    pub executable_at: i64,
+}
+
+impl ScheduledChange {
+    pub const SEED_PREFIX: &'static [u8] = b"scheduled-change";
+
+    /// Seed for the system-owned PDA that signs for executed changes. It must hold enough
+    /// lamports to pay for any accounts created by them.
+    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = b"timelock-authority";
+
+    pub fn authority_address() -> Pubkey {
+        Pubkey::find_program_address(&[Self::AUTHORITY_SEED_PREFIX], &crate::ID).0
+    }
+}
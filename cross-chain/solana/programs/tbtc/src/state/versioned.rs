@@ -0,0 +1,12 @@
+/// Accounts that store the version of their layout right after the discriminator. An account in
+/// an older layout can only be migrated, so every instruction using one checks that it is current.
+pub trait Versioned {
+    /// Version of the current layout.
+    const CURRENT_VERSION: u8;
+
+    fn version(&self) -> u8;
+
+    fn is_current_version(&self) -> bool {
+        self.version() == Self::CURRENT_VERSION
+    }
+}
@@ -1,18 +1,11 @@
-pub const TBTC_ETHEREUM_TOKEN_CHAIN: u16 = 2;
+/// A.K.A. b"msg".
+pub const MSG_SEED_PREFIX: &[u8] = b"msg";
 
-#[cfg(feature = "mainnet")]
-/// tBTC token address on the Ethereum Mainnet.
-pub const TBTC_ETHEREUM_TOKEN_ADDRESS: [u8; 32] = [
-    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x18, 0x08, 0x4f, 0xba, 0x66, 0x6a,
-    0x33, 0xd3, 0x75, 0x92, 0xfa, 0x26, 0x33, 0xfd, 0x49, 0xa7, 0x4d, 0xd9, 0x3a, 0x88,
-];
+/// A.K.A. b"wrapped-token".
+pub const WRAPPED_TBTC_TOKEN_SEED_PREFIX: &[u8] = b"wrapped-token";
 
-#[cfg(feature = "solana-devnet")]
-/// tBTC token address on the Ethereum Testnet (Goerli).
-pub const TBTC_ETHEREUM_TOKEN_ADDRESS: [u8; 32] = [
-    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x67, 0x98, 0x74, 0xfb, 0xe6, 0xd4,
-    0xe7, 0xcc, 0x54, 0xa5, 0x9e, 0x31, 0x5f, 0xf1, 0xeb, 0x26, 0x66, 0x86, 0xa9, 0x37,
-];
+/// A.K.A. b"treasury".
+pub const TREASURY_SEED_PREFIX: &[u8] = b"treasury";
 
-/// A.K.A. b"msg".
-pub const MSG_SEED_PREFIX: &[u8] = b"msg";
+/// A.K.A. b"call-escrow".
+pub const CALL_ESCROW_SEED_PREFIX: &[u8] = b"call-escrow";
@@ -5,6 +5,12 @@ pub enum WormholeGatewayError {
     #[msg("Cannot mint more than the minting limit")]
     MintingLimitExceeded = 0x10,
 
+    #[msg("Cannot mint more than the inbound limit currently allows")]
+    InboundLimitExceeded = 0x12,
+
+    #[msg("Cannot send more than the outbound limit to this chain currently allows")]
+    OutboundLimitExceeded = 0x14,
+
     #[msg("Only custodian authority is permitted for this action")]
     IsNotAuthority = 0x20,
 
@@ -20,18 +26,51 @@ pub enum WormholeGatewayError {
     #[msg("Not enough wormhole tBTC in the gateway to bridge")]
     NotEnoughWrappedTbtc = 0x40,
 
+    #[msg("Cannot recover wrapped tBTC backing minted tBTC")]
+    RecoveryExceedsSurplus = 0x42,
+
+    #[msg("Cannot recover tokens from the treasury")]
+    TokenAccountNotRecoverable = 0x44,
+
     #[msg("Amount must not be 0")]
     ZeroAmount = 0x50,
 
+    #[msg("Amount is below the minimum transfer amount for this chain")]
+    TransferAmountTooSmall = 0x52,
+
+    #[msg("Amount is above the maximum transfer amount for this chain")]
+    TransferAmountTooLarge = 0x54,
+
+    #[msg("Amount does not cover the fee for this chain")]
+    AmountDoesNotCoverFee = 0x56,
+
+    #[msg("Fee cannot be more than 10000 basis points")]
+    InvalidFeeBps = 0x58,
+
+    #[msg("Minimum transfer amount cannot be above the maximum")]
+    InvalidTransferBounds = 0x5a,
+
+    #[msg("Recipient chain's gateway is disabled")]
+    GatewayDisabled = 0x60,
+
+    #[msg("Recipient chain has no registered gateway")]
+    GatewayNotRegistered = 0x62,
+
     #[msg("Token Bridge transfer already redeemed")]
     TransferAlreadyRedeemed = 0x70,
 
     #[msg("Token chain and address do not match Ethereum's tBTC")]
     InvalidEthereumTbtc = 0x80,
 
+    #[msg("Cannot change the tBTC token while wrapped tBTC is in custody")]
+    WrappedTbtcInCustody = 0x82,
+
     #[msg("No tBTC transferred")]
     NoTbtcTransferred = 0x90,
 
+    #[msg("Transfer payload is not a valid gateway message")]
+    InvalidGatewayMessage = 0x92,
+
     #[msg("0x0 receiver not allowed")]
     RecipientZeroAddress = 0xa0,
 
@@ -40,4 +79,52 @@ pub enum WormholeGatewayError {
 
     #[msg("Minted amount after deposit exceeds u64")]
     MintedAmountOverflow = 0xb2,
+
+    #[msg("Multisig threshold must be between 1 and the number of signers")]
+    InvalidMultisigThreshold = 0xc0,
+
+    #[msg("Too many multisig signers")]
+    TooManyMultisigSigners = 0xc2,
+
+    #[msg("Multisig signers must be unique")]
+    DuplicateMultisigSigner = 0xc4,
+
+    #[msg("Caller is not a multisig signer")]
+    IsNotMultisigSigner = 0xc6,
+
+    #[msg("Signer already approved this proposal")]
+    ProposalAlreadyApproved = 0xc8,
+
+    #[msg("Multisig signers changed since this proposal was created")]
+    ProposalStale = 0xca,
+
+    #[msg("Proposal does not have enough approvals")]
+    ProposalThresholdNotMet = 0xcc,
+
+    #[msg("Change must be scheduled through the timelock")]
+    TimelockRequired = 0xd0,
+
+    #[msg("Scheduled change cannot be executed yet")]
+    ScheduledChangeNotReady = 0xd2,
+
+    #[msg("Account is not in a layout that can be migrated")]
+    AccountNotMigratable = 0xe0,
+
+    #[msg("Guardian info must be provided with its guardian")]
+    GuardianInfoMismatch = 0xf0,
+
+    #[msg("Inbound transfers are disabled by the gateway mode")]
+    InboundDisabled = 0x100,
+
+    #[msg("Outbound transfers are disabled by the gateway mode")]
+    OutboundDisabled = 0x102,
+
+    #[msg("Signer is not the authority or a gateway guardian")]
+    IsNotGatewayGuardian = 0x104,
+
+    #[msg("Gateway guardians can only restrict the gateway mode")]
+    GatewayModeNotRestricted = 0x106,
+
+    #[msg("Call accounts do not match the target program in the gateway message")]
+    CallAccountsMismatch = 0x110,
 }
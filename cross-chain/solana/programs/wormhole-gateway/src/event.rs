@@ -1,9 +1,12 @@
+use crate::state::{GatewayMode, ProposalAction, TimelockedAction};
 use anchor_lang::prelude::*;
 
 #[event]
 pub struct WormholeTbtcReceived {
     pub receiver: Pubkey,
     pub amount: u64,
+    pub fee: u64,
+    pub relayer_fee: u64,
 }
 
 #[event]
@@ -14,6 +17,7 @@ pub struct WormholeTbtcSent {
     pub recipient: [u8; 32],
     pub arbiter_fee: u64,
     pub nonce: u32,
+    pub fee: u64,
 }
 
 #[event]
@@ -28,7 +32,164 @@ pub struct GatewayAddressUpdated {
     pub gateway: [u8; 32],
 }
 
+#[event]
+pub struct GatewayDisabled {
+    pub chain: u16,
+    pub gateway: [u8; 32],
+}
+
+#[event]
+pub struct GatewayLimitsUpdated {
+    pub chain: u16,
+    pub outbound_limit_amount: u64,
+    pub outbound_limit_period: u32,
+    pub min_transfer_amount: u64,
+    pub max_transfer_amount: u64,
+}
+
+#[event]
+pub struct GatewayFeesUpdated {
+    pub chain: u16,
+    pub inbound_fee_bps: u16,
+    pub inbound_min_fee: u64,
+    pub outbound_fee_bps: u16,
+    pub outbound_min_fee: u64,
+}
+
+#[event]
+pub struct TreasuryInitialized {
+    pub treasury_token: Pubkey,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CallTargetAdded {
+    pub program: Pubkey,
+}
+
+#[event]
+pub struct CallTargetRemoved {
+    pub program: Pubkey,
+}
+
+#[event]
+pub struct TargetCalled {
+    pub program: Pubkey,
+    pub amount: u64,
+    /// Amount the program left in the escrow, which is sent to the recipient.
+    pub unused_amount: u64,
+}
+
+#[event]
+pub struct TbtcTokenUpdated {
+    pub chain: u16,
+    pub address: [u8; 32],
+    pub wrapped_tbtc_mint: Pubkey,
+    pub wrapped_tbtc_token: Pubkey,
+}
+
 #[event]
 pub struct MintingLimitUpdated {
     pub minting_limit: u64,
 }
+
+#[event]
+pub struct InboundLimitUpdated {
+    pub amount: u64,
+    pub period: u32,
+}
+
+#[event]
+pub struct GatewayGuardianAdded {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct GatewayGuardianRemoved {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct GatewayModeUpdated {
+    pub mode: GatewayMode,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct TokensRecovered {
+    pub mint: Pubkey,
+    pub source_token: Pubkey,
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct TimelockDelayUpdated {
+    pub delay: u32,
+}
+
+#[event]
+pub struct ChangeScheduled {
+    pub scheduled_change: Pubkey,
+    pub index: u64,
+    pub action: TimelockedAction,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct ScheduledChangeCancelled {
+    pub scheduled_change: Pubkey,
+    pub index: u64,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct ScheduledChangeExecuted {
+    pub scheduled_change: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct MintedAmountReconciled {
+    pub minted_amount: u64,
+    pub tbtc_outstanding: u64,
+    pub discrepancy: bool,
+    pub paused_by: Option<Pubkey>,
+}
@@ -6,8 +6,16 @@ pub mod error;
 
 pub(crate) mod event;
 
+mod message;
+pub use message::*;
+
 mod processor;
 pub(crate) use processor::*;
+pub use processor::{
+    InitializeArgs, InitializeMultisigArgs, SendTbtcGatewayArgs, SendTbtcWrappedArgs,
+    UpdateGatewayAddressArgs, UpdateGatewayFeesArgs, UpdateGatewayLimitsArgs,
+    UpdateInboundLimitArgs, UpdateTbtcTokenArgs,
+};
 
 mod state;
 pub use state::*;
@@ -30,8 +38,12 @@ pub mod wormhole_gateway {
 
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>, minting_limit: u64) -> Result<()> {
-        processor::initialize(ctx, minting_limit)
+    pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+        processor::initialize(ctx, args)
+    }
+
+    pub fn migrate_custodian(ctx: Context<MigrateCustodian>) -> Result<()> {
+        processor::migrate_custodian(ctx)
     }
 
     pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
@@ -46,6 +58,45 @@ pub mod wormhole_gateway {
         processor::take_authority(ctx)
     }
 
+    pub fn initialize_multisig(
+        ctx: Context<InitializeMultisig>,
+        args: InitializeMultisigArgs,
+    ) -> Result<()> {
+        processor::initialize_multisig(ctx, args)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        processor::create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        processor::approve_proposal(ctx)
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        processor::execute_proposal(ctx)
+    }
+
+    pub fn update_timelock_delay(ctx: Context<UpdateTimelockDelay>, delay: u32) -> Result<()> {
+        processor::update_timelock_delay(ctx, delay)
+    }
+
+    pub fn schedule_change(ctx: Context<ScheduleChange>, action: TimelockedAction) -> Result<()> {
+        processor::schedule_change(ctx, action)
+    }
+
+    pub fn cancel_scheduled_change(ctx: Context<CancelScheduledChange>) -> Result<()> {
+        processor::cancel_scheduled_change(ctx)
+    }
+
+    pub fn execute_scheduled_change<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteScheduledChange<'info>>,
+    ) -> Result<()> {
+        processor::execute_scheduled_change(ctx)
+    }
+
     pub fn update_gateway_address(
         ctx: Context<UpdateGatewayAddress>,
         args: UpdateGatewayAddressArgs,
@@ -53,11 +104,82 @@ pub mod wormhole_gateway {
         processor::update_gateway_address(ctx, args)
     }
 
+    pub fn disable_gateway(ctx: Context<DisableGateway>, chain: u16) -> Result<()> {
+        processor::disable_gateway(ctx, chain)
+    }
+
+    pub fn update_gateway_limits(
+        ctx: Context<UpdateGatewayLimits>,
+        args: UpdateGatewayLimitsArgs,
+    ) -> Result<()> {
+        processor::update_gateway_limits(ctx, args)
+    }
+
+    pub fn update_gateway_fees(
+        ctx: Context<UpdateGatewayFees>,
+        args: UpdateGatewayFeesArgs,
+    ) -> Result<()> {
+        processor::update_gateway_fees(ctx, args)
+    }
+
+    pub fn update_tbtc_token(
+        ctx: Context<UpdateTbtcToken>,
+        args: UpdateTbtcTokenArgs,
+    ) -> Result<()> {
+        processor::update_tbtc_token(ctx, args)
+    }
+
+    pub fn migrate_gateway_info(ctx: Context<MigrateGatewayInfo>, chain: u16) -> Result<()> {
+        processor::migrate_gateway_info(ctx, chain)
+    }
+
     pub fn update_minting_limit(ctx: Context<UpdateMintingLimit>, new_limit: u64) -> Result<()> {
         processor::update_minting_limit(ctx, new_limit)
     }
 
-    pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, message_hash: [u8; 32]) -> Result<()> {
+    pub fn update_inbound_limit(
+        ctx: Context<UpdateInboundLimit>,
+        args: UpdateInboundLimitArgs,
+    ) -> Result<()> {
+        processor::update_inbound_limit(ctx, args)
+    }
+
+    pub fn add_gateway_guardian(ctx: Context<AddGatewayGuardian>) -> Result<()> {
+        processor::add_gateway_guardian(ctx)
+    }
+
+    pub fn remove_gateway_guardian(ctx: Context<RemoveGatewayGuardian>) -> Result<()> {
+        processor::remove_gateway_guardian(ctx)
+    }
+
+    pub fn set_gateway_mode(ctx: Context<SetGatewayMode>, mode: GatewayMode) -> Result<()> {
+        processor::set_gateway_mode(ctx, mode)
+    }
+
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        processor::initialize_treasury(ctx)
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        processor::withdraw_fees(ctx, amount)
+    }
+
+    pub fn add_call_target(ctx: Context<AddCallTarget>) -> Result<()> {
+        processor::add_call_target(ctx)
+    }
+
+    pub fn remove_call_target(ctx: Context<RemoveCallTarget>) -> Result<()> {
+        processor::remove_call_target(ctx)
+    }
+
+    pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+        processor::recover_tokens(ctx, amount)
+    }
+
+    pub fn receive_tbtc<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiveTbtc<'info>>,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
         processor::receive_tbtc(ctx, message_hash)
     }
 
@@ -78,4 +200,8 @@ pub mod wormhole_gateway {
     pub fn deposit_wormhole_tbtc(ctx: Context<DepositWormholeTbtc>, amount: u64) -> Result<()> {
         processor::deposit_wormhole_tbtc(ctx, amount)
     }
+
+    pub fn reconcile(ctx: Context<Reconcile>) -> Result<()> {
+        processor::reconcile(ctx)
+    }
 }
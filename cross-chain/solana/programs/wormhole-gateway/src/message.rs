@@ -0,0 +1,295 @@
+use crate::error::WormholeGatewayError;
+use anchor_lang::{prelude::*, solana_program::hash};
+
+/// Message a gateway attaches to the Token Bridge transfers it sends to this program.
+///
+/// Legacy messages are just the 32-byte recipient. Versioned messages start with a version byte:
+///
+/// * Version 1: recipient (32 bytes), relayer fee (u64, big-endian).
+/// * Version 2: version 1 fields, target program (32 bytes), accounts hash (32 bytes), instruction
+///   data (rest of the message).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GatewayMessage {
+    /// Receives the tBTC, or whatever the target program leaves of it if there is a call.
+    pub recipient: Pubkey,
+    /// Amount of tBTC paid to whoever redeems the transfer.
+    pub relayer_fee: u64,
+    pub call: Option<TargetCall>,
+}
+
+/// Program to deliver the received tBTC into, with the instruction data to call it with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetCall {
+    pub program: Pubkey,
+    /// Commits to the accounts the program is called with, so that whoever redeems the transfer
+    /// cannot substitute them. See [TargetCall::hash_accounts].
+    pub accounts_hash: [u8; 32],
+    pub data: Vec<u8>,
+}
+
+impl TargetCall {
+    /// SHA-256 of the accounts passed to the program after the call authority, the call escrow and
+    /// the recipient. Each account is hashed as its key followed by 1 if it is writable and 0
+    /// otherwise.
+    pub fn hash_accounts(accounts: &[AccountMeta]) -> [u8; 32] {
+        let data: Vec<u8> = accounts
+            .iter()
+            .flat_map(|meta| {
+                meta.pubkey
+                    .to_bytes()
+                    .into_iter()
+                    .chain([u8::from(meta.is_writable)])
+            })
+            .collect();
+        hash::hash(&data).to_bytes()
+    }
+}
+
+impl GatewayMessage {
+    pub const LEGACY_LEN: usize = 32;
+
+    pub const VERSION_RELAYER_FEE: u8 = 1;
+
+    pub const VERSION_CALL: u8 = 2;
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() == Self::LEGACY_LEN {
+            return Ok(Self {
+                recipient: Pubkey::try_from(data).unwrap(),
+                relayer_fee: 0,
+                call: None,
+            });
+        }
+
+        let (version, rest) = match data.split_first() {
+            Some((&version, rest)) if rest.len() >= 40 => (version, rest),
+            _ => return err!(WormholeGatewayError::InvalidGatewayMessage),
+        };
+        let (recipient, rest) = rest.split_at(32);
+        let (relayer_fee, rest) = rest.split_at(8);
+        let recipient = Pubkey::try_from(recipient).unwrap();
+        let relayer_fee = u64::from_be_bytes(relayer_fee.try_into().unwrap());
+
+        let call = match version {
+            Self::VERSION_RELAYER_FEE if rest.is_empty() => None,
+            Self::VERSION_CALL if rest.len() >= 64 => {
+                let (program, rest) = rest.split_at(32);
+                let (accounts_hash, data) = rest.split_at(32);
+                Some(TargetCall {
+                    program: Pubkey::try_from(program).unwrap(),
+                    accounts_hash: accounts_hash.try_into().unwrap(),
+                    data: data.to_vec(),
+                })
+            }
+            _ => return err!(WormholeGatewayError::InvalidGatewayMessage),
+        };
+
+        Ok(Self {
+            recipient,
+            relayer_fee,
+            call,
+        })
+    }
+
+    /// Encodes the message in its versioned format.
+    pub fn encode(&self) -> Vec<u8> {
+        let version = match self.call {
+            Some(_) => Self::VERSION_CALL,
+            None => Self::VERSION_RELAYER_FEE,
+        };
+
+        let mut data = vec![version];
+        data.extend_from_slice(self.recipient.as_ref());
+        data.extend_from_slice(&self.relayer_fee.to_be_bytes());
+        if let Some(call) = &self.call {
+            data.extend_from_slice(call.program.as_ref());
+            data.extend_from_slice(&call.accounts_hash);
+            data.extend_from_slice(&call.data);
+        }
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid() -> Error {
+        WormholeGatewayError::InvalidGatewayMessage.into()
+    }
+
+    fn versioned(version: u8, recipient: &Pubkey, relayer_fee: u64, rest: &[u8]) -> Vec<u8> {
+        let mut data = vec![version];
+        data.extend_from_slice(recipient.as_ref());
+        data.extend_from_slice(&relayer_fee.to_be_bytes());
+        data.extend_from_slice(rest);
+        data
+    }
+
+    #[test]
+    fn parses_legacy_recipient() {
+        let recipient = Pubkey::new_unique();
+
+        assert_eq!(
+            GatewayMessage::parse(recipient.as_ref()).unwrap(),
+            GatewayMessage {
+                recipient,
+                relayer_fee: 0,
+                call: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_relayer_fee_version() {
+        let recipient = Pubkey::new_unique();
+        let data = versioned(GatewayMessage::VERSION_RELAYER_FEE, &recipient, 0x0102, &[]);
+
+        assert_eq!(
+            GatewayMessage::parse(&data).unwrap(),
+            GatewayMessage {
+                recipient,
+                relayer_fee: 0x0102,
+                call: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_call_version() {
+        let recipient = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let mut rest = program.to_bytes().to_vec();
+        rest.extend_from_slice(&[7; 32]);
+        rest.extend_from_slice(&[1, 2, 3]);
+        let data = versioned(GatewayMessage::VERSION_CALL, &recipient, 5, &rest);
+
+        assert_eq!(
+            GatewayMessage::parse(&data).unwrap(),
+            GatewayMessage {
+                recipient,
+                relayer_fee: 5,
+                call: Some(TargetCall {
+                    program,
+                    accounts_hash: [7; 32],
+                    data: vec![1, 2, 3],
+                }),
+            }
+        );
+
+        // The instruction data can be empty.
+        let data = versioned(GatewayMessage::VERSION_CALL, &recipient, 5, &rest[..64]);
+        assert_eq!(
+            GatewayMessage::parse(&data).unwrap().call.unwrap().data,
+            Vec::<u8>::new()
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_messages() {
+        let recipient = Pubkey::new_unique();
+        let relayer_fee = versioned(GatewayMessage::VERSION_RELAYER_FEE, &recipient, 1, &[]);
+        let call = versioned(GatewayMessage::VERSION_CALL, &recipient, 1, &[0; 64]);
+
+        assert_eq!(GatewayMessage::parse(&[]).unwrap_err(), invalid());
+        assert_eq!(GatewayMessage::parse(&[0; 31]).unwrap_err(), invalid());
+        for len in 1..relayer_fee.len() {
+            if len != GatewayMessage::LEGACY_LEN {
+                assert_eq!(
+                    GatewayMessage::parse(&relayer_fee[..len]).unwrap_err(),
+                    invalid()
+                );
+            }
+        }
+        for len in relayer_fee.len()..call.len() {
+            assert_eq!(GatewayMessage::parse(&call[..len]).unwrap_err(), invalid());
+        }
+    }
+
+    #[test]
+    fn rejects_trailing_bytes_without_call() {
+        let data = versioned(
+            GatewayMessage::VERSION_RELAYER_FEE,
+            &Pubkey::new_unique(),
+            1,
+            &[0],
+        );
+
+        assert_eq!(GatewayMessage::parse(&data).unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        let recipient = Pubkey::new_unique();
+
+        for version in [0, 3, u8::MAX] {
+            let data = versioned(version, &recipient, 1, &[0; 64]);
+            assert_eq!(GatewayMessage::parse(&data).unwrap_err(), invalid());
+            assert_eq!(GatewayMessage::parse(&data[..41]).unwrap_err(), invalid());
+        }
+    }
+
+    #[test]
+    fn encode_round_trips() {
+        let messages = [
+            GatewayMessage {
+                recipient: Pubkey::new_unique(),
+                relayer_fee: u64::MAX,
+                call: None,
+            },
+            GatewayMessage {
+                recipient: Pubkey::new_unique(),
+                relayer_fee: 0,
+                call: Some(TargetCall {
+                    program: Pubkey::new_unique(),
+                    accounts_hash: [9; 32],
+                    data: vec![4, 5, 6],
+                }),
+            },
+        ];
+
+        for message in messages {
+            assert_eq!(GatewayMessage::parse(&message.encode()).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn hashes_keys_and_writability_in_order() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let accounts = [
+            AccountMeta::new(first, false),
+            AccountMeta::new_readonly(second, true),
+        ];
+
+        let mut expected = first.to_bytes().to_vec();
+        expected.push(1);
+        expected.extend_from_slice(second.as_ref());
+        expected.push(0);
+        assert_eq!(
+            TargetCall::hash_accounts(&accounts),
+            hash::hash(&expected).to_bytes()
+        );
+
+        // Signer flags are not committed to, but writability and order are.
+        assert_eq!(
+            TargetCall::hash_accounts(&[
+                AccountMeta::new(first, true),
+                AccountMeta::new_readonly(second, false),
+            ]),
+            TargetCall::hash_accounts(&accounts)
+        );
+        assert_ne!(
+            TargetCall::hash_accounts(&[
+                AccountMeta::new_readonly(first, false),
+                AccountMeta::new_readonly(second, true),
+            ]),
+            TargetCall::hash_accounts(&accounts)
+        );
+        assert_ne!(
+            TargetCall::hash_accounts(&[accounts[1].clone(), accounts[0].clone()]),
+            TargetCall::hash_accounts(&accounts)
+        );
+        assert_eq!(TargetCall::hash_accounts(&[]), hash::hash(&[]).to_bytes());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    constants::CALL_ESCROW_SEED_PREFIX,
+    error::WormholeGatewayError,
+    state::{CallTarget, Custodian},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct AddCallTarget<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = tbtc_mint,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CallTarget::INIT_SPACE,
+        seeds = [CallTarget::SEED_PREFIX, program.key().as_ref()],
+        bump
+    )]
+    call_target: Account<'info, CallTarget>,
+
+    /// CHECK: Program that inbound tBTC can be delivered into. This pubkey lives in `CallTarget`.
+    #[account(executable)]
+    program: AccountInfo<'info>,
+
+    tbtc_mint: Account<'info, token::Mint>,
+
+    /// CHECK: This PDA owns the call escrow and signs when target programs are called.
+    #[account(
+        seeds = [CallTarget::AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    call_authority: AccountInfo<'info>,
+
+    /// Token account that inbound tBTC is minted into before a target program is called. It is
+    /// shared by all target programs and created with the first one.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = tbtc_mint,
+        token::authority = call_authority,
+        seeds = [CALL_ESCROW_SEED_PREFIX],
+        bump
+    )]
+    call_escrow: Account<'info, token::TokenAccount>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> AddCallTarget<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts
+            .custodian
+            .require_timelocked_authority(&ctx.accounts.authority.key())
+    }
+}
+
+/// Allows inbound transfers to deliver tBTC into a program. This lets the program spend tBTC on
+/// behalf of recipients, so it is timelocked.
+#[access_control(AddCallTarget::constraints(&ctx))]
+pub fn add_call_target(ctx: Context<AddCallTarget>) -> Result<()> {
+    let program = ctx.accounts.program.key();
+
+    ctx.accounts.call_target.set_inner(CallTarget {
+        bump: ctx.bumps["call_target"],
+        program,
+    });
+
+    emit!(crate::event::CallTargetAdded { program });
+
+    Ok(())
+}
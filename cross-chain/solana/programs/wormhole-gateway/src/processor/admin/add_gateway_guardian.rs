@@ -0,0 +1,48 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayGuardian},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct AddGatewayGuardian<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GatewayGuardian::INIT_SPACE,
+        seeds = [GatewayGuardian::SEED_PREFIX, guardian.key().as_ref()],
+        bump
+    )]
+    gateway_guardian: Account<'info, GatewayGuardian>,
+
+    /// CHECK: Required authority to restrict the gateway mode. This pubkey lives in
+    /// `GatewayGuardian`.
+    guardian: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_gateway_guardian(ctx: Context<AddGatewayGuardian>) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+
+    ctx.accounts.gateway_guardian.set_inner(GatewayGuardian {
+        bump: ctx.bumps["gateway_guardian"],
+        guardian,
+    });
+
+    emit!(crate::event::GatewayGuardianAdded { guardian });
+
+    Ok(())
+}
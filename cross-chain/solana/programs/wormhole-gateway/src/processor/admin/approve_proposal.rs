@@ -0,0 +1,58 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Multisig, Proposal},
+};
+use anchor_lang::prelude::*;
+use tbtc_governance::multisig;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [Multisig::SEED_PREFIX],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [Proposal::SEED_PREFIX, &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.signer_set_seqno == multisig.signer_set_seqno @ WormholeGatewayError::ProposalStale,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    signer: Signer<'info>,
+}
+
+impl<'info> ApproveProposal<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        let signer = ctx.accounts.signer.key();
+        match ctx.accounts.multisig.signer_index(&signer) {
+            Some(index) => {
+                require!(
+                    !multisig::has_approved(ctx.accounts.proposal.approvals, index),
+                    WormholeGatewayError::ProposalAlreadyApproved
+                );
+
+                Ok(())
+            }
+            None => err!(WormholeGatewayError::IsNotMultisigSigner),
+        }
+    }
+}
+
+#[access_control(ApproveProposal::constraints(&ctx))]
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+
+    // It is safe to unwrap because the signer was checked against the multisig.
+    let index = ctx.accounts.multisig.signer_index(&signer).unwrap();
+    ctx.accounts.proposal.approvals |= multisig::approval(index);
+
+    emit!(crate::event::ProposalApproved {
+        proposal: ctx.accounts.proposal.key(),
+        signer,
+    });
+
+    Ok(())
+}
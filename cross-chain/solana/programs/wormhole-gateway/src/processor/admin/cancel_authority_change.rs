@@ -1,5 +1,6 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
+use tbtc::Versioned;
 
 #[derive(Accounts)]
 
@@ -9,7 +10,8 @@ pub struct CancelAuthorityChange<'info> {
         seeds = [Custodian::SEED_PREFIX],
         bump,
         has_one = authority @ WormholeGatewayError::IsNotAuthority,
-        constraint = custodian.pending_authority.is_some() @ WormholeGatewayError::NoPendingAuthorityChange
+        constraint = custodian.pending_authority.is_some() @ WormholeGatewayError::NoPendingAuthorityChange,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
@@ -0,0 +1,42 @@
+use crate::{error::WormholeGatewayError, state::ScheduledChange};
+use anchor_lang::prelude::*;
+use tbtc::{GuardianInfo, Versioned};
+
+/// Guardians of the tBTC program can cancel scheduled changes.
+#[derive(Accounts)]
+pub struct CancelScheduledChange<'info> {
+    #[account(
+        has_one = guardian,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump,
+        seeds::program = tbtc::ID,
+        constraint = guardian_info.is_current_version()
+            @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = scheduled_by,
+        close = scheduled_by,
+        seeds = [ScheduledChange::SEED_PREFIX, &scheduled_change.index.to_le_bytes()],
+        bump = scheduled_change.bump,
+    )]
+    scheduled_change: Account<'info, ScheduledChange>,
+
+    /// CHECK: Receives the scheduled change's lamports. This pubkey lives in `ScheduledChange`.
+    #[account(mut)]
+    scheduled_by: AccountInfo<'info>,
+}
+
+pub fn cancel_scheduled_change(ctx: Context<CancelScheduledChange>) -> Result<()> {
+    emit!(crate::event::ScheduledChangeCancelled {
+        scheduled_change: ctx.accounts.scheduled_change.key(),
+        index: ctx.accounts.scheduled_change.index,
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
+use tbtc::Versioned;
 
 #[derive(Accounts)]
 pub struct ChangeAuthority<'info> {
@@ -7,7 +8,8 @@ pub struct ChangeAuthority<'info> {
         mut,
         seeds = [Custodian::SEED_PREFIX],
         bump,
-        has_one = authority @ WormholeGatewayError::IsNotAuthority
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
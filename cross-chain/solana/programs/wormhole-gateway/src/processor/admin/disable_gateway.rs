@@ -0,0 +1,43 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct DisableGateway<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()],
+        bump = gateway_info.bump,
+        constraint = gateway_info.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    authority: Signer<'info>,
+}
+
+/// Retires a destination chain's gateway. This only restricts what the gateway can do, so it is
+/// not timelocked. Sending to the chain's gateway fails until `update_gateway_address` registers
+/// one again. The gateway info is kept, so the chain's limits and fees still apply to wrapped
+/// transfers.
+pub fn disable_gateway(ctx: Context<DisableGateway>, chain: u16) -> Result<()> {
+    ctx.accounts.gateway_info.disabled = true;
+
+    emit!(crate::event::GatewayDisabled {
+        chain,
+        gateway: ctx.accounts.gateway_info.address,
+    });
+
+    Ok(())
+}
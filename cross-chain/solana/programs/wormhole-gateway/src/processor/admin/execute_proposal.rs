@@ -0,0 +1,362 @@
+use crate::{
+    constants::{CALL_ESCROW_SEED_PREFIX, TREASURY_SEED_PREFIX, WRAPPED_TBTC_TOKEN_SEED_PREFIX},
+    error::WormholeGatewayError,
+    state::{
+        CallTarget, Custodian, GatewayGuardian, GatewayInfo, Multisig, Proposal, ProposalAction,
+        ScheduledChange,
+    },
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc_governance::Invoker;
+use wormhole_anchor_sdk::token_bridge;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [Multisig::SEED_PREFIX],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = proposer,
+        close = proposer,
+        seeds = [Proposal::SEED_PREFIX, &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.signer_set_seqno == multisig.signer_set_seqno @ WormholeGatewayError::ProposalStale,
+    )]
+    proposal: Account<'info, Proposal>,
+
+    /// CHECK: Receives the proposal's lamports. This pubkey lives in `Proposal`.
+    #[account(mut)]
+    proposer: AccountInfo<'info>,
+
+    /// CHECK: This PDA signs for the multisig when the proposal's action is invoked.
+    #[account(
+        mut,
+        seeds = [Multisig::AUTHORITY_SEED_PREFIX],
+        bump = multisig.authority_bump,
+    )]
+    multisig_authority: AccountInfo<'info>,
+
+    program: Program<'info, crate::program::WormholeGateway>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require_gte!(
+            ctx.accounts.proposal.num_approvals(),
+            ctx.accounts.multisig.threshold,
+            WormholeGatewayError::ProposalThresholdNotMet
+        );
+
+        Ok(())
+    }
+}
+
+/// Executes an approved proposal. Actions on the program's state are invoked on this program with
+/// the multisig authority as signer, so the accounts they touch must be passed in as remaining
+/// accounts.
+#[access_control(ExecuteProposal::constraints(&ctx))]
+pub fn execute_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+    let bump = [ctx.accounts.multisig.authority_bump];
+    let signer_seeds: &[&[u8]] = &[Multisig::AUTHORITY_SEED_PREFIX, &bump];
+    let invoker = Invoker::new(
+        crate::ID,
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.multisig_authority.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+        ],
+        signer_seeds,
+    );
+
+    let authority = ctx.accounts.multisig_authority.key();
+    let custodian = invoker.find_address(&[Custodian::SEED_PREFIX]);
+
+    match ctx.accounts.proposal.action.clone() {
+        ProposalAction::UpdateMintingLimit { new_limit } => invoker.invoke(
+            crate::accounts::UpdateMintingLimit {
+                custodian,
+                authority,
+            },
+            crate::instruction::UpdateMintingLimit { new_limit },
+        )?,
+        ProposalAction::UpdateGatewayAddress { chain, address } => invoker.invoke(
+            crate::accounts::UpdateGatewayAddress {
+                custodian,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                authority,
+                system_program: System::id(),
+            },
+            crate::instruction::UpdateGatewayAddress {
+                args: crate::UpdateGatewayAddressArgs { chain, address },
+            },
+        )?,
+        ProposalAction::ChangeAuthority { new_authority } => invoker.invoke(
+            crate::accounts::ChangeAuthority {
+                custodian,
+                authority,
+                new_authority,
+            },
+            crate::instruction::ChangeAuthority {},
+        )?,
+        ProposalAction::TakeAuthority => invoker.invoke(
+            crate::accounts::TakeAuthority {
+                custodian,
+                pending_authority: authority,
+            },
+            crate::instruction::TakeAuthority {},
+        )?,
+        ProposalAction::ScheduleChange { action } => {
+            // The scheduled change's address depends on the custodian's current count.
+            let index = invoker
+                .load_account::<Custodian>(custodian)?
+                .num_scheduled_changes;
+
+            invoker.invoke(
+                crate::accounts::ScheduleChange {
+                    custodian,
+                    scheduled_change: invoker
+                        .find_address(&[ScheduledChange::SEED_PREFIX, &index.to_le_bytes()]),
+                    authority,
+                    system_program: System::id(),
+                },
+                crate::instruction::ScheduleChange { action },
+            )?
+        }
+        ProposalAction::UpdateMultisig { signers, threshold } => {
+            let multisig = &mut ctx.accounts.multisig;
+            multisig.signers = signers.clone();
+            multisig.threshold = threshold;
+            multisig.signer_set_seqno += 1;
+
+            emit!(crate::event::MultisigUpdated { signers, threshold });
+        }
+        ProposalAction::UpdateTbtcToken { chain, address } => {
+            // The current custody account lives in the custodian.
+            let wrapped_tbtc_token = invoker
+                .load_account::<Custodian>(custodian)?
+                .wrapped_tbtc_token;
+            let new_wrapped_tbtc_mint = Pubkey::find_program_address(
+                &[
+                    token_bridge::WrappedMint::SEED_PREFIX,
+                    &chain.to_be_bytes(),
+                    &address,
+                ],
+                &token_bridge::program::ID,
+            )
+            .0;
+
+            invoker.invoke(
+                crate::accounts::UpdateTbtcToken {
+                    custodian,
+                    wrapped_tbtc_token,
+                    new_wrapped_tbtc_mint,
+                    new_wrapped_tbtc_token: invoker.find_address(&[
+                        WRAPPED_TBTC_TOKEN_SEED_PREFIX,
+                        new_wrapped_tbtc_mint.as_ref(),
+                    ]),
+                    authority,
+                    token_program: token::ID,
+                    system_program: System::id(),
+                },
+                crate::instruction::UpdateTbtcToken {
+                    args: crate::UpdateTbtcTokenArgs { chain, address },
+                },
+            )?
+        }
+        ProposalAction::UpdateInboundLimit { amount, period } => invoker.invoke(
+            crate::accounts::UpdateInboundLimit {
+                custodian,
+                authority,
+            },
+            crate::instruction::UpdateInboundLimit {
+                args: crate::UpdateInboundLimitArgs { amount, period },
+            },
+        )?,
+        ProposalAction::UpdateGatewayLimits {
+            chain,
+            outbound_limit_amount,
+            outbound_limit_period,
+            min_transfer_amount,
+            max_transfer_amount,
+        } => invoker.invoke(
+            crate::accounts::UpdateGatewayLimits {
+                custodian,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                authority,
+            },
+            crate::instruction::UpdateGatewayLimits {
+                args: crate::UpdateGatewayLimitsArgs {
+                    chain,
+                    outbound_limit_amount,
+                    outbound_limit_period,
+                    min_transfer_amount,
+                    max_transfer_amount,
+                },
+            },
+        )?,
+        ProposalAction::DisableGateway { chain } => invoker.invoke(
+            crate::accounts::DisableGateway {
+                custodian,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                authority,
+            },
+            crate::instruction::DisableGateway { chain },
+        )?,
+        ProposalAction::AddGatewayGuardian { guardian } => invoker.invoke(
+            crate::accounts::AddGatewayGuardian {
+                custodian,
+                authority,
+                gateway_guardian: invoker
+                    .find_address(&[GatewayGuardian::SEED_PREFIX, guardian.as_ref()]),
+                guardian,
+                system_program: System::id(),
+            },
+            crate::instruction::AddGatewayGuardian {},
+        )?,
+        ProposalAction::RemoveGatewayGuardian { guardian } => invoker.invoke(
+            crate::accounts::RemoveGatewayGuardian {
+                custodian,
+                authority,
+                gateway_guardian: invoker
+                    .find_address(&[GatewayGuardian::SEED_PREFIX, guardian.as_ref()]),
+                guardian,
+            },
+            crate::instruction::RemoveGatewayGuardian {},
+        )?,
+        ProposalAction::SetGatewayMode { mode } => invoker.invoke(
+            crate::accounts::SetGatewayMode {
+                custodian,
+                signer: authority,
+                gateway_guardian: None,
+            },
+            crate::instruction::SetGatewayMode { mode },
+        )?,
+        ProposalAction::InitializeTreasury => invoker.invoke(
+            crate::accounts::InitializeTreasury {
+                custodian,
+                authority,
+                tbtc_mint: invoker.load_account::<Custodian>(custodian)?.tbtc_mint,
+                treasury_token: invoker.find_address(&[TREASURY_SEED_PREFIX]),
+                system_program: System::id(),
+                token_program: token::ID,
+            },
+            crate::instruction::InitializeTreasury {},
+        )?,
+        ProposalAction::UpdateGatewayFees {
+            chain,
+            inbound_fee_bps,
+            inbound_min_fee,
+            outbound_fee_bps,
+            outbound_min_fee,
+        } => invoker.invoke(
+            crate::accounts::UpdateGatewayFees {
+                custodian,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                treasury_token: invoker.find_address(&[TREASURY_SEED_PREFIX]),
+                authority,
+            },
+            crate::instruction::UpdateGatewayFees {
+                args: crate::UpdateGatewayFeesArgs {
+                    chain,
+                    inbound_fee_bps,
+                    inbound_min_fee,
+                    outbound_fee_bps,
+                    outbound_min_fee,
+                },
+            },
+        )?,
+        ProposalAction::WithdrawFees {
+            recipient_token,
+            amount,
+        } => invoker.invoke(
+            crate::accounts::WithdrawFees {
+                custodian,
+                authority,
+                treasury_token: invoker.find_address(&[TREASURY_SEED_PREFIX]),
+                recipient_token,
+                token_program: token::ID,
+            },
+            crate::instruction::WithdrawFees { amount },
+        )?,
+        ProposalAction::AddCallTarget { program } => invoker.invoke(
+            crate::accounts::AddCallTarget {
+                custodian,
+                authority,
+                call_target: invoker.find_address(&[CallTarget::SEED_PREFIX, program.as_ref()]),
+                program,
+                tbtc_mint: invoker.load_account::<Custodian>(custodian)?.tbtc_mint,
+                call_authority: invoker.find_address(&[CallTarget::AUTHORITY_SEED_PREFIX]),
+                call_escrow: invoker.find_address(&[CALL_ESCROW_SEED_PREFIX]),
+                system_program: System::id(),
+                token_program: token::ID,
+            },
+            crate::instruction::AddCallTarget {},
+        )?,
+        ProposalAction::RemoveCallTarget { program } => invoker.invoke(
+            crate::accounts::RemoveCallTarget {
+                custodian,
+                authority,
+                call_target: invoker.find_address(&[CallTarget::SEED_PREFIX, program.as_ref()]),
+                program,
+            },
+            crate::instruction::RemoveCallTarget {},
+        )?,
+        ProposalAction::CancelAuthorityChange => invoker.invoke(
+            crate::accounts::CancelAuthorityChange {
+                custodian,
+                authority,
+            },
+            crate::instruction::CancelAuthorityChange {},
+        )?,
+        ProposalAction::UpdateTimelockDelay { delay } => invoker.invoke(
+            crate::accounts::UpdateTimelockDelay {
+                custodian,
+                authority,
+            },
+            crate::instruction::UpdateTimelockDelay { delay },
+        )?,
+        ProposalAction::RecoverTokens {
+            source_token,
+            recipient_token,
+            amount,
+        } => invoker.invoke(
+            crate::accounts::RecoverTokens {
+                custodian,
+                authority,
+                source_token,
+                recipient_token,
+                token_program: token::ID,
+            },
+            crate::instruction::RecoverTokens { amount },
+        )?,
+        ProposalAction::MigrateGatewayInfo { chain } => invoker.invoke(
+            crate::accounts::MigrateGatewayInfo {
+                custodian,
+                authority,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                system_program: System::id(),
+            },
+            crate::instruction::MigrateGatewayInfo { chain },
+        )?,
+    }
+
+    emit!(crate::event::ProposalExecuted {
+        proposal: ctx.accounts.proposal.key(),
+        index: ctx.accounts.proposal.index,
+    });
+
+    Ok(())
+}
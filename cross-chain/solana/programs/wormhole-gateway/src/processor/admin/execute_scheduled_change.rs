@@ -0,0 +1,209 @@
+use crate::{
+    constants::{CALL_ESCROW_SEED_PREFIX, TREASURY_SEED_PREFIX, WRAPPED_TBTC_TOKEN_SEED_PREFIX},
+    error::WormholeGatewayError,
+    state::{CallTarget, Custodian, GatewayInfo, ScheduledChange, TimelockedAction},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc_governance::Invoker;
+use wormhole_anchor_sdk::token_bridge;
+
+#[derive(Accounts)]
+pub struct ExecuteScheduledChange<'info> {
+    #[account(
+        mut,
+        has_one = scheduled_by,
+        close = scheduled_by,
+        seeds = [ScheduledChange::SEED_PREFIX, &scheduled_change.index.to_le_bytes()],
+        bump = scheduled_change.bump,
+    )]
+    scheduled_change: Account<'info, ScheduledChange>,
+
+    /// CHECK: Receives the scheduled change's lamports. This pubkey lives in `ScheduledChange`.
+    #[account(mut)]
+    scheduled_by: AccountInfo<'info>,
+
+    /// CHECK: This PDA signs for the timelock when the scheduled action is invoked.
+    #[account(
+        mut,
+        seeds = [ScheduledChange::AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    timelock_authority: AccountInfo<'info>,
+
+    program: Program<'info, crate::program::WormholeGateway>,
+}
+
+impl<'info> ExecuteScheduledChange<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require_gte!(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.scheduled_change.executable_at,
+            WormholeGatewayError::ScheduledChangeNotReady
+        );
+
+        Ok(())
+    }
+}
+
+/// Executes a scheduled change once its delay has passed. Anyone can execute it. The action is
+/// invoked on this program with the timelock authority as signer, so the accounts it touches must
+/// be passed in as remaining accounts.
+#[access_control(ExecuteScheduledChange::constraints(&ctx))]
+pub fn execute_scheduled_change<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteScheduledChange<'info>>,
+) -> Result<()> {
+    let bump = [ctx.bumps["timelock_authority"]];
+    let signer_seeds: &[&[u8]] = &[ScheduledChange::AUTHORITY_SEED_PREFIX, &bump];
+    let invoker = Invoker::new(
+        crate::ID,
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.timelock_authority.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+        ],
+        signer_seeds,
+    );
+
+    let authority = ctx.accounts.timelock_authority.key();
+    let custodian = invoker.find_address(&[Custodian::SEED_PREFIX]);
+
+    match ctx.accounts.scheduled_change.action {
+        TimelockedAction::UpdateMintingLimit { new_limit } => invoker.invoke(
+            crate::accounts::UpdateMintingLimit {
+                custodian,
+                authority,
+            },
+            crate::instruction::UpdateMintingLimit { new_limit },
+        )?,
+        TimelockedAction::UpdateGatewayAddress { chain, address } => invoker.invoke(
+            crate::accounts::UpdateGatewayAddress {
+                custodian,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                authority,
+                system_program: System::id(),
+            },
+            crate::instruction::UpdateGatewayAddress {
+                args: crate::UpdateGatewayAddressArgs { chain, address },
+            },
+        )?,
+        TimelockedAction::UpdateTimelockDelay { delay } => invoker.invoke(
+            crate::accounts::UpdateTimelockDelay {
+                custodian,
+                authority,
+            },
+            crate::instruction::UpdateTimelockDelay { delay },
+        )?,
+        TimelockedAction::UpdateTbtcToken { chain, address } => {
+            // The current custody account lives in the custodian.
+            let wrapped_tbtc_token = invoker
+                .load_account::<Custodian>(custodian)?
+                .wrapped_tbtc_token;
+            let new_wrapped_tbtc_mint = Pubkey::find_program_address(
+                &[
+                    token_bridge::WrappedMint::SEED_PREFIX,
+                    &chain.to_be_bytes(),
+                    &address,
+                ],
+                &token_bridge::program::ID,
+            )
+            .0;
+
+            invoker.invoke(
+                crate::accounts::UpdateTbtcToken {
+                    custodian,
+                    wrapped_tbtc_token,
+                    new_wrapped_tbtc_mint,
+                    new_wrapped_tbtc_token: invoker.find_address(&[
+                        WRAPPED_TBTC_TOKEN_SEED_PREFIX,
+                        new_wrapped_tbtc_mint.as_ref(),
+                    ]),
+                    authority,
+                    token_program: token::ID,
+                    system_program: System::id(),
+                },
+                crate::instruction::UpdateTbtcToken {
+                    args: crate::UpdateTbtcTokenArgs { chain, address },
+                },
+            )?
+        }
+        TimelockedAction::UpdateInboundLimit { amount, period } => invoker.invoke(
+            crate::accounts::UpdateInboundLimit {
+                custodian,
+                authority,
+            },
+            crate::instruction::UpdateInboundLimit {
+                args: crate::UpdateInboundLimitArgs { amount, period },
+            },
+        )?,
+        TimelockedAction::UpdateGatewayLimits {
+            chain,
+            outbound_limit_amount,
+            outbound_limit_period,
+            min_transfer_amount,
+            max_transfer_amount,
+        } => invoker.invoke(
+            crate::accounts::UpdateGatewayLimits {
+                custodian,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                authority,
+            },
+            crate::instruction::UpdateGatewayLimits {
+                args: crate::UpdateGatewayLimitsArgs {
+                    chain,
+                    outbound_limit_amount,
+                    outbound_limit_period,
+                    min_transfer_amount,
+                    max_transfer_amount,
+                },
+            },
+        )?,
+        TimelockedAction::UpdateGatewayFees {
+            chain,
+            inbound_fee_bps,
+            inbound_min_fee,
+            outbound_fee_bps,
+            outbound_min_fee,
+        } => invoker.invoke(
+            crate::accounts::UpdateGatewayFees {
+                custodian,
+                gateway_info: invoker
+                    .find_address(&[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()]),
+                treasury_token: invoker.find_address(&[TREASURY_SEED_PREFIX]),
+                authority,
+            },
+            crate::instruction::UpdateGatewayFees {
+                args: crate::UpdateGatewayFeesArgs {
+                    chain,
+                    inbound_fee_bps,
+                    inbound_min_fee,
+                    outbound_fee_bps,
+                    outbound_min_fee,
+                },
+            },
+        )?,
+        TimelockedAction::AddCallTarget { program } => invoker.invoke(
+            crate::accounts::AddCallTarget {
+                custodian,
+                authority,
+                call_target: invoker.find_address(&[CallTarget::SEED_PREFIX, program.as_ref()]),
+                program,
+                tbtc_mint: invoker.load_account::<Custodian>(custodian)?.tbtc_mint,
+                call_authority: invoker.find_address(&[CallTarget::AUTHORITY_SEED_PREFIX]),
+                call_escrow: invoker.find_address(&[CALL_ESCROW_SEED_PREFIX]),
+                system_program: System::id(),
+                token_program: token::ID,
+            },
+            crate::instruction::AddCallTarget {},
+        )?,
+    }
+
+    emit!(crate::event::ScheduledChangeExecuted {
+        scheduled_change: ctx.accounts.scheduled_change.key(),
+        index: ctx.accounts.scheduled_change.index,
+    });
+
+    Ok(())
+}
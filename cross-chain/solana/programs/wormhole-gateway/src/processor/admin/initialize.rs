@@ -1,12 +1,13 @@
 use crate::{
-    constants::{TBTC_ETHEREUM_TOKEN_ADDRESS, TBTC_ETHEREUM_TOKEN_CHAIN},
-    state::Custodian,
+    constants::WRAPPED_TBTC_TOKEN_SEED_PREFIX,
+    state::{Custodian, GatewayMode},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use wormhole_anchor_sdk::token_bridge;
 
 #[derive(Accounts)]
+#[instruction(args: InitializeArgs)]
 pub struct Initialize<'info> {
     #[account(mut)]
     authority: Signer<'info>,
@@ -30,11 +31,13 @@ pub struct Initialize<'info> {
     )]
     tbtc_mint: Account<'info, token::Mint>,
 
+    /// Token Bridge mint of the canonical tBTC token. This fails if the Token Bridge has not
+    /// attested the token yet.
     #[account(
         seeds = [
             token_bridge::WrappedMint::SEED_PREFIX,
-            &TBTC_ETHEREUM_TOKEN_CHAIN.to_be_bytes(),
-            TBTC_ETHEREUM_TOKEN_ADDRESS.as_ref()
+            &args.tbtc_token_chain.to_be_bytes(),
+            args.tbtc_token_address.as_ref()
         ],
         bump,
         seeds::program = token_bridge::program::ID
@@ -46,7 +49,7 @@ pub struct Initialize<'info> {
         payer = authority,
         token::mint = wrapped_tbtc_mint,
         token::authority = custodian,
-        seeds = [b"wrapped-token"],
+        seeds = [WRAPPED_TBTC_TOKEN_SEED_PREFIX],
         bump
     )]
     wrapped_tbtc_token: Account<'info, token::TokenAccount>,
@@ -63,18 +66,41 @@ pub struct Initialize<'info> {
     token_program: Program<'info, token::Token>,
 }
 
-pub fn initialize(ctx: Context<Initialize>, minting_limit: u64) -> Result<()> {
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeArgs {
+    pub minting_limit: u64,
+    pub tbtc_token_chain: u16,
+    pub tbtc_token_address: [u8; 32],
+}
+
+pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+    let InitializeArgs {
+        minting_limit,
+        tbtc_token_chain,
+        tbtc_token_address,
+    } = args;
+
     ctx.accounts.custodian.set_inner(Custodian {
+        version: Custodian::VERSION,
         bump: ctx.bumps["custodian"],
         authority: ctx.accounts.authority.key(),
         pending_authority: None,
         tbtc_mint: ctx.accounts.tbtc_mint.key(),
+        tbtc_token_chain,
+        tbtc_token_address,
         wrapped_tbtc_mint: ctx.accounts.wrapped_tbtc_mint.key(),
         wrapped_tbtc_token: ctx.accounts.wrapped_tbtc_token.key(),
         token_bridge_sender: ctx.accounts.token_bridge_sender.key(),
         token_bridge_sender_bump: ctx.bumps["token_bridge_sender"],
         minting_limit,
         minted_amount: 0,
+        inbound_limit_amount: 0,
+        inbound_limit_period: 0,
+        inbound_updated_at: 0,
+        inbound_amount: 0,
+        timelock_delay: 0,
+        num_scheduled_changes: 0,
+        mode: GatewayMode::Active,
     });
 
     Ok(())
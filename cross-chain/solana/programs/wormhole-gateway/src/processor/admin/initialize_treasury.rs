@@ -0,0 +1,44 @@
+use crate::{constants::TREASURY_SEED_PREFIX, error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        has_one = tbtc_mint,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    tbtc_mint: Account<'info, token::Mint>,
+
+    /// Token account collecting fees in tBTC. Only the custodian authority can withdraw from it.
+    #[account(
+        init,
+        payer = authority,
+        token::mint = tbtc_mint,
+        token::authority = custodian,
+        seeds = [TREASURY_SEED_PREFIX],
+        bump
+    )]
+    treasury_token: Account<'info, token::TokenAccount>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+}
+
+/// Creates the treasury, which has to exist before any fees can be set.
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    emit!(crate::event::TreasuryInitialized {
+        treasury_token: ctx.accounts.treasury_token.key(),
+    });
+
+    Ok(())
+}
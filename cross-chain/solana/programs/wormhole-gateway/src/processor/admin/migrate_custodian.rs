@@ -0,0 +1,70 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{read_legacy, write_migrated, Custodian, CustodianV0},
+};
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::token_bridge;
+
+#[derive(Accounts)]
+pub struct MigrateCustodian<'info> {
+    /// CHECK: Custodian in a legacy layout, which cannot be deserialized as `Custodian`. Its
+    /// owner, discriminator and size are checked when it is read.
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Token Bridge metadata of the custodian's wrapped tBTC mint, which records the canonical
+    /// tBTC token. Its address is checked against the legacy custodian's mint.
+    token_bridge_wrapped_asset: Account<'info, token_bridge::WrappedMeta>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Upgrades the custodian from its legacy layout. This has to happen before any gateway info is
+/// migrated, since those migrations check the authority against the upgraded custodian. The legacy
+/// custodian predates the configurable tBTC token, so it is taken from the Token Bridge's metadata
+/// of the wrapped tBTC mint the custodian already uses.
+pub fn migrate_custodian(ctx: Context<MigrateCustodian>) -> Result<()> {
+    let legacy = read_legacy::<Custodian, CustodianV0>(&ctx.accounts.custodian)?;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        legacy.authority,
+        WormholeGatewayError::IsNotAuthority
+    );
+
+    let (wrapped_asset, _) = Pubkey::find_program_address(
+        &[
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            legacy.wrapped_tbtc_mint.as_ref(),
+        ],
+        &token_bridge::program::ID,
+    );
+    require_keys_eq!(
+        ctx.accounts.token_bridge_wrapped_asset.key(),
+        wrapped_asset,
+        ErrorCode::ConstraintSeeds
+    );
+    let wrapped_meta = &ctx.accounts.token_bridge_wrapped_asset;
+    let custodian = legacy.into_current(wrapped_meta.chain, wrapped_meta.token_address);
+
+    write_migrated(
+        &ctx.accounts.custodian,
+        &custodian,
+        8 + Custodian::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit!(crate::event::AccountMigrated {
+        account: ctx.accounts.custodian.key(),
+        version: Custodian::VERSION,
+    });
+
+    Ok(())
+}
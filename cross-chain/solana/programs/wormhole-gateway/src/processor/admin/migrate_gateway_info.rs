@@ -0,0 +1,53 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{read_legacy, write_migrated, Custodian, GatewayInfo, GatewayInfoV0},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct MigrateGatewayInfo<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: Gateway info in its legacy layout, which cannot be deserialized as `GatewayInfo`.
+    /// Its owner, discriminator and size are checked when it is read.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()],
+        bump,
+    )]
+    gateway_info: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Upgrades a gateway info from its legacy layout.
+pub fn migrate_gateway_info(ctx: Context<MigrateGatewayInfo>, _chain: u16) -> Result<()> {
+    let gateway_info =
+        read_legacy::<GatewayInfo, GatewayInfoV0>(&ctx.accounts.gateway_info)?.into_current();
+
+    write_migrated(
+        &ctx.accounts.gateway_info,
+        &gateway_info,
+        8 + GatewayInfo::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit!(crate::event::AccountMigrated {
+        account: ctx.accounts.gateway_info.key(),
+        version: GatewayInfo::VERSION,
+    });
+
+    Ok(())
+}
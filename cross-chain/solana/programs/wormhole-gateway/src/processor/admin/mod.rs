@@ -1,17 +1,86 @@
+mod add_call_target;
+pub use add_call_target::*;
+
+mod add_gateway_guardian;
+pub use add_gateway_guardian::*;
+
+mod approve_proposal;
+pub use approve_proposal::*;
+
 mod cancel_authority_change;
 pub use cancel_authority_change::*;
 
+mod cancel_scheduled_change;
+pub use cancel_scheduled_change::*;
+
 mod change_authority;
 pub use change_authority::*;
 
+mod create_proposal;
+pub use create_proposal::*;
+
+mod disable_gateway;
+pub use disable_gateway::*;
+
+mod execute_proposal;
+pub use execute_proposal::*;
+
+mod execute_scheduled_change;
+pub use execute_scheduled_change::*;
+
 mod initialize;
 pub use initialize::*;
 
+mod initialize_multisig;
+pub use initialize_multisig::*;
+
+mod initialize_treasury;
+pub use initialize_treasury::*;
+
+mod migrate_custodian;
+pub use migrate_custodian::*;
+
+mod migrate_gateway_info;
+pub use migrate_gateway_info::*;
+
+mod recover_tokens;
+pub use recover_tokens::*;
+
+mod remove_call_target;
+pub use remove_call_target::*;
+
+mod remove_gateway_guardian;
+pub use remove_gateway_guardian::*;
+
+mod schedule_change;
+pub use schedule_change::*;
+
+mod set_gateway_mode;
+pub use set_gateway_mode::*;
+
 mod take_authority;
 pub use take_authority::*;
 
 mod update_gateway_address;
 pub use update_gateway_address::*;
 
+mod update_gateway_fees;
+pub use update_gateway_fees::*;
+
+mod update_gateway_limits;
+pub use update_gateway_limits::*;
+
+mod update_inbound_limit;
+pub use update_inbound_limit::*;
+
 mod update_minting_limit;
 pub use update_minting_limit::*;
+
+mod update_tbtc_token;
+pub use update_tbtc_token::*;
+
+mod update_timelock_delay;
+pub use update_timelock_delay::*;
+
+mod withdraw_fees;
+pub use withdraw_fees::*;
@@ -0,0 +1,85 @@
+use crate::{constants::TREASURY_SEED_PREFIX, error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct RecoverTokens<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+
+    /// Token account owned by the custodian PDA holding the stranded tokens.
+    #[account(
+        mut,
+        token::authority = custodian,
+    )]
+    source_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = source_token.mint,
+    )]
+    recipient_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> RecoverTokens<'info> {
+    fn constraints(ctx: &Context<Self>, amount: u64) -> Result<()> {
+        let custodian = &ctx.accounts.custodian;
+        let source_token = &ctx.accounts.source_token;
+
+        // Fees are withdrawn with withdraw_fees.
+        let (treasury_token, _) = Pubkey::find_program_address(&[TREASURY_SEED_PREFIX], &crate::ID);
+        require_keys_neq!(
+            source_token.key(),
+            treasury_token,
+            WormholeGatewayError::TokenAccountNotRecoverable
+        );
+
+        // Wrapped tBTC in custody backs the tBTC minted by this program. Only the surplus can be
+        // recovered.
+        if source_token.key() == custodian.wrapped_tbtc_token {
+            let surplus = source_token.amount.saturating_sub(custodian.minted_amount);
+            require_gte!(
+                surplus,
+                amount,
+                WormholeGatewayError::RecoveryExceedsSurplus
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(RecoverTokens::constraints(&ctx, amount))]
+pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.source_token.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.custodian.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[ctx.accounts.custodian.bump]]],
+        ),
+        amount,
+    )?;
+
+    emit!(crate::event::TokensRecovered {
+        mint: ctx.accounts.source_token.mint,
+        source_token: ctx.accounts.source_token.key(),
+        recipient_token: ctx.accounts.recipient_token.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{CallTarget, Custodian},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct RemoveCallTarget<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = program,
+        close = authority,
+        seeds = [CallTarget::SEED_PREFIX, program.key().as_ref()],
+        bump = call_target.bump,
+    )]
+    call_target: Account<'info, CallTarget>,
+
+    /// CHECK: This pubkey lives in `CallTarget`.
+    program: AccountInfo<'info>,
+}
+
+/// Transfers calling the removed program are delivered to their recipients instead.
+pub fn remove_call_target(ctx: Context<RemoveCallTarget>) -> Result<()> {
+    emit!(crate::event::CallTargetRemoved {
+        program: ctx.accounts.program.key()
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayGuardian},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct RemoveGatewayGuardian<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = guardian,
+        close = authority,
+        seeds = [GatewayGuardian::SEED_PREFIX, guardian.key().as_ref()],
+        bump = gateway_guardian.bump,
+    )]
+    gateway_guardian: Account<'info, GatewayGuardian>,
+
+    /// CHECK: This pubkey lives in `GatewayGuardian`.
+    guardian: AccountInfo<'info>,
+}
+
+pub fn remove_gateway_guardian(ctx: Context<RemoveGatewayGuardian>) -> Result<()> {
+    emit!(crate::event::GatewayGuardianRemoved {
+        guardian: ctx.accounts.guardian.key()
+    });
+
+    Ok(())
+}
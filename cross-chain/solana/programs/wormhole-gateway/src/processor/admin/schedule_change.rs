@@ -0,0 +1,59 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, ScheduledChange, TimelockedAction},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct ScheduleChange<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ScheduledChange::INIT_SPACE,
+        seeds = [ScheduledChange::SEED_PREFIX, &custodian.num_scheduled_changes.to_le_bytes()],
+        bump
+    )]
+    scheduled_change: Account<'info, ScheduledChange>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn schedule_change(ctx: Context<ScheduleChange>, action: TimelockedAction) -> Result<()> {
+    let custodian = &mut ctx.accounts.custodian;
+    let index = custodian.num_scheduled_changes;
+    let executable_at = Clock::get()?
+        .unix_timestamp
+        .saturating_add(custodian.timelock_delay.into());
+
+    ctx.accounts.scheduled_change.set_inner(ScheduledChange {
+        bump: ctx.bumps["scheduled_change"],
+        index,
+        scheduled_by: ctx.accounts.authority.key(),
+        action: action.clone(),
+        executable_at,
+    });
+
+    custodian.num_scheduled_changes += 1;
+
+    emit!(crate::event::ChangeScheduled {
+        scheduled_change: ctx.accounts.scheduled_change.key(),
+        index,
+        action,
+        executable_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayGuardian, GatewayMode},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct SetGatewayMode<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// Either the custodian authority or a gateway guardian.
+    signer: Signer<'info>,
+
+    /// Only needed if the signer is a gateway guardian.
+    #[account(
+        seeds = [GatewayGuardian::SEED_PREFIX, signer.key().as_ref()],
+        bump = gateway_guardian.bump,
+    )]
+    gateway_guardian: Option<Account<'info, GatewayGuardian>>,
+}
+
+impl<'info> SetGatewayMode<'info> {
+    fn constraints(ctx: &Context<Self>, mode: GatewayMode) -> Result<()> {
+        let custodian = &ctx.accounts.custodian;
+        if ctx.accounts.signer.key() == custodian.authority {
+            return Ok(());
+        }
+
+        // Guardians can only restrict the mode.
+        require!(
+            ctx.accounts.gateway_guardian.is_some(),
+            WormholeGatewayError::IsNotGatewayGuardian
+        );
+        require!(
+            custodian.mode.is_restricted_by(mode),
+            WormholeGatewayError::GatewayModeNotRestricted
+        );
+
+        Ok(())
+    }
+}
+
+/// Sets which directions the gateway bridges in. A gateway guardian can pause either direction
+/// during an incident, and only the authority can resume it.
+#[access_control(SetGatewayMode::constraints(&ctx, mode))]
+pub fn set_gateway_mode(ctx: Context<SetGatewayMode>, mode: GatewayMode) -> Result<()> {
+    ctx.accounts.custodian.mode = mode;
+
+    emit!(crate::event::GatewayModeUpdated {
+        mode,
+        updated_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
+use tbtc::Versioned;
 
 #[derive(Accounts)]
 pub struct TakeAuthority<'info> {
@@ -7,6 +8,7 @@ pub struct TakeAuthority<'info> {
         mut,
         seeds = [Custodian::SEED_PREFIX],
         bump,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
@@ -3,6 +3,7 @@ use crate::{
     state::{Custodian, GatewayInfo},
 };
 use anchor_lang::prelude::*;
+use tbtc::Versioned;
 
 #[derive(Accounts)]
 #[instruction(args: UpdateGatewayAddressArgs)]
@@ -10,7 +11,7 @@ pub struct UpdateGatewayAddress<'info> {
     #[account(
         seeds = [Custodian::SEED_PREFIX],
         bump = custodian.bump,
-        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
@@ -31,20 +32,42 @@ pub struct UpdateGatewayAddress<'info> {
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateGatewayAddressArgs {
-    chain: u16,
-    address: [u8; 32],
+    pub chain: u16,
+    pub address: [u8; 32],
 }
 
+impl<'info> UpdateGatewayAddress<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts
+            .custodian
+            .require_timelocked_authority(&ctx.accounts.authority.key())?;
+
+        // A gateway info created by this instruction is still zeroed. An existing one has to be
+        // current.
+        let gateway_info = &ctx.accounts.gateway_info;
+        require!(
+            gateway_info.version == 0 || gateway_info.is_current_version(),
+            WormholeGatewayError::AccountNotMigratable
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateGatewayAddress::constraints(&ctx))]
 pub fn update_gateway_address(
     ctx: Context<UpdateGatewayAddress>,
     args: UpdateGatewayAddressArgs,
 ) -> Result<()> {
     let UpdateGatewayAddressArgs { chain, address } = args;
 
-    ctx.accounts.gateway_info.set_inner(GatewayInfo {
-        bump: ctx.bumps["gateway_info"],
-        address,
-    });
+    // Limits and fees are kept when an existing gateway's address changes. Registering an address
+    // enables a disabled gateway again.
+    let gateway_info = &mut ctx.accounts.gateway_info;
+    gateway_info.version = GatewayInfo::VERSION;
+    gateway_info.bump = ctx.bumps["gateway_info"];
+    gateway_info.address = address;
+    gateway_info.disabled = false;
 
     emit!(crate::event::GatewayAddressUpdated {
         chain,
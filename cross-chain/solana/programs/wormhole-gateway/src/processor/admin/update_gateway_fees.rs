@@ -0,0 +1,96 @@
+use crate::{
+    constants::TREASURY_SEED_PREFIX,
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+#[instruction(args: UpdateGatewayFeesArgs)]
+pub struct UpdateGatewayFees<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump = gateway_info.bump,
+        constraint = gateway_info.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    /// Fees cannot be charged before the treasury collecting them exists.
+    #[account(
+        seeds = [TREASURY_SEED_PREFIX],
+        bump,
+    )]
+    treasury_token: Account<'info, token::TokenAccount>,
+
+    authority: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGatewayFeesArgs {
+    pub chain: u16,
+    pub inbound_fee_bps: u16,
+    pub inbound_min_fee: u64,
+    pub outbound_fee_bps: u16,
+    pub outbound_min_fee: u64,
+}
+
+impl<'info> UpdateGatewayFees<'info> {
+    fn constraints(ctx: &Context<Self>, args: &UpdateGatewayFeesArgs) -> Result<()> {
+        ctx.accounts
+            .custodian
+            .require_timelocked_authority(&ctx.accounts.authority.key())?;
+
+        require_gte!(
+            GatewayInfo::MAX_FEE_BPS,
+            args.inbound_fee_bps,
+            WormholeGatewayError::InvalidFeeBps
+        );
+        require_gte!(
+            GatewayInfo::MAX_FEE_BPS,
+            args.outbound_fee_bps,
+            WormholeGatewayError::InvalidFeeBps
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateGatewayFees::constraints(&ctx, &args))]
+pub fn update_gateway_fees(
+    ctx: Context<UpdateGatewayFees>,
+    args: UpdateGatewayFeesArgs,
+) -> Result<()> {
+    let UpdateGatewayFeesArgs {
+        chain,
+        inbound_fee_bps,
+        inbound_min_fee,
+        outbound_fee_bps,
+        outbound_min_fee,
+    } = args;
+
+    let gateway_info = &mut ctx.accounts.gateway_info;
+    gateway_info.inbound_fee_bps = inbound_fee_bps;
+    gateway_info.inbound_min_fee = inbound_min_fee;
+    gateway_info.outbound_fee_bps = outbound_fee_bps;
+    gateway_info.outbound_min_fee = outbound_min_fee;
+
+    emit!(crate::event::GatewayFeesUpdated {
+        chain,
+        inbound_fee_bps,
+        inbound_min_fee,
+        outbound_fee_bps,
+        outbound_min_fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+#[instruction(args: UpdateGatewayLimitsArgs)]
+pub struct UpdateGatewayLimits<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump = gateway_info.bump,
+        constraint = gateway_info.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    authority: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGatewayLimitsArgs {
+    pub chain: u16,
+    pub outbound_limit_amount: u64,
+    pub outbound_limit_period: u32,
+    pub min_transfer_amount: u64,
+    pub max_transfer_amount: u64,
+}
+
+impl<'info> UpdateGatewayLimits<'info> {
+    fn constraints(ctx: &Context<Self>, args: &UpdateGatewayLimitsArgs) -> Result<()> {
+        ctx.accounts
+            .custodian
+            .require_timelocked_authority(&ctx.accounts.authority.key())?;
+
+        // A maximum of zero means there is no maximum.
+        if args.max_transfer_amount > 0 {
+            require_gte!(
+                args.max_transfer_amount,
+                args.min_transfer_amount,
+                WormholeGatewayError::InvalidTransferBounds
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateGatewayLimits::constraints(&ctx, &args))]
+pub fn update_gateway_limits(
+    ctx: Context<UpdateGatewayLimits>,
+    args: UpdateGatewayLimitsArgs,
+) -> Result<()> {
+    let UpdateGatewayLimitsArgs {
+        chain,
+        outbound_limit_amount,
+        outbound_limit_period,
+        min_transfer_amount,
+        max_transfer_amount,
+    } = args;
+
+    let gateway_info = &mut ctx.accounts.gateway_info;
+
+    // Changing the outbound limit clears the amount counted against it.
+    if gateway_info.outbound_limit_amount != outbound_limit_amount
+        || gateway_info.outbound_limit_period != outbound_limit_period
+    {
+        gateway_info.outbound_updated_at = Clock::get()?.unix_timestamp;
+        gateway_info.outbound_amount = 0;
+    }
+
+    gateway_info.outbound_limit_amount = outbound_limit_amount;
+    gateway_info.outbound_limit_period = outbound_limit_period;
+    gateway_info.min_transfer_amount = min_transfer_amount;
+    gateway_info.max_transfer_amount = max_transfer_amount;
+
+    emit!(crate::event::GatewayLimitsUpdated {
+        chain,
+        outbound_limit_amount,
+        outbound_limit_period,
+        min_transfer_amount,
+        max_transfer_amount,
+    });
+
+    Ok(())
+}
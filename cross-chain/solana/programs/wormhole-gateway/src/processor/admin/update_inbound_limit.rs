@@ -0,0 +1,50 @@
+use crate::{error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct UpdateInboundLimit<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> UpdateInboundLimit<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts
+            .custodian
+            .require_timelocked_authority(&ctx.accounts.authority.key())
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateInboundLimitArgs {
+    pub amount: u64,
+    pub period: u32,
+}
+
+/// Configures the rolling inbound limit. A period of zero disables it. Changing the limit clears
+/// the amount counted against it.
+#[access_control(UpdateInboundLimit::constraints(&ctx))]
+pub fn update_inbound_limit(
+    ctx: Context<UpdateInboundLimit>,
+    args: UpdateInboundLimitArgs,
+) -> Result<()> {
+    let UpdateInboundLimitArgs { amount, period } = args;
+
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.inbound_limit_amount = amount;
+    custodian.inbound_limit_period = period;
+    custodian.inbound_updated_at = Clock::get()?.unix_timestamp;
+    custodian.inbound_amount = 0;
+
+    emit!(crate::event::InboundLimitUpdated { amount, period });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
+use tbtc::Versioned;
 
 #[derive(Accounts)]
 pub struct UpdateMintingLimit<'info> {
@@ -7,13 +8,22 @@ pub struct UpdateMintingLimit<'info> {
         mut,
         seeds = [Custodian::SEED_PREFIX],
         bump = custodian.bump,
-        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
     authority: Signer<'info>,
 }
 
+impl<'info> UpdateMintingLimit<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts
+            .custodian
+            .require_timelocked_authority(&ctx.accounts.authority.key())
+    }
+}
+
+#[access_control(UpdateMintingLimit::constraints(&ctx))]
 pub fn update_minting_limit(ctx: Context<UpdateMintingLimit>, new_limit: u64) -> Result<()> {
     ctx.accounts.custodian.minting_limit = new_limit;
 
@@ -0,0 +1,98 @@
+use crate::{
+    constants::WRAPPED_TBTC_TOKEN_SEED_PREFIX, error::WormholeGatewayError, state::Custodian,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc::Versioned;
+use wormhole_anchor_sdk::token_bridge;
+
+#[derive(Accounts)]
+#[instruction(args: UpdateTbtcTokenArgs)]
+pub struct UpdateTbtcToken<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = wrapped_tbtc_token,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// Current custody account, which must be empty.
+    wrapped_tbtc_token: Account<'info, token::TokenAccount>,
+
+    /// Token Bridge mint of the new canonical tBTC token.
+    #[account(
+        seeds = [
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &args.chain.to_be_bytes(),
+            args.address.as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge::program::ID
+    )]
+    new_wrapped_tbtc_mint: Account<'info, token::Mint>,
+
+    /// Custody account for the new wrapped tBTC mint.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = new_wrapped_tbtc_mint,
+        token::authority = custodian,
+        seeds = [WRAPPED_TBTC_TOKEN_SEED_PREFIX, new_wrapped_tbtc_mint.key().as_ref()],
+        bump
+    )]
+    new_wrapped_tbtc_token: Account<'info, token::TokenAccount>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTbtcTokenArgs {
+    pub chain: u16,
+    pub address: [u8; 32],
+}
+
+impl<'info> UpdateTbtcToken<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        ctx.accounts
+            .custodian
+            .require_timelocked_authority(&ctx.accounts.authority.key())?;
+
+        // Wrapped tBTC in custody backs minted tBTC and would be stranded by the switch.
+        require_eq!(
+            ctx.accounts.wrapped_tbtc_token.amount,
+            0,
+            WormholeGatewayError::WrappedTbtcInCustody
+        );
+
+        Ok(())
+    }
+}
+
+/// Points the gateway at a different canonical tBTC token, such as when a cluster moves to a new
+/// Ethereum testnet. This is only possible while nothing is in custody, and the new token gets its
+/// own custody account.
+#[access_control(UpdateTbtcToken::constraints(&ctx))]
+pub fn update_tbtc_token(ctx: Context<UpdateTbtcToken>, args: UpdateTbtcTokenArgs) -> Result<()> {
+    let UpdateTbtcTokenArgs { chain, address } = args;
+
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.tbtc_token_chain = chain;
+    custodian.tbtc_token_address = address;
+    custodian.wrapped_tbtc_mint = ctx.accounts.new_wrapped_tbtc_mint.key();
+    custodian.wrapped_tbtc_token = ctx.accounts.new_wrapped_tbtc_token.key();
+
+    emit!(crate::event::TbtcTokenUpdated {
+        chain,
+        address,
+        wrapped_tbtc_mint: custodian.wrapped_tbtc_mint,
+        wrapped_tbtc_token: custodian.wrapped_tbtc_token,
+    });
+
+    Ok(())
+}
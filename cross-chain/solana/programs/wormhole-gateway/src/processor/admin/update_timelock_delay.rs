@@ -0,0 +1,52 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, ScheduledChange},
+};
+use anchor_lang::prelude::*;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct UpdateTimelockDelay<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> UpdateTimelockDelay<'info> {
+    fn constraints(ctx: &Context<Self>, delay: u32) -> Result<()> {
+        let custodian = &ctx.accounts.custodian;
+        let authority = ctx.accounts.authority.key();
+
+        // The authority can lengthen the delay right away, but shortening it has to go through the
+        // timelock.
+        if authority != ScheduledChange::authority_address() {
+            require_keys_eq!(
+                authority,
+                custodian.authority,
+                WormholeGatewayError::IsNotAuthority
+            );
+            require_gte!(
+                delay,
+                custodian.timelock_delay,
+                WormholeGatewayError::TimelockRequired
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateTimelockDelay::constraints(&ctx, delay))]
+pub fn update_timelock_delay(ctx: Context<UpdateTimelockDelay>, delay: u32) -> Result<()> {
+    ctx.accounts.custodian.timelock_delay = delay;
+
+    emit!(crate::event::TimelockDelayUpdated { delay });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::{constants::TREASURY_SEED_PREFIX, error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tbtc::Versioned;
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED_PREFIX],
+        bump,
+    )]
+    treasury_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = treasury_token.mint,
+    )]
+    recipient_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.treasury_token.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.custodian.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[ctx.accounts.custodian.bump]]],
+        ),
+        amount,
+    )?;
+
+    emit!(crate::event::FeesWithdrawn {
+        recipient_token: ctx.accounts.recipient_token.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,18 +1,20 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use tbtc::Versioned;
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct DepositWormholeTbtc<'info> {
     /// NOTE: This account also acts as a minter for the TBTC program.
     #[account(
-        mut, 
+        mut,
         seeds = [Custodian::SEED_PREFIX],
         bump = custodian.bump,
         has_one = wrapped_tbtc_token,
         has_one = wrapped_tbtc_mint,
         has_one = tbtc_mint,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
@@ -49,17 +51,27 @@ pub struct DepositWormholeTbtc<'info> {
     recipient: Signer<'info>,
 
     /// CHECK: TBTC program requires this account.
+    #[account(mut)]
     tbtc_config: UncheckedAccount<'info>,
 
     /// CHECK: TBTC program requires this account.
+    #[account(mut)]
     tbtc_minter_info: UncheckedAccount<'info>,
 
+    /// CHECK: This account is needed for the TBTC program to emit events.
+    tbtc_event_authority: UncheckedAccount<'info>,
+
     token_program: Program<'info, token::Token>,
     tbtc_program: Program<'info, tbtc::Tbtc>,
 }
 
 impl<'info> DepositWormholeTbtc<'info> {
     fn constraints(ctx: &Context<Self>, amount: u64) -> Result<()> {
+        require!(
+            ctx.accounts.custodian.mode.allows_inbound(),
+            WormholeGatewayError::InboundDisabled
+        );
+
         let updated_minted_amount = ctx
             .accounts
             .custodian
@@ -92,7 +104,12 @@ pub fn deposit_wormhole_tbtc(ctx: Context<DepositWormholeTbtc>, amount: u64) ->
     )?;
 
     // Account for minted amount.
-    ctx.accounts.custodian.minted_amount += amount;
+    let custodian = &mut ctx.accounts.custodian;
+    require!(
+        custodian.record_inbound(amount, Clock::get()?.unix_timestamp),
+        WormholeGatewayError::InboundLimitExceeded
+    );
+    custodian.minted_amount += amount;
 
     let custodian = &ctx.accounts.custodian;
 
@@ -107,6 +124,8 @@ pub fn deposit_wormhole_tbtc(ctx: Context<DepositWormholeTbtc>, amount: u64) ->
                 minter: custodian.to_account_info(),
                 recipient_token: ctx.accounts.recipient_token.to_account_info(),
                 token_program: ctx.accounts.token_program.to_account_info(),
+                event_authority: ctx.accounts.tbtc_event_authority.to_account_info(),
+                program: ctx.accounts.tbtc_program.to_account_info(),
             },
             &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
         ),
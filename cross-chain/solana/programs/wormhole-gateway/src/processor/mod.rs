@@ -4,6 +4,9 @@ pub use admin::*;
 mod deposit_wormhole_tbtc;
 pub use deposit_wormhole_tbtc::*;
 
+mod reconcile;
+pub use reconcile::*;
+
 mod receive_tbtc;
 pub use receive_tbtc::*;
 
@@ -1,10 +1,15 @@
 use crate::{
-    constants::{TBTC_ETHEREUM_TOKEN_ADDRESS, TBTC_ETHEREUM_TOKEN_CHAIN},
+    constants::{CALL_ESCROW_SEED_PREFIX, TREASURY_SEED_PREFIX},
     error::WormholeGatewayError,
-    state::Custodian,
+    state::{CallTarget, Custodian, GatewayInfo},
+    GatewayMessage, TargetCall,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program},
 };
-use anchor_lang::prelude::*;
 use anchor_spl::{associated_token, token};
+use tbtc::Versioned;
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
@@ -22,7 +27,8 @@ pub struct ReceiveTbtc<'info> {
         bump = custodian.bump,
         has_one = wrapped_tbtc_token,
         has_one = wrapped_tbtc_mint,
-        has_one = tbtc_mint
+        has_one = tbtc_mint,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
@@ -31,7 +37,24 @@ pub struct ReceiveTbtc<'info> {
         bump,
         seeds::program = core_bridge_program
     )]
-    posted_vaa: Box<Account<'info, token_bridge::PostedTransferWith<[u8; 32]>>>,
+    posted_vaa: Box<Account<'info, token_bridge::PostedTransferWithPayload>>,
+
+    /// CHECK: Gateway info of the chain this transfer was sent from, whose inbound fee applies. This
+    /// account does not exist if the chain has no gateway, in which case no fee is charged.
+    #[account(
+        seeds = [GatewayInfo::SEED_PREFIX, &posted_vaa.emitter_chain().to_le_bytes()],
+        bump,
+    )]
+    gateway_info: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury collecting fees in tBTC. It only has to exist if the sending chain charges
+    /// an inbound fee, which cannot be set before the treasury is initialized.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED_PREFIX],
+        bump,
+    )]
+    treasury_token: UncheckedAccount<'info>,
 
     /// CHECK: This claim account is created by the Token Bridge program when it redeems its inbound
     /// transfer. By checking whether this account exists is a short-circuit way of bailing out
@@ -52,25 +75,39 @@ pub struct ReceiveTbtc<'info> {
     #[account(mut)]
     tbtc_mint: Box<Account<'info, token::Mint>>,
 
-    /// Token account for minted tBTC.
+    /// CHECK: Associated token account for minted tBTC. The gateway will create it for the
+    /// recipient if it doesn't exist.
     ///
-    /// NOTE: Because the recipient is encoded in the transfer message payload, we can check the
-    /// authority from the deserialized VAA. But we should still check whether the authority is the
-    /// zero address in access control.
+    /// NOTE: Because the recipient is encoded in the transfer's gateway message, we can check the
+    /// authority against the parsed message in access control, where we also check whether the
+    /// authority is the zero address.
     #[account(
         mut,
-        token::mint = tbtc_mint,
-        token::authority = recipient,
+        address = associated_token::get_associated_token_address(
+            &recipient.key(),
+            &tbtc_mint.key()
+        ),
     )]
-    recipient_token: Box<Account<'info, token::TokenAccount>>,
+    recipient_token: AccountInfo<'info>,
 
     /// CHECK: This account needs to be in the context in case an associated token account needs to
-    /// be created for him.
-    #[account(address = Pubkey::from(*posted_vaa.data().message()))]
+    /// be created for him. It is checked against the gateway message in access control.
     recipient: AccountInfo<'info>,
 
-    /// CHECK: This account exists just in case the minting limit is breached after this transfer.
-    /// The gateway will create an associated token account for the recipient if it doesn't exist.
+    /// CHECK: Associated token account of the payer, who relays this transfer and is paid the
+    /// relayer fee in tBTC. The gateway will create it if there is a fee and it doesn't exist.
+    #[account(
+        mut,
+        address = associated_token::get_associated_token_address(
+            &payer.key(),
+            &tbtc_mint.key()
+        ),
+    )]
+    payer_token: AccountInfo<'info>,
+
+    /// CHECK: This account exists just in case the minting or inbound limit is breached by this
+    /// transfer. The gateway will create an associated token account for the recipient if it
+    /// doesn't exist.
     ///
     /// NOTE: When the minting limit increases, the recipient can use this token account to mint
     /// tBTC using the deposit_wormhole_tbtc instruction.
@@ -83,12 +120,39 @@ pub struct ReceiveTbtc<'info> {
     )]
     recipient_wrapped_token: AccountInfo<'info>,
 
+    /// CHECK: Allow-list entry of the program named in the gateway message. It must be provided if
+    /// the message names a program, which is only called if this account exists.
+    call_target: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This PDA signs when the target program is called.
+    #[account(
+        seeds = [CallTarget::AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    call_authority: Option<UncheckedAccount<'info>>,
+
+    /// Escrow that tBTC is minted into before the target program is called.
+    #[account(
+        mut,
+        seeds = [CALL_ESCROW_SEED_PREFIX],
+        bump,
+    )]
+    call_escrow: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    /// CHECK: Program named in the gateway message, which is checked before it is called.
+    target_program: Option<UncheckedAccount<'info>>,
+
     /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
     tbtc_config: UncheckedAccount<'info>,
 
     /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
     tbtc_minter_info: UncheckedAccount<'info>,
 
+    /// CHECK: This account is needed for the TBTC program to emit events.
+    tbtc_event_authority: UncheckedAccount<'info>,
+
     /// CHECK: This account is needed for the Token Bridge program.
     token_bridge_config: UncheckedAccount<'info>,
 
@@ -114,17 +178,23 @@ pub struct ReceiveTbtc<'info> {
 
 impl<'info> ReceiveTbtc<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.custodian.mode.allows_inbound(),
+            WormholeGatewayError::InboundDisabled
+        );
+
         // Check if transfer has already been claimed.
         require!(
             ctx.accounts.token_bridge_claim.data_is_empty(),
             WormholeGatewayError::TransferAlreadyRedeemed
         );
 
-        // Token info must match Ethereum's canonical tBTC token info.
+        // Token info must match the canonical tBTC token info.
+        let custodian = &ctx.accounts.custodian;
         let transfer = ctx.accounts.posted_vaa.data();
         require!(
-            transfer.token_chain() == TBTC_ETHEREUM_TOKEN_CHAIN
-                && *transfer.token_address() == TBTC_ETHEREUM_TOKEN_ADDRESS,
+            transfer.token_chain() == custodian.tbtc_token_chain
+                && *transfer.token_address() == custodian.tbtc_token_address,
             WormholeGatewayError::InvalidEthereumTbtc
         );
 
@@ -135,19 +205,58 @@ impl<'info> ReceiveTbtc<'info> {
             WormholeGatewayError::NoTbtcTransferred
         );
 
-        // Recipient must not be zero address.
+        Ok(())
+    }
+
+    /// Parses the transfer's gateway message and checks the accounts it names. The handler calls
+    /// this right after the access control and keeps the parsed message.
+    fn parse_message(&self) -> Result<GatewayMessage> {
+        // Recipient must be the one in the gateway message and must not be zero address.
+        let message = GatewayMessage::parse(self.posted_vaa.data().message())?;
+        require_keys_eq!(
+            self.recipient.key(),
+            message.recipient,
+            ErrorCode::ConstraintAddress
+        );
         require_keys_neq!(
-            ctx.accounts.recipient.key(),
+            message.recipient,
             Pubkey::default(),
             WormholeGatewayError::RecipientZeroAddress
         );
 
-        Ok(())
+        // Whether a program is called depends on its allow-list entry, so the entry cannot be
+        // left out.
+        if let Some(call) = &message.call {
+            let (call_target, _) = Pubkey::find_program_address(
+                &[CallTarget::SEED_PREFIX, call.program.as_ref()],
+                &crate::ID,
+            );
+            require!(
+                matches!(&self.call_target, Some(info) if info.key() == call_target),
+                WormholeGatewayError::CallAccountsMismatch
+            );
+        }
+
+        Ok(message)
     }
 }
 
+/// If the gateway message names an allowed program, the received tBTC is minted into the call
+/// escrow and the program is called with the call authority, the escrow, the recipient and the
+/// remaining accounts followed by the instruction data in the message. The remaining accounts must
+/// match the accounts hash in the message. Whatever tBTC the program leaves in the escrow goes to
+/// the recipient.
+///
+/// A failed call fails the whole redemption. The recipient can then redeem the transfer without
+/// the call by signing for it, and anyone can once `CallTarget::CALL_TIMEOUT` has passed since the
+/// transfer was sent. Transfers that name a program that is not allowed, or that cannot be minted,
+/// are delivered to the recipient without the call.
 #[access_control(ReceiveTbtc::constraints(&ctx))]
-pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, _message_hash: [u8; 32]) -> Result<()> {
+pub fn receive_tbtc<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiveTbtc<'info>>,
+    _message_hash: [u8; 32],
+) -> Result<()> {
+    let message = ctx.accounts.parse_message()?;
     let wrapped_tbtc_token = &ctx.accounts.wrapped_tbtc_token;
     let wrapped_tbtc_mint = &ctx.accounts.wrapped_tbtc_mint;
 
@@ -184,18 +293,35 @@ pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, _message_hash: [u8; 32]) -> Resul
     let amount = ctx.accounts.posted_vaa.data().amount();
     let recipient = &ctx.accounts.recipient;
 
-    emit!(crate::event::WormholeTbtcReceived {
-        receiver: recipient.key(),
-        amount
-    });
-
     let updated_minted_amount = ctx.accounts.custodian.minted_amount.saturating_add(amount);
     let custodian_seeds = &[Custodian::SEED_PREFIX, &[ctx.accounts.custodian.bump]];
 
+    let fee = crate::load_gateway_info(&ctx.accounts.gateway_info)?
+        .map(|gateway_info| gateway_info.inbound_fee(amount))
+        .unwrap_or_default();
+
+    // The inbound limit is only consumed if the minting limit allows minting this amount. An
+    // amount that does not cover the fee is not minted either, so that the fee never takes all of
+    // it.
+    let can_mint = fee < amount
+        && updated_minted_amount <= ctx.accounts.custodian.minting_limit
+        && ctx
+            .accounts
+            .custodian
+            .record_inbound(amount, Clock::get()?.unix_timestamp);
+
     // We send Wormhole tBTC OR mint canonical tBTC. We do not want to send dust. Sending Wormhole
-    // tBTC is an exceptional situation and we want to keep it simple.
-    if updated_minted_amount > ctx.accounts.custodian.minting_limit {
-        msg!("Insufficient minted amount. Sending Wormhole tBTC instead");
+    // tBTC is an exceptional situation and we want to keep it simple, so no fees are charged for
+    // it.
+    if !can_mint {
+        msg!("Insufficient minting capacity or amount. Sending Wormhole tBTC instead");
+
+        emit!(crate::event::WormholeTbtcReceived {
+            receiver: recipient.key(),
+            amount,
+            fee: 0,
+            relayer_fee: 0,
+        });
 
         let ata = &ctx.accounts.recipient_wrapped_token;
 
@@ -232,20 +358,199 @@ pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, _message_hash: [u8; 32]) -> Resul
         // call that does not allow to use the same VAA again.
         ctx.accounts.custodian.minted_amount = updated_minted_amount;
 
-        tbtc::cpi::mint(
-            CpiContext::new_with_signer(
-                ctx.accounts.tbtc_program.to_account_info(),
-                tbtc::cpi::accounts::Mint {
+        // The relayer fee is paid out of what is left after the protocol fee.
+        let relayer_fee = message.relayer_fee.min(amount - fee);
+        let received_amount = amount - fee - relayer_fee;
+
+        emit!(crate::event::WormholeTbtcReceived {
+            receiver: recipient.key(),
+            amount: received_amount,
+            fee,
+            relayer_fee,
+        });
+
+        let payer_token = &ctx.accounts.payer_token;
+
+        // Create associated token account for the payer if it is owed a relayer fee.
+        if relayer_fee > 0 && payer_token.data_is_empty() {
+            associated_token::create(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: ctx.accounts.payer.to_account_info(),
+                    associated_token: payer_token.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
                     mint: ctx.accounts.tbtc_mint.to_account_info(),
-                    config: ctx.accounts.tbtc_config.to_account_info(),
-                    minter_info: ctx.accounts.tbtc_minter_info.to_account_info(),
-                    minter: ctx.accounts.custodian.to_account_info(),
-                    recipient_token: ctx.accounts.recipient_token.to_account_info(),
                     token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
                 },
-                &[custodian_seeds],
+            ))?;
+        }
+
+        if fee > 0 {
+            mint_tbtc(ctx.accounts, &ctx.accounts.treasury_token, fee)?;
+        }
+
+        if relayer_fee > 0 {
+            mint_tbtc(ctx.accounts, payer_token, relayer_fee)?;
+        }
+
+        // The recipient opts out of the call by signing. Once the call times out, the transfer is
+        // delivered without it.
+        let call_timed_out = Clock::get()?.unix_timestamp
+            >= i64::from(ctx.accounts.posted_vaa.timestamp())
+                .saturating_add(CallTarget::CALL_TIMEOUT);
+        let call = message.call.filter(|_| {
+            !ctx.accounts.recipient.is_signer
+                && !call_timed_out
+                && matches!(&ctx.accounts.call_target, Some(info) if !info.data_is_empty())
+        });
+
+        match call {
+            Some(call) => call_target_program(ctx, call, received_amount),
+            None => {
+                create_recipient_token(ctx.accounts)?;
+                mint_tbtc(ctx.accounts, &ctx.accounts.recipient_token, received_amount)
+            }
+        }
+    }
+}
+
+fn call_target_program<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiveTbtc<'info>>,
+    call: TargetCall,
+    amount: u64,
+) -> Result<()> {
+    let (Some(call_authority), Some(call_escrow), Some(target_program)) = (
+        &ctx.accounts.call_authority,
+        &ctx.accounts.call_escrow,
+        &ctx.accounts.target_program,
+    ) else {
+        return err!(WormholeGatewayError::CallAccountsMismatch);
+    };
+    require_keys_eq!(
+        target_program.key(),
+        call.program,
+        WormholeGatewayError::CallAccountsMismatch
+    );
+
+    // Remaining accounts are passed through to the program, but never as signers. They have to be
+    // the ones the sender committed to.
+    let remaining_accounts: Vec<_> = ctx
+        .remaining_accounts
+        .iter()
+        .map(|info| AccountMeta {
+            pubkey: info.key(),
+            is_signer: false,
+            is_writable: info.is_writable,
+        })
+        .collect();
+    require!(
+        TargetCall::hash_accounts(&remaining_accounts) == call.accounts_hash,
+        WormholeGatewayError::CallAccountsMismatch
+    );
+
+    let call_authority = call_authority.to_account_info();
+    let call_escrow_info = call_escrow.to_account_info();
+    let recipient = ctx.accounts.recipient.to_account_info();
+    let target_program = target_program.to_account_info();
+
+    mint_tbtc(ctx.accounts, &call_escrow_info, amount)?;
+
+    // The program cannot call back into this program, because Solana does not allow indirect
+    // reentrancy.
+    let mut accounts = vec![
+        AccountMeta::new_readonly(call_authority.key(), true),
+        AccountMeta::new(call_escrow_info.key(), false),
+        AccountMeta::new_readonly(recipient.key(), false),
+    ];
+    accounts.extend(remaining_accounts);
+
+    let mut account_infos = vec![call_authority.clone(), call_escrow_info.clone(), recipient];
+    account_infos.extend_from_slice(ctx.remaining_accounts);
+    account_infos.push(target_program);
+
+    let call_authority_seeds = &[
+        CallTarget::AUTHORITY_SEED_PREFIX,
+        &[ctx.bumps["call_authority"]],
+    ];
+    program::invoke_signed(
+        &Instruction {
+            program_id: call.program,
+            accounts,
+            data: call.data,
+        },
+        &account_infos,
+        &[call_authority_seeds],
+    )?;
+
+    // Send whatever the program left in the escrow to the recipient.
+    let unused_amount = token::accessor::amount(&call_escrow_info)?;
+    if unused_amount > 0 {
+        create_recipient_token(ctx.accounts)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: call_escrow_info,
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: call_authority,
+                },
+                &[call_authority_seeds],
             ),
-            amount,
-        )
+            unused_amount,
+        )?;
     }
+
+    emit!(crate::event::TargetCalled {
+        program: call.program,
+        amount,
+        unused_amount,
+    });
+
+    Ok(())
+}
+
+/// Creates the recipient's associated token account for tBTC if it doesn't exist already.
+fn create_recipient_token(accounts: &ReceiveTbtc) -> Result<()> {
+    let ata = &accounts.recipient_token;
+    if ata.data_is_empty() {
+        associated_token::create(CpiContext::new(
+            accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: accounts.payer.to_account_info(),
+                associated_token: ata.to_account_info(),
+                authority: accounts.recipient.to_account_info(),
+                mint: accounts.tbtc_mint.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+            },
+        ))?;
+    }
+
+    Ok(())
+}
+
+fn mint_tbtc<'info>(
+    accounts: &ReceiveTbtc<'info>,
+    recipient_token: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    tbtc::cpi::mint(
+        CpiContext::new_with_signer(
+            accounts.tbtc_program.to_account_info(),
+            tbtc::cpi::accounts::Mint {
+                mint: accounts.tbtc_mint.to_account_info(),
+                config: accounts.tbtc_config.to_account_info(),
+                minter_info: accounts.tbtc_minter_info.to_account_info(),
+                minter: accounts.custodian.to_account_info(),
+                recipient_token: recipient_token.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+                event_authority: accounts.tbtc_event_authority.to_account_info(),
+                program: accounts.tbtc_program.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[accounts.custodian.bump]]],
+        ),
+        amount,
+    )
 }
@@ -0,0 +1,117 @@
+use crate::{error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+use tbtc::{Config, GuardianInfo, MinterInfo, Versioned, PAUSE_REASON_SUPPLY_DISCREPANCY};
+
+#[derive(Accounts)]
+pub struct Reconcile<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = tbtc_config.bump,
+        seeds::program = tbtc::ID,
+        constraint = tbtc_config.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    tbtc_config: Account<'info, Config>,
+
+    #[account(
+        seeds = [MinterInfo::SEED_PREFIX, custodian.key().as_ref()],
+        bump = tbtc_minter_info.bump,
+        seeds::program = tbtc::ID,
+        constraint = tbtc_minter_info.is_current_version()
+            @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    tbtc_minter_info: Account<'info, MinterInfo>,
+
+    /// Only needed for a guardian of the tBTC program to pause it if a discrepancy is found. This
+    /// account must be provided together with its signing guardian.
+    #[account(
+        mut,
+        constraint = tbtc_guardian_info.is_current_version()
+            @ WormholeGatewayError::AccountNotMigratable,
+    )]
+    tbtc_guardian_info: Option<Account<'info, GuardianInfo>>,
+
+    tbtc_guardian: Option<Signer<'info>>,
+
+    /// CHECK: This account is needed for the TBTC program to emit events.
+    tbtc_event_authority: UncheckedAccount<'info>,
+
+    tbtc_program: Program<'info, tbtc::Tbtc>,
+}
+
+impl<'info> Reconcile<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        match (
+            &ctx.accounts.tbtc_guardian_info,
+            &ctx.accounts.tbtc_guardian,
+        ) {
+            (Some(guardian_info), Some(guardian)) => {
+                require_keys_eq!(
+                    guardian_info.guardian,
+                    guardian.key(),
+                    WormholeGatewayError::GuardianInfoMismatch
+                );
+
+                Ok(())
+            }
+            (None, None) => Ok(()),
+            _ => err!(WormholeGatewayError::GuardianInfoMismatch),
+        }
+    }
+}
+
+/// Compare the amount the gateway has minted against what the tBTC program has outstanding for the
+/// custodian. Anyone can call this, but a discrepancy only pauses the tBTC program if a guardian
+/// signs, the program is not already paused and the guardian's cooldown has passed.
+///
+/// Together with the tBTC program's reconcile, which checks the mint's supply against the totals
+/// over all minters, this ties the supply back to the wrapped tBTC the gateway has minted against.
+#[access_control(Reconcile::constraints(&ctx))]
+pub fn reconcile(ctx: Context<Reconcile>) -> Result<()> {
+    let minted_amount = ctx.accounts.custodian.minted_amount;
+    let tbtc_outstanding = ctx.accounts.tbtc_minter_info.outstanding();
+    let discrepancy = minted_amount != tbtc_outstanding;
+
+    let mut paused_by = None;
+    if let (Some(guardian_info), Some(guardian)) = (
+        &ctx.accounts.tbtc_guardian_info,
+        &ctx.accounts.tbtc_guardian,
+    ) {
+        let config = &ctx.accounts.tbtc_config;
+        let now = Clock::get()?.unix_timestamp;
+
+        if discrepancy && !config.is_paused(now) && config.pause_cooldown_passed(guardian_info, now)
+        {
+            tbtc::cpi::pause(
+                CpiContext::new(
+                    ctx.accounts.tbtc_program.to_account_info(),
+                    tbtc::cpi::accounts::Pause {
+                        config: config.to_account_info(),
+                        guardian_info: guardian_info.to_account_info(),
+                        guardian: guardian.to_account_info(),
+                        event_authority: ctx.accounts.tbtc_event_authority.to_account_info(),
+                        program: ctx.accounts.tbtc_program.to_account_info(),
+                    },
+                ),
+                PAUSE_REASON_SUPPLY_DISCREPANCY,
+            )?;
+            paused_by = Some(guardian.key());
+        }
+    }
+
+    emit!(crate::event::MintedAmountReconciled {
+        minted_amount,
+        tbtc_outstanding,
+        discrepancy,
+        paused_by,
+    });
+
+    Ok(())
+}
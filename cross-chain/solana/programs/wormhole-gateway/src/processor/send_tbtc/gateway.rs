@@ -1,9 +1,11 @@
 use crate::{
-    constants::MSG_SEED_PREFIX,
+    constants::{MSG_SEED_PREFIX, TREASURY_SEED_PREFIX},
+    error::WormholeGatewayError,
     state::{Custodian, GatewayInfo},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use tbtc::Versioned;
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
@@ -13,21 +15,25 @@ use wormhole_anchor_sdk::{
 #[instruction(args: SendTbtcGatewayArgs)]
 pub struct SendTbtcGateway<'info> {
     #[account(
-        mut, 
+        mut,
         seeds = [Custodian::SEED_PREFIX],
         bump = custodian.bump,
         has_one = wrapped_tbtc_token,
         has_one = wrapped_tbtc_mint,
         has_one = tbtc_mint,
         has_one = token_bridge_sender,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
+    /// CHECK: Gateway info of the recipient chain. This account does not exist if the chain's
+    /// gateway was disabled or never registered, which is checked in access control.
     #[account(
+        mut,
         seeds = [GatewayInfo::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
-        bump = gateway_info.bump,
+        bump,
     )]
-    gateway_info: Account<'info, GatewayInfo>,
+    gateway_info: UncheckedAccount<'info>,
 
     /// Custody account.
     #[account(mut)]
@@ -50,6 +56,26 @@ pub struct SendTbtcGateway<'info> {
     #[account(mut)]
     sender: Signer<'info>,
 
+    /// CHECK: Treasury collecting fees in tBTC. It only has to exist if the recipient chain charges
+    /// an outbound fee, which cannot be set before the treasury is initialized.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED_PREFIX],
+        bump,
+    )]
+    treasury_token: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_minter_info: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TBTC program to emit events.
+    tbtc_event_authority: UncheckedAccount<'info>,
+
     /// CHECK: This account is needed for the Token Bridge program.
     token_bridge_config: UncheckedAccount<'info>,
 
@@ -95,6 +121,7 @@ pub struct SendTbtcGateway<'info> {
     /// CHECK: This account is needed for the Token Bridge program.
     rent: UncheckedAccount<'info>,
 
+    tbtc_program: Program<'info, tbtc::Tbtc>,
     token_bridge_program: Program<'info, TokenBridge>,
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
@@ -103,7 +130,13 @@ pub struct SendTbtcGateway<'info> {
 
 impl<'info> SendTbtcGateway<'info> {
     fn constraints(ctx: &Context<Self>, args: &SendTbtcGatewayArgs) -> Result<()> {
+        require!(
+            !ctx.accounts.gateway_info.data_is_empty(),
+            WormholeGatewayError::GatewayNotRegistered
+        );
+
         super::validate_send(
+            &ctx.accounts.custodian,
             &ctx.accounts.wrapped_tbtc_token,
             &args.recipient,
             args.amount,
@@ -113,10 +146,10 @@ impl<'info> SendTbtcGateway<'info> {
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct SendTbtcGatewayArgs {
-    amount: u64,
-    recipient_chain: u16,
-    recipient: [u8; 32],
-    nonce: u32,
+    pub amount: u64,
+    pub recipient_chain: u16,
+    pub recipient: [u8; 32],
+    pub nonce: u32,
 }
 
 #[access_control(SendTbtcGateway::constraints(&ctx, &args))]
@@ -133,18 +166,33 @@ pub fn send_tbtc_gateway(ctx: Context<SendTbtcGateway>, args: SendTbtcGatewayArg
     let token_bridge_transfer_authority = &ctx.accounts.token_bridge_transfer_authority;
     let token_program = &ctx.accounts.token_program;
 
-    let gateway = ctx.accounts.gateway_info.address;
+    let mut gateway_info = Account::<GatewayInfo>::try_from(&ctx.accounts.gateway_info)?;
+    require!(
+        gateway_info.is_current_version(),
+        WormholeGatewayError::AccountNotMigratable
+    );
+    require!(
+        !gateway_info.disabled,
+        WormholeGatewayError::GatewayDisabled
+    );
+    let gateway = gateway_info.address;
 
     // Prepare for wrapped tBTC transfer (this method also truncates the amount to prevent having to
     // handle dust since tBTC has >8 decimals).
-    super::burn_and_prepare_transfer(
+    let amount = super::burn_and_prepare_transfer(
         super::PrepareTransfer {
             custodian: &mut ctx.accounts.custodian,
+            gateway_info: Some(&mut gateway_info),
             tbtc_mint: &ctx.accounts.tbtc_mint,
             sender_token: &ctx.accounts.sender_token,
             sender,
+            treasury_token: &ctx.accounts.treasury_token,
             wrapped_tbtc_token,
             token_bridge_transfer_authority,
+            tbtc_config: &ctx.accounts.tbtc_config,
+            tbtc_minter_info: &ctx.accounts.tbtc_minter_info,
+            tbtc_event_authority: &ctx.accounts.tbtc_event_authority,
+            tbtc_program: &ctx.accounts.tbtc_program,
             token_program,
         },
         amount,
@@ -154,6 +202,7 @@ pub fn send_tbtc_gateway(ctx: Context<SendTbtcGateway>, args: SendTbtcGatewayArg
         None, // arbiter_fee
         nonce,
     )?;
+    gateway_info.exit(&crate::ID)?;
 
     let custodian = &ctx.accounts.custodian;
 
@@ -5,15 +5,22 @@ mod wrapped;
 pub use wrapped::*;
 
 use crate::error::WormholeGatewayError;
-use crate::state::Custodian;
+use crate::state::{Custodian, GatewayInfo};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use tbtc::Versioned;
 
 pub fn validate_send(
+    custodian: &Custodian,
     wrapped_tbtc_token: &Account<'_, token::TokenAccount>,
     recipient: &[u8; 32],
     amount: u64,
 ) -> Result<()> {
+    require!(
+        custodian.mode.allows_outbound(),
+        WormholeGatewayError::OutboundDisabled
+    );
+
     require!(*recipient != [0; 32], WormholeGatewayError::ZeroRecipient);
     require_gt!(amount, 0, WormholeGatewayError::ZeroAmount);
 
@@ -27,16 +34,44 @@ pub fn validate_send(
     Ok(())
 }
 
+/// Loads the gateway info of the recipient chain, which does not exist if the chain has no
+/// gateway. It is not deserialized with the context, so changes to it have to be written back with
+/// `exit`. Gateway infos that were not migrated to the current layout are rejected.
+pub fn load_gateway_info<'info>(
+    info: &AccountInfo<'info>,
+) -> Result<Option<Account<'info, GatewayInfo>>> {
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let gateway_info = Account::<GatewayInfo>::try_from(info)?;
+    require!(
+        gateway_info.is_current_version(),
+        WormholeGatewayError::AccountNotMigratable
+    );
+
+    Ok(Some(gateway_info))
+}
+
 pub struct PrepareTransfer<'ctx, 'info> {
     custodian: &'ctx mut Account<'info, Custodian>,
+    /// Gateway info of the recipient chain, if it has one.
+    gateway_info: Option<&'ctx mut Account<'info, GatewayInfo>>,
     tbtc_mint: &'ctx Account<'info, token::Mint>,
     sender_token: &'ctx Account<'info, token::TokenAccount>,
     sender: &'ctx Signer<'info>,
+    treasury_token: &'ctx AccountInfo<'info>,
     wrapped_tbtc_token: &'ctx Account<'info, token::TokenAccount>,
     token_bridge_transfer_authority: &'ctx AccountInfo<'info>,
+    tbtc_config: &'ctx AccountInfo<'info>,
+    tbtc_minter_info: &'ctx AccountInfo<'info>,
+    tbtc_event_authority: &'ctx AccountInfo<'info>,
+    tbtc_program: &'ctx Program<'info, tbtc::Tbtc>,
     token_program: &'ctx Program<'info, token::Token>,
 }
 
+/// Charges the recipient chain's outbound fee and burns the rest of the amount, which is returned
+/// as the amount to bridge.
 pub fn burn_and_prepare_transfer(
     prepare_transfer: PrepareTransfer,
     amount: u64,
@@ -45,32 +80,73 @@ pub fn burn_and_prepare_transfer(
     recipient: [u8; 32],
     arbiter_fee: Option<u64>,
     nonce: u32,
-) -> Result<()> {
+) -> Result<u64> {
     let PrepareTransfer {
         custodian,
+        gateway_info,
         tbtc_mint,
         sender_token,
         sender,
+        treasury_token,
         wrapped_tbtc_token,
         token_bridge_transfer_authority,
+        tbtc_config,
+        tbtc_minter_info,
+        tbtc_event_authority,
+        tbtc_program,
         token_program,
     } = prepare_transfer;
 
+    // Enforce the recipient chain's transfer bounds and outbound limit.
+    let fee = match gateway_info {
+        Some(gateway_info) => {
+            gateway_info.record_outbound(amount, Clock::get()?.unix_timestamp)?;
+            gateway_info.outbound_fee(amount)
+        }
+        None => 0,
+    };
+    require_gt!(amount, fee, WormholeGatewayError::AmountDoesNotCoverFee);
+
+    if fee > 0 {
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: sender_token.to_account_info(),
+                    to: treasury_token.to_account_info(),
+                    authority: sender.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    // Only the amount net of the fee is bridged.
+    let amount = amount - fee;
+
     // Account for burning tBTC.
     custodian.minted_amount = custodian
         .minted_amount
         .checked_sub(amount)
         .ok_or(WormholeGatewayError::MintedAmountUnderflow)?;
 
-    // Burn TBTC mint.
-    token::burn(
-        CpiContext::new(
-            token_program.to_account_info(),
-            token::Burn {
+    // Burn TBTC mint. The custodian signs as the minter so the TBTC program accounts for this burn
+    // against what the gateway has minted.
+    tbtc::cpi::burn(
+        CpiContext::new_with_signer(
+            tbtc_program.to_account_info(),
+            tbtc::cpi::accounts::Burn {
                 mint: tbtc_mint.to_account_info(),
-                from: sender_token.to_account_info(),
-                authority: sender.to_account_info(),
+                config: tbtc_config.to_account_info(),
+                owner_token: sender_token.to_account_info(),
+                owner: sender.to_account_info(),
+                minter_info: Some(tbtc_minter_info.to_account_info()),
+                minter: Some(custodian.to_account_info()),
+                token_program: token_program.to_account_info(),
+                event_authority: tbtc_event_authority.to_account_info(),
+                program: tbtc_program.to_account_info(),
             },
+            &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
         ),
         amount,
     )?;
@@ -81,7 +157,8 @@ pub fn burn_and_prepare_transfer(
         gateway: gateway.unwrap_or_default(),
         recipient,
         arbiter_fee: arbiter_fee.unwrap_or_default(),
-        nonce
+        nonce,
+        fee,
     });
 
     // Delegate authority to Token Bridge's transfer authority.
@@ -96,5 +173,7 @@ pub fn burn_and_prepare_transfer(
             &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
         ),
         amount,
-    )
+    )?;
+
+    Ok(amount)
 }
@@ -1,6 +1,11 @@
-use crate::{constants::MSG_SEED_PREFIX, state::Custodian};
+use crate::{
+    constants::{MSG_SEED_PREFIX, TREASURY_SEED_PREFIX},
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayInfo},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use tbtc::Versioned;
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
@@ -16,9 +21,20 @@ pub struct SendTbtcWrapped<'info> {
         has_one = wrapped_tbtc_token,
         has_one = wrapped_tbtc_mint,
         has_one = tbtc_mint,
+        constraint = custodian.is_current_version() @ WormholeGatewayError::AccountNotMigratable,
     )]
     custodian: Account<'info, Custodian>,
 
+    /// CHECK: Gateway info of the recipient chain. Wrapped tBTC can be sent to chains without a
+    /// gateway, in which case this account does not exist. If it does, the chain's outbound limits
+    /// apply.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
+        bump,
+    )]
+    gateway_info: UncheckedAccount<'info>,
+
     /// Custody account.
     #[account(mut)]
     wrapped_tbtc_token: Box<Account<'info, token::TokenAccount>>,
@@ -40,6 +56,26 @@ pub struct SendTbtcWrapped<'info> {
     #[account(mut)]
     sender: Signer<'info>,
 
+    /// CHECK: Treasury collecting fees in tBTC. It only has to exist if the recipient chain charges
+    /// an outbound fee, which cannot be set before the treasury is initialized.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED_PREFIX],
+        bump,
+    )]
+    treasury_token: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_minter_info: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TBTC program to emit events.
+    tbtc_event_authority: UncheckedAccount<'info>,
+
     /// CHECK: This account is needed for the Token Bridge program.
     token_bridge_config: UncheckedAccount<'info>,
 
@@ -81,6 +117,7 @@ pub struct SendTbtcWrapped<'info> {
     /// CHECK: This account is needed for the Token Bridge program.
     rent: UncheckedAccount<'info>,
 
+    tbtc_program: Program<'info, tbtc::Tbtc>,
     token_bridge_program: Program<'info, TokenBridge>,
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
@@ -90,6 +127,7 @@ pub struct SendTbtcWrapped<'info> {
 impl<'info> SendTbtcWrapped<'info> {
     fn constraints(ctx: &Context<Self>, args: &SendTbtcWrappedArgs) -> Result<()> {
         super::validate_send(
+            &ctx.accounts.custodian,
             &ctx.accounts.wrapped_tbtc_token,
             &args.recipient,
             args.amount,
@@ -99,11 +137,11 @@ impl<'info> SendTbtcWrapped<'info> {
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct SendTbtcWrappedArgs {
-    amount: u64,
-    recipient_chain: u16,
-    recipient: [u8; 32],
-    arbiter_fee: u64,
-    nonce: u32,
+    pub amount: u64,
+    pub recipient_chain: u16,
+    pub recipient: [u8; 32],
+    pub arbiter_fee: u64,
+    pub nonce: u32,
 }
 
 #[access_control(SendTbtcWrapped::constraints(&ctx, &args))]
@@ -121,15 +159,23 @@ pub fn send_tbtc_wrapped(ctx: Context<SendTbtcWrapped>, args: SendTbtcWrappedArg
     let token_bridge_transfer_authority = &ctx.accounts.token_bridge_transfer_authority;
     let token_program = &ctx.accounts.token_program;
 
+    let mut gateway_info = super::load_gateway_info(&ctx.accounts.gateway_info)?;
+
     // Prepare for wrapped tBTC transfer.
-    super::burn_and_prepare_transfer(
+    let amount = super::burn_and_prepare_transfer(
         super::PrepareTransfer {
             custodian: &mut ctx.accounts.custodian,
+            gateway_info: gateway_info.as_mut(),
             tbtc_mint: &ctx.accounts.tbtc_mint,
             sender_token: &ctx.accounts.sender_token,
             sender,
+            treasury_token: &ctx.accounts.treasury_token,
             wrapped_tbtc_token,
             token_bridge_transfer_authority,
+            tbtc_config: &ctx.accounts.tbtc_config,
+            tbtc_minter_info: &ctx.accounts.tbtc_minter_info,
+            tbtc_event_authority: &ctx.accounts.tbtc_event_authority,
+            tbtc_program: &ctx.accounts.tbtc_program,
             token_program,
         },
         amount,
@@ -140,6 +186,10 @@ pub fn send_tbtc_wrapped(ctx: Context<SendTbtcWrapped>, args: SendTbtcWrappedArg
         nonce,
     )?;
 
+    if let Some(gateway_info) = gateway_info {
+        gateway_info.exit(&crate::ID)?;
+    }
+
     let custodian = &ctx.accounts.custodian;
 
     // Finally transfer wrapped tBTC to the recipient.
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Allows `receive_tbtc` to deliver inbound tBTC into its program. The account only exists while
+/// the program is allowed.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CallTarget {
+    pub bump: u8,
+    pub program: Pubkey,
+}
+
+impl CallTarget {
+    pub const SEED_PREFIX: &'static [u8] = b"call-target";
+
+    /// Seed for the PDA that owns the call escrow and signs when a target program is called. It
+    /// has no other authority, so a target program cannot act as the custodian.
+    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = b"call-authority";
+
+    /// Seconds after a transfer was sent during which its target program is called. Redeeming the
+    /// transfer later delivers the tBTC to the recipient instead, so that a call that keeps
+    /// failing cannot hold up the transfer for good.
+    pub const CALL_TIMEOUT: i64 = 24 * 60 * 60;
+}
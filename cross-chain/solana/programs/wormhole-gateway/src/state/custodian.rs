@@ -1,20 +1,48 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{GatewayMode, ScheduledChange},
+};
 use anchor_lang::prelude::*;
+use tbtc::Versioned;
 use wormhole_anchor_sdk::token_bridge;
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Custodian {
+    pub version: u8,
     pub bump: u8,
     pub authority: Pubkey,
     pub pending_authority: Option<Pubkey>,
 
     pub tbtc_mint: Pubkey,
+
+    /// Wormhole chain and address of the canonical tBTC token. Inbound transfers must carry this
+    /// token, and the wrapped tBTC mint is the Token Bridge's mint for it.
+    pub tbtc_token_chain: u16,
+    pub tbtc_token_address: [u8; 32],
     pub wrapped_tbtc_mint: Pubkey,
     pub wrapped_tbtc_token: Pubkey,
     pub token_bridge_sender: Pubkey,
     pub token_bridge_sender_bump: u8,
     pub minting_limit: u64,
     pub minted_amount: u64,
+
+    /// Rolling limit on the amount minted for inbound transfers and deposits within a period of
+    /// seconds. A period of zero disables the limit.
+    pub inbound_limit_amount: u64,
+    pub inbound_limit_period: u32,
+    /// Amount counted against the inbound limit as of `inbound_updated_at`. It decays linearly,
+    /// freeing up the whole limit over one period.
+    pub inbound_updated_at: i64,
+    pub inbound_amount: u64,
+
+    /// Delay in seconds before a scheduled change can be executed. While this is nonzero, sensitive
+    /// changes can only be made through the timelock.
+    pub timelock_delay: u32,
+    pub num_scheduled_changes: u64,
+
+    /// Gateway guardians can restrict this mode, and only the authority can relax it.
+    pub mode: GatewayMode,
 }
 
 impl Custodian {
@@ -22,4 +50,207 @@ impl Custodian {
     /// completing transfers with payload, we are conveniently having the Custodian's PDA address
     /// derived as this redeemer.
     pub const SEED_PREFIX: &'static [u8] = token_bridge::SEED_PREFIX_REDEEMER;
+
+    pub const VERSION: u8 = 4;
+
+    /// Amount that can still be minted under the inbound limit at the given timestamp.
+    ///
+    /// Unlike a fixed window, the decaying amount does not let the whole limit be minted again
+    /// right after a window boundary. At most the limit can be minted at once, and at most twice
+    /// the limit within any period: the limit up front, plus what decays during the period.
+    pub fn remaining_inbound_capacity(&self, now: i64) -> u64 {
+        if self.inbound_limit_period == 0 {
+            u64::MAX
+        } else {
+            self.inbound_limit_amount
+                .saturating_sub(self.inbound_amount_at(now))
+        }
+    }
+
+    /// Account for an amount minted at the given timestamp. Returns false without recording
+    /// anything if the amount exceeds the remaining inbound capacity.
+    pub(crate) fn record_inbound(&mut self, amount: u64, now: i64) -> bool {
+        if self.inbound_limit_period == 0 {
+            return true;
+        }
+        if amount > self.remaining_inbound_capacity(now) {
+            return false;
+        }
+
+        self.inbound_amount = self.inbound_amount_at(now) + amount;
+        // A clock behind the last update must not move it back, which would decay the amount again.
+        self.inbound_updated_at = now.max(self.inbound_updated_at);
+
+        true
+    }
+
+    /// Amount counted against the inbound limit at the given timestamp, after decaying by the
+    /// limit's share of the time elapsed since the last update.
+    fn inbound_amount_at(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.inbound_updated_at).max(0) as u128;
+        let decayed =
+            elapsed * u128::from(self.inbound_limit_amount) / u128::from(self.inbound_limit_period);
+
+        self.inbound_amount
+            .saturating_sub(decayed.try_into().unwrap_or(u64::MAX))
+    }
+
+    /// Checks whether the signer can make a timelocked change. The timelock authority always can,
+    /// and the custodian authority can only if no timelock delay is set.
+    pub(crate) fn require_timelocked_authority(&self, signer: &Pubkey) -> Result<()> {
+        if *signer == ScheduledChange::authority_address() {
+            return Ok(());
+        }
+
+        require_keys_eq!(
+            *signer,
+            self.authority,
+            WormholeGatewayError::IsNotAuthority
+        );
+        require_eq!(
+            self.timelock_delay,
+            0,
+            WormholeGatewayError::TimelockRequired
+        );
+
+        Ok(())
+    }
+}
+
+impl Versioned for Custodian {
+    const CURRENT_VERSION: u8 = Self::VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: u64 = 1_000;
+    const PERIOD: u32 = 100;
+    const START: i64 = 1_700_000_000;
+
+    fn custodian(inbound_limit_amount: u64, inbound_limit_period: u32) -> Custodian {
+        Custodian {
+            version: Custodian::VERSION,
+            bump: 0,
+            authority: Pubkey::default(),
+            pending_authority: None,
+            tbtc_mint: Pubkey::default(),
+            tbtc_token_chain: 0,
+            tbtc_token_address: [0; 32],
+            wrapped_tbtc_mint: Pubkey::default(),
+            wrapped_tbtc_token: Pubkey::default(),
+            token_bridge_sender: Pubkey::default(),
+            token_bridge_sender_bump: 0,
+            minting_limit: u64::MAX,
+            minted_amount: 0,
+            inbound_limit_amount,
+            inbound_limit_period,
+            inbound_updated_at: START,
+            inbound_amount: 0,
+            timelock_delay: 0,
+            num_scheduled_changes: 0,
+            mode: GatewayMode::default(),
+        }
+    }
+
+    #[test]
+    fn zero_period_is_unlimited() {
+        let mut custodian = custodian(LIMIT, 0);
+
+        assert!(custodian.record_inbound(u64::MAX, START));
+        assert!(custodian.record_inbound(u64::MAX, START));
+        assert_eq!(custodian.inbound_amount, 0);
+        assert_eq!(custodian.remaining_inbound_capacity(START), u64::MAX);
+    }
+
+    #[test]
+    fn rejects_over_limit_without_recording() {
+        let mut custodian = custodian(LIMIT, PERIOD);
+
+        assert!(!custodian.record_inbound(LIMIT + 1, START + 1));
+        assert_eq!(custodian.inbound_amount, 0);
+        assert_eq!(custodian.inbound_updated_at, START);
+
+        assert!(custodian.record_inbound(LIMIT, START + 1));
+        assert!(!custodian.record_inbound(1, START + 1));
+        assert_eq!(custodian.inbound_amount, LIMIT);
+        assert_eq!(custodian.inbound_updated_at, START + 1);
+    }
+
+    #[test]
+    fn decays_over_partial_window() {
+        let mut custodian = custodian(LIMIT, PERIOD);
+        assert!(custodian.record_inbound(LIMIT, START));
+
+        // A quarter of the period frees up a quarter of the limit.
+        assert_eq!(custodian.remaining_inbound_capacity(START + 25), 250);
+        assert!(!custodian.record_inbound(251, START + 25));
+        assert!(custodian.record_inbound(250, START + 25));
+        assert_eq!(custodian.inbound_amount, LIMIT);
+        assert_eq!(custodian.inbound_updated_at, START + 25);
+
+        // Decay rounds down.
+        let mut custodian = self::custodian(10, 3);
+        assert!(custodian.record_inbound(10, START));
+        assert_eq!(custodian.remaining_inbound_capacity(START + 1), 3);
+        assert_eq!(custodian.remaining_inbound_capacity(START + 2), 6);
+    }
+
+    #[test]
+    fn decays_fully_over_full_window() {
+        let mut custodian = custodian(LIMIT, PERIOD);
+        assert!(custodian.record_inbound(LIMIT, START));
+
+        assert_eq!(custodian.remaining_inbound_capacity(START + 99), 990);
+        assert_eq!(custodian.remaining_inbound_capacity(START + 100), LIMIT);
+        assert_eq!(custodian.remaining_inbound_capacity(START + 1_000), LIMIT);
+
+        assert!(custodian.record_inbound(LIMIT, START + 1_000));
+        assert_eq!(custodian.inbound_amount, LIMIT);
+    }
+
+    #[test]
+    fn skewed_clock_does_not_decay_or_rewind() {
+        let mut custodian = custodian(LIMIT, PERIOD);
+        assert!(custodian.record_inbound(600, START));
+
+        assert_eq!(custodian.remaining_inbound_capacity(START - 50), 400);
+        assert!(custodian.record_inbound(400, START - 50));
+        assert_eq!(custodian.inbound_amount, LIMIT);
+        assert_eq!(custodian.inbound_updated_at, START);
+
+        // Decay resumes from the latest update, not the skewed one.
+        assert_eq!(custodian.remaining_inbound_capacity(START + 10), 100);
+
+        assert_eq!(custodian.remaining_inbound_capacity(i64::MIN), 0);
+    }
+
+    #[test]
+    fn saturates_on_extreme_values() {
+        let mut custodian = custodian(u64::MAX, 1);
+        assert!(custodian.record_inbound(u64::MAX, START));
+        assert_eq!(custodian.remaining_inbound_capacity(START), 0);
+        assert_eq!(custodian.remaining_inbound_capacity(START + 1), u64::MAX);
+        assert_eq!(custodian.remaining_inbound_capacity(i64::MAX), u64::MAX);
+
+        let mut custodian = self::custodian(u64::MAX, u32::MAX);
+        assert!(custodian.record_inbound(u64::MAX, START));
+        assert_eq!(
+            custodian.remaining_inbound_capacity(START + 1),
+            4_294_967_297
+        );
+        assert_eq!(custodian.remaining_inbound_capacity(i64::MAX), u64::MAX);
+
+        let mut custodian = self::custodian(LIMIT, PERIOD);
+        custodian.inbound_updated_at = i64::MIN;
+        custodian.inbound_amount = LIMIT;
+        assert_eq!(custodian.remaining_inbound_capacity(i64::MAX), LIMIT);
+        assert!(custodian.record_inbound(LIMIT, i64::MAX));
+        assert_eq!(custodian.inbound_updated_at, i64::MAX);
+    }
 }
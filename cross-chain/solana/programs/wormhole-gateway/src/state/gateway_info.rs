@@ -1,12 +1,258 @@
+use crate::error::WormholeGatewayError;
 use anchor_lang::prelude::*;
+use tbtc::Versioned;
 
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct GatewayInfo {
+    pub version: u8,
     pub bump: u8,
     pub address: [u8; 32],
+
+    /// Rolling limit on the amount sent to this chain within a period of seconds. A period of zero
+    /// disables the limit.
+    pub outbound_limit_amount: u64,
+    pub outbound_limit_period: u32,
+    /// Amount counted against the outbound limit as of `outbound_updated_at`. It decays linearly,
+    /// freeing up the whole limit over one period.
+    pub outbound_updated_at: i64,
+    pub outbound_amount: u64,
+
+    /// Bounds on the amount of a single transfer to this chain. A maximum of zero means there is
+    /// no maximum.
+    pub min_transfer_amount: u64,
+    pub max_transfer_amount: u64,
+
+    /// Fees charged on transfers from and to this chain, in basis points of the amount with a
+    /// minimum. Fees are paid in tBTC to the treasury.
+    pub inbound_fee_bps: u16,
+    pub inbound_min_fee: u64,
+    pub outbound_fee_bps: u16,
+    pub outbound_min_fee: u64,
+
+    /// Whether `disable_gateway` retired this chain's gateway. Sending to the gateway fails, but
+    /// limits and fees still apply to wrapped transfers from and to the chain.
+    pub disabled: bool,
 }
 
 impl GatewayInfo {
     pub const SEED_PREFIX: &'static [u8] = b"gateway-info";
+
+    pub const VERSION: u8 = 4;
+
+    pub const MAX_FEE_BPS: u16 = 10_000;
+
+    /// Fee charged on an amount received from this chain. With a minimum fee, it can be the whole
+    /// amount or more.
+    pub fn inbound_fee(&self, amount: u64) -> u64 {
+        compute_fee(amount, self.inbound_fee_bps, self.inbound_min_fee)
+    }
+
+    /// Fee charged on an amount sent to this chain. With a minimum fee, it can be the whole amount
+    /// or more.
+    pub fn outbound_fee(&self, amount: u64) -> u64 {
+        compute_fee(amount, self.outbound_fee_bps, self.outbound_min_fee)
+    }
+
+    /// Amount that can still be sent to this chain at the given timestamp. As with the inbound
+    /// limit, at most the limit can be sent at once, and at most twice the limit within any
+    /// period.
+    pub fn remaining_outbound_capacity(&self, now: i64) -> u64 {
+        if self.outbound_limit_period == 0 {
+            u64::MAX
+        } else {
+            self.outbound_limit_amount
+                .saturating_sub(self.outbound_amount_at(now))
+        }
+    }
+
+    /// Account for an amount sent to this chain at the given timestamp. Fails if the amount is out
+    /// of the transfer bounds or exceeds the remaining outbound capacity.
+    pub(crate) fn record_outbound(&mut self, amount: u64, now: i64) -> Result<()> {
+        require_gte!(
+            amount,
+            self.min_transfer_amount,
+            WormholeGatewayError::TransferAmountTooSmall
+        );
+        if self.max_transfer_amount > 0 {
+            require_gte!(
+                self.max_transfer_amount,
+                amount,
+                WormholeGatewayError::TransferAmountTooLarge
+            );
+        }
+
+        if self.outbound_limit_period > 0 {
+            require_gte!(
+                self.remaining_outbound_capacity(now),
+                amount,
+                WormholeGatewayError::OutboundLimitExceeded
+            );
+
+            self.outbound_amount = self.outbound_amount_at(now) + amount;
+            self.outbound_updated_at = now.max(self.outbound_updated_at);
+        }
+
+        Ok(())
+    }
+
+    /// Amount counted against the outbound limit at the given timestamp, after decaying by the
+    /// limit's share of the time elapsed since the last update.
+    fn outbound_amount_at(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.outbound_updated_at).max(0) as u128;
+        let decayed = elapsed * u128::from(self.outbound_limit_amount)
+            / u128::from(self.outbound_limit_period);
+
+        self.outbound_amount
+            .saturating_sub(decayed.try_into().unwrap_or(u64::MAX))
+    }
+}
+
+fn compute_fee(amount: u64, fee_bps: u16, min_fee: u64) -> u64 {
+    let fee = u128::from(amount) * u128::from(fee_bps) / u128::from(GatewayInfo::MAX_FEE_BPS);
+    u64::try_from(fee).unwrap_or(u64::MAX).max(min_fee)
+}
+
+impl Versioned for GatewayInfo {
+    const CURRENT_VERSION: u8 = Self::VERSION;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: u64 = 1_000;
+    const PERIOD: u32 = 100;
+    const START: i64 = 1_700_000_000;
+
+    fn gateway_info(outbound_limit_amount: u64, outbound_limit_period: u32) -> GatewayInfo {
+        GatewayInfo {
+            version: GatewayInfo::VERSION,
+            bump: 0,
+            address: [0; 32],
+            outbound_limit_amount,
+            outbound_limit_period,
+            outbound_updated_at: START,
+            outbound_amount: 0,
+            min_transfer_amount: 0,
+            max_transfer_amount: 0,
+            inbound_fee_bps: 0,
+            inbound_min_fee: 0,
+            outbound_fee_bps: 0,
+            outbound_min_fee: 0,
+            disabled: false,
+        }
+    }
+
+    #[test]
+    fn zero_period_is_unlimited() {
+        let mut info = gateway_info(LIMIT, 0);
+
+        info.record_outbound(u64::MAX, START).unwrap();
+        info.record_outbound(u64::MAX, START).unwrap();
+        assert_eq!(info.outbound_amount, 0);
+        assert_eq!(info.remaining_outbound_capacity(START), u64::MAX);
+    }
+
+    #[test]
+    fn enforces_transfer_bounds() {
+        let mut info = gateway_info(LIMIT, PERIOD);
+        info.min_transfer_amount = 10;
+        info.max_transfer_amount = 100;
+
+        assert_eq!(
+            info.record_outbound(9, START).unwrap_err(),
+            WormholeGatewayError::TransferAmountTooSmall.into()
+        );
+        assert_eq!(
+            info.record_outbound(101, START).unwrap_err(),
+            WormholeGatewayError::TransferAmountTooLarge.into()
+        );
+        assert_eq!(info.outbound_amount, 0);
+
+        info.record_outbound(10, START).unwrap();
+        info.record_outbound(100, START).unwrap();
+        assert_eq!(info.outbound_amount, 110);
+
+        // A maximum of zero means there is no maximum.
+        info.max_transfer_amount = 0;
+        info.record_outbound(LIMIT - 110, START).unwrap();
+    }
+
+    #[test]
+    fn rejects_over_limit_without_recording() {
+        let mut info = gateway_info(LIMIT, PERIOD);
+
+        assert_eq!(
+            info.record_outbound(LIMIT + 1, START + 1).unwrap_err(),
+            WormholeGatewayError::OutboundLimitExceeded.into()
+        );
+        assert_eq!(info.outbound_amount, 0);
+        assert_eq!(info.outbound_updated_at, START);
+
+        info.record_outbound(LIMIT, START + 1).unwrap();
+        assert_eq!(
+            info.record_outbound(1, START + 1).unwrap_err(),
+            WormholeGatewayError::OutboundLimitExceeded.into()
+        );
+        assert_eq!(info.outbound_amount, LIMIT);
+        assert_eq!(info.outbound_updated_at, START + 1);
+    }
+
+    #[test]
+    fn decays_over_partial_window() {
+        let mut info = gateway_info(LIMIT, PERIOD);
+        info.record_outbound(LIMIT, START).unwrap();
+
+        assert_eq!(info.remaining_outbound_capacity(START + 25), 250);
+        assert!(info.record_outbound(251, START + 25).is_err());
+        info.record_outbound(250, START + 25).unwrap();
+        assert_eq!(info.outbound_amount, LIMIT);
+        assert_eq!(info.outbound_updated_at, START + 25);
+    }
+
+    #[test]
+    fn decays_fully_over_full_window() {
+        let mut info = gateway_info(LIMIT, PERIOD);
+        info.record_outbound(LIMIT, START).unwrap();
+
+        assert_eq!(info.remaining_outbound_capacity(START + 99), 990);
+        assert_eq!(info.remaining_outbound_capacity(START + 100), LIMIT);
+        assert_eq!(info.remaining_outbound_capacity(START + 1_000), LIMIT);
+
+        info.record_outbound(LIMIT, START + 1_000).unwrap();
+        assert_eq!(info.outbound_amount, LIMIT);
+    }
+
+    #[test]
+    fn skewed_clock_does_not_decay_or_rewind() {
+        let mut info = gateway_info(LIMIT, PERIOD);
+        info.record_outbound(600, START).unwrap();
+
+        assert_eq!(info.remaining_outbound_capacity(START - 50), 400);
+        info.record_outbound(400, START - 50).unwrap();
+        assert_eq!(info.outbound_amount, LIMIT);
+        assert_eq!(info.outbound_updated_at, START);
+
+        assert_eq!(info.remaining_outbound_capacity(START + 10), 100);
+    }
+
+    #[test]
+    fn saturates_on_extreme_values() {
+        let mut info = gateway_info(u64::MAX, 1);
+        info.record_outbound(u64::MAX, START).unwrap();
+        assert_eq!(info.remaining_outbound_capacity(START), 0);
+        assert_eq!(info.remaining_outbound_capacity(i64::MAX), u64::MAX);
+
+        let mut info = gateway_info(LIMIT, PERIOD);
+        info.outbound_updated_at = i64::MIN;
+        info.outbound_amount = LIMIT;
+        assert_eq!(info.remaining_outbound_capacity(i64::MAX), LIMIT);
+        info.record_outbound(LIMIT, i64::MAX).unwrap();
+        assert_eq!(info.outbound_updated_at, i64::MAX);
+    }
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// Which directions the gateway bridges tBTC in.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace,
+)]
+pub enum GatewayMode {
+    #[default]
+    Active,
+    /// Only `receive_tbtc` and `deposit_wormhole_tbtc` run.
+    InboundOnly,
+    /// Only the `send_tbtc_*` instructions run.
+    OutboundOnly,
+    Paused,
+}
+
+impl GatewayMode {
+    pub fn allows_inbound(&self) -> bool {
+        matches!(self, Self::Active | Self::InboundOnly)
+    }
+
+    pub fn allows_outbound(&self) -> bool {
+        matches!(self, Self::Active | Self::OutboundOnly)
+    }
+
+    /// Whether switching to `other` does not allow any direction this mode disallows.
+    pub fn is_restricted_by(&self, other: Self) -> bool {
+        (self.allows_inbound() || !other.allows_inbound())
+            && (self.allows_outbound() || !other.allows_outbound())
+    }
+}
+
+/// Lets its guardian restrict the gateway mode. The account only exists while the guardian is
+/// registered.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct GatewayGuardian {
+    pub bump: u8,
+    pub guardian: Pubkey,
+}
+
+impl GatewayGuardian {
+    pub const SEED_PREFIX: &'static [u8] = b"gateway-guardian";
+}
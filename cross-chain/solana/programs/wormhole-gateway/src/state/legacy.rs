@@ -0,0 +1,138 @@
+//! Superseded account layouts. These are only used to migrate existing accounts in place. Layouts
+//! from before accounts carried a version are suffixed V0. Versioned layouts store their version
+//! right after the discriminator.
+
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayInfo, GatewayMode},
+};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+/// Layout of `Custodian` before versioning.
+#[derive(AnchorDeserialize, InitSpace)]
+pub(crate) struct CustodianV0 {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub tbtc_mint: Pubkey,
+    pub wrapped_tbtc_mint: Pubkey,
+    pub wrapped_tbtc_token: Pubkey,
+    pub token_bridge_sender: Pubkey,
+    pub token_bridge_sender_bump: u8,
+    pub minting_limit: u64,
+    pub minted_amount: u64,
+}
+
+impl CustodianV0 {
+    /// Upgrades to the current layout with the canonical tBTC token, which the legacy custodian did
+    /// not record. Limits added since start disabled, and the gateway starts active.
+    pub fn into_current(self, tbtc_token_chain: u16, tbtc_token_address: [u8; 32]) -> Custodian {
+        Custodian {
+            version: Custodian::VERSION,
+            bump: self.bump,
+            authority: self.authority,
+            pending_authority: self.pending_authority,
+            tbtc_mint: self.tbtc_mint,
+            tbtc_token_chain,
+            tbtc_token_address,
+            wrapped_tbtc_mint: self.wrapped_tbtc_mint,
+            wrapped_tbtc_token: self.wrapped_tbtc_token,
+            token_bridge_sender: self.token_bridge_sender,
+            token_bridge_sender_bump: self.token_bridge_sender_bump,
+            minting_limit: self.minting_limit,
+            minted_amount: self.minted_amount,
+            inbound_limit_amount: 0,
+            inbound_limit_period: 0,
+            inbound_updated_at: 0,
+            inbound_amount: 0,
+            timelock_delay: 0,
+            num_scheduled_changes: 0,
+            mode: GatewayMode::Active,
+        }
+    }
+}
+
+/// Layout of `GatewayInfo` before versioning.
+#[derive(AnchorDeserialize, InitSpace)]
+pub(crate) struct GatewayInfoV0 {
+    pub bump: u8,
+    pub address: [u8; 32],
+}
+
+impl GatewayInfoV0 {
+    /// Upgrades to the current layout. Limits, transfer bounds and fees start disabled, and the
+    /// gateway is enabled, since disabling one used to close it.
+    pub fn into_current(self) -> GatewayInfo {
+        GatewayInfo {
+            version: GatewayInfo::VERSION,
+            bump: self.bump,
+            address: self.address,
+            outbound_limit_amount: 0,
+            outbound_limit_period: 0,
+            outbound_updated_at: 0,
+            outbound_amount: 0,
+            min_transfer_amount: 0,
+            max_transfer_amount: 0,
+            inbound_fee_bps: 0,
+            inbound_min_fee: 0,
+            outbound_fee_bps: 0,
+            outbound_min_fee: 0,
+            disabled: false,
+        }
+    }
+}
+
+/// Reads an account of type `T` stored in the legacy layout `L`. Legacy accounts are recognized
+/// by their size, so an account that has already been migrated is rejected.
+pub(crate) fn read_legacy<T, L>(info: &AccountInfo) -> Result<L>
+where
+    T: Discriminator + Owner,
+    L: AnchorDeserialize + Space,
+{
+    require_keys_eq!(
+        *info.owner,
+        T::owner(),
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == T::discriminator(),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    require_eq!(
+        data.len(),
+        8 + L::INIT_SPACE,
+        WormholeGatewayError::AccountNotMigratable
+    );
+
+    L::deserialize(&mut &data[8..]).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+}
+
+/// Writes an account in its current layout, growing it to `space` bytes and topping up its rent
+/// from the payer first.
+pub(crate) fn write_migrated<'info, T: AccountSerialize>(
+    info: &AccountInfo<'info>,
+    account: &T,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    info.realloc(space, false)?;
+    account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+}
@@ -1,5 +1,23 @@
+mod call_target;
+pub use call_target::*;
+
 mod custodian;
 pub use custodian::*;
 
 mod gateway_info;
 pub use gateway_info::*;
+
+mod gateway_mode;
+pub use gateway_mode::*;
+
+mod legacy;
+pub(crate) use legacy::*;
+
+mod multisig;
+pub use multisig::*;
+
+mod proposal;
+pub use proposal::*;
+
+mod scheduled_change;
+pub use scheduled_change::*;
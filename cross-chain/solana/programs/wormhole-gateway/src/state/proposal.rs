@@ -0,0 +1,109 @@
+use crate::state::{GatewayMode, TimelockedAction};
+use anchor_lang::prelude::*;
+use tbtc_governance::multisig;
+
+/// Admin actions that can be carried out by the multisig. Every instruction the authority can call
+/// has one, except `initialize_multisig` and `migrate_custodian`, which cannot be reached once the
+/// authority is the multisig.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum ProposalAction {
+    UpdateMintingLimit {
+        new_limit: u64,
+    },
+    UpdateGatewayAddress {
+        chain: u16,
+        address: [u8; 32],
+    },
+    ChangeAuthority {
+        new_authority: Pubkey,
+    },
+    TakeAuthority,
+    ScheduleChange {
+        action: TimelockedAction,
+    },
+    UpdateMultisig {
+        #[max_len(16)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    UpdateTbtcToken {
+        chain: u16,
+        address: [u8; 32],
+    },
+    UpdateInboundLimit {
+        amount: u64,
+        period: u32,
+    },
+    UpdateGatewayLimits {
+        chain: u16,
+        outbound_limit_amount: u64,
+        outbound_limit_period: u32,
+        min_transfer_amount: u64,
+        max_transfer_amount: u64,
+    },
+    DisableGateway {
+        chain: u16,
+    },
+    AddGatewayGuardian {
+        guardian: Pubkey,
+    },
+    RemoveGatewayGuardian {
+        guardian: Pubkey,
+    },
+    SetGatewayMode {
+        mode: GatewayMode,
+    },
+    InitializeTreasury,
+    UpdateGatewayFees {
+        chain: u16,
+        inbound_fee_bps: u16,
+        inbound_min_fee: u64,
+        outbound_fee_bps: u16,
+        outbound_min_fee: u64,
+    },
+    WithdrawFees {
+        recipient_token: Pubkey,
+        amount: u64,
+    },
+    AddCallTarget {
+        program: Pubkey,
+    },
+    RemoveCallTarget {
+        program: Pubkey,
+    },
+    CancelAuthorityChange,
+    UpdateTimelockDelay {
+        delay: u32,
+    },
+    RecoverTokens {
+        source_token: Pubkey,
+        recipient_token: Pubkey,
+        amount: u64,
+    },
+    MigrateGatewayInfo {
+        chain: u16,
+    },
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Proposal {
+    pub bump: u8,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+
+    /// Signer set this proposal was created for. Approvals are only valid for this set.
+    pub signer_set_seqno: u32,
+
+    /// Bitmask of approvals, indexed by the signer's position in `Multisig.signers`.
+    pub approvals: u16,
+}
+
+impl Proposal {
+    pub const SEED_PREFIX: &'static [u8] = b"proposal";
+
+    pub(crate) fn num_approvals(&self) -> u8 {
+        multisig::num_approvals(self.approvals)
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+/// Sensitive admin actions that are subject to the timelock delay.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum TimelockedAction {
+    UpdateMintingLimit {
+        new_limit: u64,
+    },
+    UpdateGatewayAddress {
+        chain: u16,
+        address: [u8; 32],
+    },
+    UpdateTimelockDelay {
+        delay: u32,
+    },
+    UpdateTbtcToken {
+        chain: u16,
+        address: [u8; 32],
+    },
+    UpdateInboundLimit {
+        amount: u64,
+        period: u32,
+    },
+    UpdateGatewayLimits {
+        chain: u16,
+        outbound_limit_amount: u64,
+        outbound_limit_period: u32,
+        min_transfer_amount: u64,
+        max_transfer_amount: u64,
+    },
+    UpdateGatewayFees {
+        chain: u16,
+        inbound_fee_bps: u16,
+        inbound_min_fee: u64,
+        outbound_fee_bps: u16,
+        outbound_min_fee: u64,
+    },
+    AddCallTarget {
+        program: Pubkey,
+    },
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ScheduledChange {
+    pub bump: u8,
+    pub index: u64,
+    pub scheduled_by: Pubkey,
+    pub action: TimelockedAction,
+    pub executable_at: i64,
+}
+
+impl ScheduledChange {
+    pub const SEED_PREFIX: &'static [u8] = b"scheduled-change";
+
+    /// Seed for the system-owned PDA that signs for executed changes. It must hold enough
+    /// lamports to pay for any accounts created by them.
+    pub const AUTHORITY_SEED_PREFIX: &'static [u8] = b"timelock-authority";
+
+    pub fn authority_address() -> Pubkey {
+        Pubkey::find_program_address(&[Self::AUTHORITY_SEED_PREFIX], &crate::ID).0
+    }
+}
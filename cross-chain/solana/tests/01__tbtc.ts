@@ -1,4 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
 import { Program } from "@coral-xyz/anchor";
 import * as spl from "@solana/spl-token";
 import { assert, expect } from "chai";
@@ -712,11 +713,26 @@ describe("tbtc", () => {
       );
     });
 
+    it("cannot pause again during default cooldown", async () => {
+      const pauseIx = await tbtc.pauseIx({
+        guardian: guardian.publicKey,
+      });
+      await expectIxFail([pauseIx], [txPayer, guardian], "PauseCooldown");
+    });
+
     it("pause and remove last guardian", async () => {
+      // Lift the cooldown so the guardian can pause again.
+      const updateParamsIx = await tbtc.updatePauseParamsIx(
+        { authority: authority.publicKey },
+        { maxPauseDuration: 7 * 24 * 60 * 60, pauseCooldown: 0 }
+      );
       const pauseIx = await tbtc.pauseIx({
         guardian: guardian.publicKey,
       });
-      await expectIxSuccess([pauseIx], [txPayer, guardian]);
+      await expectIxSuccess(
+        [updateParamsIx, pauseIx],
+        [txPayer, authority, guardian]
+      );
       await tbtc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
@@ -820,4 +836,2552 @@ describe("tbtc", () => {
       });
     });
   });
+
+  describe("minter limits", () => {
+    it("add minter without limits", async () => {
+      const addMinterIx = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([addMinterIx], [authority]);
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.allowance.eq(new anchor.BN("18446744073709551615"))).to
+        .be.true;
+      expect(minterInfo.mintedTotal.toNumber()).to.equal(0);
+      expect(minterInfo.rateLimitPeriod).to.equal(0);
+    });
+
+    it("cannot update minter limits without authority", async () => {
+      const cannotUpdateIx = await tbtc.updateMinterLimitsIx(
+        {
+          authority: imposter.publicKey,
+          minter: minter.publicKey,
+        },
+        {
+          allowance: new anchor.BN(500),
+          rateLimitAmount: new anchor.BN(0),
+          rateLimitPeriod: 0,
+        }
+      );
+      await expectIxFail([cannotUpdateIx], [imposter], "IsNotAuthority");
+    });
+
+    it("update minter allowance", async () => {
+      const updateIx = await tbtc.updateMinterLimitsIx(
+        {
+          authority: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        {
+          allowance: new anchor.BN(500),
+          rateLimitAmount: new anchor.BN(0),
+          rateLimitPeriod: 0,
+        }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.allowance.toNumber()).to.equal(500);
+    });
+
+    it("mint up to allowance", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(500)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.mintedTotal.toNumber()).to.equal(500);
+
+      const cannotMintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(1)
+      );
+      await expectIxFail(
+        [cannotMintIx],
+        [txPayer, minter],
+        "MinterAllowanceExceeded"
+      );
+    });
+
+    it("update minter rate limit", async () => {
+      const updateIx = await tbtc.updateMinterLimitsIx(
+        {
+          authority: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        {
+          allowance: new anchor.BN(10000),
+          rateLimitAmount: new anchor.BN(100),
+          rateLimitPeriod: 3600,
+        }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.allowance.toNumber()).to.equal(10000);
+      expect(minterInfo.rateLimitAmount.toNumber()).to.equal(100);
+      expect(minterInfo.rateLimitPeriod).to.equal(3600);
+      expect(minterInfo.windowMinted.toNumber()).to.equal(0);
+    });
+
+    it("cannot mint more than rate limit", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.mintedTotal.toNumber()).to.equal(600);
+      expect(minterInfo.windowMinted.toNumber()).to.equal(100);
+
+      const cannotMintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(1)
+      );
+      await expectIxFail(
+        [cannotMintIx],
+        [txPayer, minter],
+        "MinterRateLimitExceeded"
+      );
+    });
+
+    it("remove minter with limits", async () => {
+      const removeIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([removeIx], [authority]);
+
+      // Burn what was minted in these tests so the supply is back to where it was.
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const burnIx = spl.createBurnInstruction(
+        recipientToken,
+        tbtc.getMintPDA(),
+        recipient.publicKey,
+        600
+      );
+      await expectIxSuccess([burnIx], [recipient]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("burning", () => {
+    it("burn", async () => {
+      const ownerToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const ownerBefore = await getTokenBalance(ownerToken);
+      expect(ownerBefore).to.equal(BigInt(2000));
+
+      const burnIx = await tbtc.burnIx(
+        {
+          ownerToken,
+          owner: recipient.publicKey,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([burnIx], [txPayer, recipient]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(1900),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const ownerAfter = await getTokenBalance(ownerToken);
+      expect(ownerAfter).to.equal(ownerBefore - BigInt(100));
+
+      const configState = await tbtc.getConfigData();
+      expect(configState.burnedTotal.toNumber()).to.equal(100);
+    });
+
+    it("cannot burn without owner", async () => {
+      const ownerToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotBurnIx = await tbtc.burnIx(
+        {
+          ownerToken,
+          owner: imposter.publicKey,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail(
+        [cannotBurnIx],
+        [txPayer, imposter],
+        "ConstraintTokenOwner"
+      );
+    });
+
+    it("burn from as delegate", async () => {
+      const fromToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const approveIx = spl.createApproveInstruction(
+        fromToken,
+        txPayer.publicKey,
+        recipient.publicKey,
+        50
+      );
+      await expectIxSuccess([approveIx], [recipient]);
+
+      const burnFromIx = await tbtc.burnFromIx(
+        {
+          fromToken,
+          spender: txPayer.publicKey,
+        },
+        new anchor.BN(50)
+      );
+      await expectIxSuccess([burnFromIx], [txPayer]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(1850),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const configState = await tbtc.getConfigData();
+      expect(configState.burnedTotal.toNumber()).to.equal(150);
+    });
+
+    it("cannot burn from without delegation", async () => {
+      const fromToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotBurnIx = await tbtc.burnFromIx(
+        {
+          fromToken,
+          spender: imposter.publicKey,
+        },
+        new anchor.BN(50)
+      );
+      await expectIxFail(
+        [cannotBurnIx],
+        [txPayer, imposter],
+        "IsNotDelegate"
+      );
+    });
+
+    it("burn on behalf of minter", async () => {
+      const addMinterIx = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([addMinterIx], [authority]);
+
+      const ownerToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken: ownerToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+
+      const burnIx = await tbtc.burnIx(
+        {
+          ownerToken,
+          owner: recipient.publicKey,
+          minter: minter.publicKey,
+        },
+        new anchor.BN(60)
+      );
+      await expectIxSuccess([burnIx], [txPayer, recipient, minter]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(1890),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.mintedTotal.toNumber()).to.equal(100);
+      expect(minterInfo.burnedTotal.toNumber()).to.equal(60);
+    });
+
+    it("cannot burn more than minter has outstanding", async () => {
+      const ownerToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotBurnIx = await tbtc.burnIx(
+        {
+          ownerToken,
+          owner: recipient.publicKey,
+          minter: minter.publicKey,
+        },
+        new anchor.BN(41)
+      );
+      await expectIxFail(
+        [cannotBurnIx],
+        [txPayer, recipient, minter],
+        "MinterBurnExceedsOutstanding"
+      );
+    });
+
+    it("cannot burn on behalf of minter without its signature", async () => {
+      const ownerToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotBurnIx = await tbtc.burnIx(
+        {
+          ownerToken,
+          owner: recipient.publicKey,
+          minterInfo: tbtc.getMinterInfoPDA(minter.publicKey),
+          minter: imposter.publicKey,
+        },
+        new anchor.BN(10)
+      );
+      await expectIxFail(
+        [cannotBurnIx],
+        [txPayer, recipient, imposter],
+        "MinterInfoMismatch"
+      );
+    });
+
+    it("cannot burn while paused", async () => {
+      const addGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([addGuardianIx], [authority]);
+
+      const pauseIx = await tbtc.pauseIx({
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([pauseIx], [txPayer, guardian]);
+
+      const ownerToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotBurnIx = await tbtc.burnIx(
+        {
+          ownerToken,
+          owner: recipient.publicKey,
+        },
+        new anchor.BN(10)
+      );
+      await expectIxFail([cannotBurnIx], [txPayer, recipient], "IsPaused");
+
+      const unpauseIx = await tbtc.unpauseIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([unpauseIx], [authority]);
+    });
+
+    it("remove minter and guardian", async () => {
+      // Mint back what was burned in these tests so the supply is back to where it was.
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(110)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+
+      const removeMinterIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([removeMinterIx, removeGuardianIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("token recovery", () => {
+    it("recover tokens sent to config", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      // Strand some tBTC in a token account owned by the config PDA.
+      const sourceToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        tbtc.getConfigPDA(),
+        true
+      );
+      const createIx = spl.createAssociatedTokenAccountIdempotentInstruction(
+        txPayer.publicKey,
+        sourceToken,
+        tbtc.getConfigPDA(),
+        tbtc.getMintPDA()
+      );
+      const transferIx = spl.createTransferInstruction(
+        recipientToken,
+        sourceToken,
+        recipient.publicKey,
+        10
+      );
+      await expectIxSuccess([createIx, transferIx], [txPayer, recipient]);
+
+      const recipientBefore = await getTokenBalance(recipientToken);
+
+      const recoverIx = await tbtc.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken,
+          recipientToken,
+        },
+        new anchor.BN(10)
+      );
+      await expectIxSuccess([recoverIx], [authority]);
+
+      const recipientAfter = await getTokenBalance(recipientToken);
+      expect(recipientAfter).to.equal(recipientBefore + BigInt(10));
+
+      const sourceAfter = await getTokenBalance(sourceToken);
+      expect(sourceAfter).to.equal(BigInt(0));
+    });
+
+    it("cannot recover tokens without authority", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const sourceToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        tbtc.getConfigPDA(),
+        true
+      );
+
+      const cannotRecoverIx = await tbtc.recoverTokensIx(
+        {
+          authority: imposter.publicKey,
+          sourceToken,
+          recipientToken,
+        },
+        new anchor.BN(0)
+      );
+      await expectIxFail([cannotRecoverIx], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot recover tokens not owned by config", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotRecoverIx = await tbtc.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: recipientToken,
+          recipientToken,
+        },
+        new anchor.BN(10)
+      );
+      await expectIxFail(
+        [cannotRecoverIx],
+        [authority],
+        "ConstraintTokenOwner"
+      );
+    });
+  });
+
+  describe("metadata", () => {
+    it("update metadata", async () => {
+      const uri = "https://threshold.network/tbtc.json";
+
+      const updateIx = await tbtc.updateMetadataIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          name: "tBTC v2",
+          symbol: "tBTC",
+          uri,
+        }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+
+      const metadata = await Metadata.fromAccountAddress(
+        program.provider.connection,
+        tbtc.getTbtcMetadataPDA()
+      );
+      expect(metadata.data.name.replace(/\0/g, "")).to.equal("tBTC v2");
+      expect(metadata.data.symbol.replace(/\0/g, "")).to.equal("tBTC");
+      expect(metadata.data.uri.replace(/\0/g, "")).to.equal(uri);
+    });
+
+    it("cannot update metadata without authority", async () => {
+      const cannotUpdateIx = await tbtc.updateMetadataIx(
+        {
+          authority: imposter.publicKey,
+        },
+        {
+          name: "Fake tBTC",
+          symbol: "FAKE",
+          uri: "",
+        }
+      );
+      await expectIxFail([cannotUpdateIx], [imposter], "IsNotAuthority");
+    });
+  });
+
+  describe("events", () => {
+    it("emit minted and burned events", async () => {
+      const addMinterIx = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([addMinterIx], [authority]);
+
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(10)
+      );
+      const txSig = await expectIxSuccess([mintIx], [txPayer, minter]);
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(1);
+      expect(events[0].name).to.equal("TbtcMinted");
+      expect(events[0].data.minter).to.eql(minter.publicKey);
+      expect(events[0].data.recipientToken).to.eql(recipientToken);
+      expect(events[0].data.amount.toNumber()).to.equal(10);
+
+      // Burn what was minted so the supply is back to where it was.
+      const burnIx = await tbtc.burnIx(
+        {
+          ownerToken: recipientToken,
+          owner: recipient.publicKey,
+          minter: minter.publicKey,
+        },
+        new anchor.BN(10)
+      );
+      const removeMinterIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const burnSig = await expectIxSuccess(
+        [burnIx],
+        [txPayer, recipient, minter]
+      );
+      await expectIxSuccess([removeMinterIx], [authority]);
+
+      const burnEvents = await tbtc.getCpiEvents(burnSig);
+      expect(burnEvents).has.length(1);
+      expect(burnEvents[0].name).to.equal("TbtcBurned");
+      expect(burnEvents[0].data.token).to.eql(recipientToken);
+      expect(burnEvents[0].data.minter).to.eql(minter.publicKey);
+      expect(burnEvents[0].data.amount.toNumber()).to.equal(10);
+    });
+
+    it("emit paused and unpaused events", async () => {
+      const addGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([addGuardianIx], [authority]);
+
+      const pauseIx = await tbtc.pauseIx({
+        guardian: guardian.publicKey,
+      });
+      const pauseSig = await expectIxSuccess([pauseIx], [txPayer, guardian]);
+
+      const pauseEvents = await tbtc.getCpiEvents(pauseSig);
+      expect(pauseEvents).has.length(1);
+      expect(pauseEvents[0].name).to.equal("Paused");
+      expect(pauseEvents[0].data.guardian).to.eql(guardian.publicKey);
+
+      const unpauseIx = await tbtc.unpauseIx({
+        authority: authority.publicKey,
+      });
+      const unpauseSig = await expectIxSuccess([unpauseIx], [authority]);
+
+      const unpauseEvents = await tbtc.getCpiEvents(unpauseSig);
+      expect(unpauseEvents).has.length(1);
+      expect(unpauseEvents[0].name).to.equal("Unpaused");
+      expect(unpauseEvents[0].data.authority).to.eql(authority.publicKey);
+
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([removeGuardianIx], [authority]);
+    });
+
+    it("emit authority change events", async () => {
+      const changeIx = await tbtc.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: newAuthority.publicKey,
+      });
+      const changeSig = await expectIxSuccess([changeIx], [authority]);
+
+      const changeEvents = await tbtc.getCpiEvents(changeSig);
+      expect(changeEvents).has.length(1);
+      expect(changeEvents[0].name).to.equal("AuthorityChangeRequested");
+      expect(changeEvents[0].data.authority).to.eql(authority.publicKey);
+      expect(changeEvents[0].data.pendingAuthority).to.eql(
+        newAuthority.publicKey
+      );
+
+      const cancelIx = await tbtc.cancelAuthorityChangeIx({
+        authority: authority.publicKey,
+      });
+      const cancelSig = await expectIxSuccess([cancelIx], [authority]);
+
+      const cancelEvents = await tbtc.getCpiEvents(cancelSig);
+      expect(cancelEvents).has.length(1);
+      expect(cancelEvents[0].name).to.equal("AuthorityChangeCancelled");
+      expect(cancelEvents[0].data.pendingAuthority).to.eql(
+        newAuthority.publicKey
+      );
+
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("multisig", () => {
+    const signers = [
+      anchor.web3.Keypair.generate(),
+      anchor.web3.Keypair.generate(),
+      anchor.web3.Keypair.generate(),
+    ];
+    const multisigAuthority = tbtc.getMultisigAuthorityPDA();
+
+    async function propose(proposer: anchor.web3.Keypair, action: any) {
+      const { numProposals } = await tbtc.getMultisigData();
+      const proposal = tbtc.getProposalPDA(numProposals);
+      const ix = await tbtc.createProposalIx(
+        { proposal, proposer: proposer.publicKey },
+        action
+      );
+      await expectIxSuccess([ix], [proposer]);
+
+      return proposal;
+    }
+
+    async function approve(proposal: anchor.web3.PublicKey) {
+      const ix = await tbtc.approveProposalIx({
+        proposal,
+        signer: signers[1].publicKey,
+      });
+      await expectIxSuccess([ix], [signers[1]]);
+    }
+
+    it("set up multisig signers", async () => {
+      for (const signer of signers) {
+        await transferLamports(authority, signer.publicKey, 1000000000);
+      }
+
+      // The multisig authority pays for accounts created by executed proposals.
+      await transferLamports(authority, multisigAuthority, 1000000000);
+    });
+
+    it("cannot initialize multisig (invalid threshold)", async () => {
+      const ix = await tbtc.initializeMultisigIx(
+        { authority: authority.publicKey },
+        {
+          signers: signers.map((signer) => signer.publicKey),
+          threshold: 4,
+        }
+      );
+      await expectIxFail([ix], [authority], "InvalidMultisigThreshold");
+    });
+
+    it("cannot initialize multisig (duplicate signers)", async () => {
+      const ix = await tbtc.initializeMultisigIx(
+        { authority: authority.publicKey },
+        {
+          signers: [signers[0].publicKey, signers[0].publicKey],
+          threshold: 1,
+        }
+      );
+      await expectIxFail([ix], [authority], "DuplicateMultisigSigner");
+    });
+
+    it("cannot initialize multisig (not authority)", async () => {
+      const ix = await tbtc.initializeMultisigIx(
+        { authority: imposter.publicKey },
+        {
+          signers: signers.map((signer) => signer.publicKey),
+          threshold: 2,
+        }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("initialize multisig", async () => {
+      const ix = await tbtc.initializeMultisigIx(
+        { authority: authority.publicKey },
+        {
+          signers: signers.map((signer) => signer.publicKey),
+          threshold: 2,
+        }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const multisig = await tbtc.getMultisigData();
+      expect(multisig.signers).to.eql(
+        signers.map((signer) => signer.publicKey)
+      );
+      expect(multisig.threshold).to.equal(2);
+      expect(multisig.numProposals.toNumber()).to.equal(0);
+    });
+
+    it("hand authority to multisig", async () => {
+      const changeIx = await tbtc.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: multisigAuthority,
+      });
+      await expectIxSuccess([changeIx], [authority]);
+
+      const proposal = await propose(signers[0], { takeAuthority: {} });
+      await approve(proposal);
+
+      const executeIx = await tbtc.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        [
+          { pubkey: tbtc.getConfigPDA(), isSigner: false, isWritable: true },
+          {
+            pubkey: tbtc.getEventAuthorityPDA(),
+            isSigner: false,
+            isWritable: false,
+          },
+        ]
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+
+      await tbtc.checkConfig({
+        authority: multisigAuthority,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot create proposal (not signer)", async () => {
+      const ix = await tbtc.createProposalIx(
+        { proposer: imposter.publicKey },
+        { addMinter: { minter: minter.publicKey } }
+      );
+      await expectIxFail([ix], [imposter], "IsNotMultisigSigner");
+    });
+
+    it("add minter through multisig", async () => {
+      const proposal = await propose(signers[0], {
+        addMinter: { minter: minter.publicKey },
+      });
+      const remainingAccounts = [
+        { pubkey: tbtc.getConfigPDA(), isSigner: false, isWritable: true },
+        {
+          pubkey: await tbtc.getNextMintersPagePDA(),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: tbtc.getMinterInfoPDA(minter.publicKey),
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: minter.publicKey, isSigner: false, isWritable: false },
+        {
+          pubkey: anchor.web3.SystemProgram.programId,
+          isSigner: false,
+          isWritable: false,
+        },
+      ];
+
+      // Only the proposer has approved so far.
+      const failedExecuteIx = await tbtc.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        remainingAccounts
+      );
+      await expectIxFail(
+        [failedExecuteIx],
+        [txPayer],
+        "ProposalThresholdNotMet"
+      );
+
+      const failedApproveIx = await tbtc.approveProposalIx({
+        proposal,
+        signer: signers[0].publicKey,
+      });
+      await expectIxFail(
+        [failedApproveIx],
+        [signers[0]],
+        "ProposalAlreadyApproved"
+      );
+
+      await approve(proposal);
+
+      const executeIx = await tbtc.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        remainingAccounts
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+      await tbtc.checkMinterInfo(minter.publicKey);
+
+      // Executed proposals are closed.
+      const proposalInfo = await program.provider.connection.getAccountInfo(
+        proposal
+      );
+      expect(proposalInfo).to.be.null;
+    });
+
+    it("remove minter through multisig", async () => {
+      const proposal = await propose(signers[2], {
+        removeMinter: { minter: minter.publicKey },
+      });
+      await approve(proposal);
+
+      const { registryPage } = await tbtc.getMinterInfo(minter.publicKey);
+      const executeIx = await tbtc.executeProposalIx(
+        { proposal, proposer: signers[2].publicKey },
+        [
+          { pubkey: tbtc.getConfigPDA(), isSigner: false, isWritable: true },
+          {
+            pubkey: tbtc.getMintersPagePDA(registryPage),
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: tbtc.getMinterInfoPDA(minter.publicKey),
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: minter.publicKey, isSigner: false, isWritable: false },
+          {
+            pubkey: anchor.web3.SystemProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+        ]
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+
+      const mustBeNull = await tbtc
+        .checkMinterInfo(minter.publicKey)
+        .catch((_) => null);
+      assert(mustBeNull === null, "minter info found");
+    });
+
+    it("update multisig signers", async () => {
+      const staleProposal = await propose(signers[0], {
+        addMinter: { minter: anotherMinter.publicKey },
+      });
+
+      const proposal = await propose(signers[0], {
+        updateMultisig: {
+          signers: [signers[0].publicKey, signers[1].publicKey],
+          threshold: 2,
+        },
+      });
+      await approve(proposal);
+
+      const executeIx = await tbtc.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        []
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+
+      const multisig = await tbtc.getMultisigData();
+      expect(multisig.signers).to.eql([
+        signers[0].publicKey,
+        signers[1].publicKey,
+      ]);
+      expect(multisig.signerSetSeqno).to.equal(1);
+
+      // Proposals made for the previous signer set cannot be approved anymore.
+      const failedApproveIx = await tbtc.approveProposalIx({
+        proposal: staleProposal,
+        signer: signers[1].publicKey,
+      });
+      await expectIxFail([failedApproveIx], [signers[1]], "ProposalStale");
+    });
+
+    // Proposes an action and executes it once both signers approved, passing
+    // the accounts of the instruction the multisig authority would sign.
+    async function proposeAndExecute(
+      action: any,
+      ix: { keys: anchor.web3.AccountMeta[] },
+      expectedError?: string
+    ) {
+      const proposal = await propose(signers[0], action);
+      await approve(proposal);
+
+      const executeIx = await tbtc.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        ix.keys.map((key) => ({ ...key, isSigner: false }))
+      );
+      if (expectedError === undefined) {
+        return expectIxSuccess([executeIx], [txPayer]);
+      }
+      return expectIxFail([executeIx], [txPayer], expectedError);
+    }
+
+    it("cancel authority change through multisig", async () => {
+      await proposeAndExecute(
+        { changeAuthority: { newAuthority: newAuthority.publicKey } },
+        await tbtc.changeAuthorityIx({
+          authority: multisigAuthority,
+          newAuthority: newAuthority.publicKey,
+        })
+      );
+      expect((await tbtc.getConfigData()).pendingAuthority).to.eql(
+        newAuthority.publicKey
+      );
+
+      await proposeAndExecute(
+        { cancelAuthorityChange: {} },
+        await tbtc.cancelAuthorityChangeIx({ authority: multisigAuthority })
+      );
+      expect((await tbtc.getConfigData()).pendingAuthority).to.be.null;
+    });
+
+    it("update timelock delay through multisig", async () => {
+      // Keep the delay at zero, since shortening it later needs the timelock.
+      const txSig = await proposeAndExecute(
+        { updateTimelockDelay: { delay: 0 } },
+        await tbtc.updateTimelockDelayIx({ authority: multisigAuthority }, 0)
+      );
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(1);
+      expect(events[0].name).to.equal("TimelockDelayUpdated");
+      expect((await tbtc.getConfigData()).timelockDelay).to.equal(0);
+    });
+
+    it("update pause params through multisig", async () => {
+      const { maxPauseDuration, pauseCooldown } = await tbtc.getConfigData();
+      const args = {
+        maxPauseDuration: maxPauseDuration + 1,
+        pauseCooldown: pauseCooldown + 1,
+      };
+      await proposeAndExecute(
+        { updatePauseParams: args },
+        await tbtc.updatePauseParamsIx({ authority: multisigAuthority }, args)
+      );
+
+      const config = await tbtc.getConfigData();
+      expect(config.maxPauseDuration).to.equal(args.maxPauseDuration);
+      expect(config.pauseCooldown).to.equal(args.pauseCooldown);
+
+      const restored = { maxPauseDuration, pauseCooldown };
+      await proposeAndExecute(
+        { updatePauseParams: restored },
+        await tbtc.updatePauseParamsIx(
+          { authority: multisigAuthority },
+          restored
+        )
+      );
+    });
+
+    it("update max freeze duration through multisig", async () => {
+      const { maxFreezeDuration } = await tbtc.getConfigData();
+      await proposeAndExecute(
+        {
+          updateMaxFreezeDuration: {
+            maxFreezeDuration: maxFreezeDuration + 1,
+          },
+        },
+        await tbtc.updateMaxFreezeDurationIx(
+          { authority: multisigAuthority },
+          maxFreezeDuration + 1
+        )
+      );
+      expect((await tbtc.getConfigData()).maxFreezeDuration).to.equal(
+        maxFreezeDuration + 1
+      );
+
+      await proposeAndExecute(
+        { updateMaxFreezeDuration: { maxFreezeDuration } },
+        await tbtc.updateMaxFreezeDurationIx(
+          { authority: multisigAuthority },
+          maxFreezeDuration
+        )
+      );
+    });
+
+    it("update metadata through multisig", async () => {
+      const metadataPDA = tbtc.getTbtcMetadataPDA();
+      const { data } = await Metadata.fromAccountAddress(
+        program.provider.connection,
+        metadataPDA
+      );
+      const args = {
+        name: data.name.replace(/\0/g, ""),
+        symbol: data.symbol.replace(/\0/g, ""),
+        uri: data.uri.replace(/\0/g, "") + "?v=2",
+      };
+      await proposeAndExecute(
+        { updateMetadata: args },
+        await tbtc.updateMetadataIx({ authority: multisigAuthority }, args)
+      );
+
+      const metadata = await Metadata.fromAccountAddress(
+        program.provider.connection,
+        metadataPDA
+      );
+      expect(metadata.data.uri.replace(/\0/g, "")).to.equal(args.uri);
+    });
+
+    it("recover tokens through multisig", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const sourceToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        tbtc.getConfigPDA(),
+        true
+      );
+      const transferIx = spl.createTransferInstruction(
+        recipientToken,
+        sourceToken,
+        recipient.publicKey,
+        10
+      );
+      await expectIxSuccess([transferIx], [recipient]);
+
+      const recipientBefore = await getTokenBalance(recipientToken);
+
+      await proposeAndExecute(
+        {
+          recoverTokens: {
+            sourceToken,
+            recipientToken,
+            amount: new anchor.BN(10),
+          },
+        },
+        await tbtc.recoverTokensIx(
+          { authority: multisigAuthority, sourceToken, recipientToken },
+          new anchor.BN(10)
+        )
+      );
+
+      const recipientAfter = await getTokenBalance(recipientToken);
+      expect(recipientAfter).to.equal(recipientBefore + BigInt(10));
+    });
+
+    it("add guardian through multisig", async () => {
+      await transferLamports(authority, guardian.publicKey, 1000000000);
+      await proposeAndExecute(
+        { addGuardian: { guardian: guardian.publicKey } },
+        await tbtc.addGuardianIx({
+          authority: multisigAuthority,
+          guardian: guardian.publicKey,
+        })
+      );
+      await tbtc.checkGuardianInfo(guardian.publicKey);
+    });
+
+    it("schedule change through multisig", async () => {
+      const { numScheduledChanges } = await tbtc.getConfigData();
+      const scheduledChange = tbtc.getScheduledChangePDA(numScheduledChanges);
+      const action = { addMinter: { minter: anotherMinter.publicKey } };
+      await proposeAndExecute(
+        { scheduleChange: { action } },
+        await tbtc.scheduleChangeIx(
+          { scheduledChange, authority: multisigAuthority },
+          action
+        )
+      );
+
+      const { scheduledBy } = await program.account.scheduledChange.fetch(
+        scheduledChange
+      );
+      expect(scheduledBy).to.eql(multisigAuthority);
+
+      const cancelIx = await tbtc.cancelScheduledChangeIx({
+        guardian: guardian.publicKey,
+        scheduledChange,
+        scheduledBy: multisigAuthority,
+      });
+      await expectIxSuccess([cancelIx], [guardian]);
+    });
+
+    it("grant and revoke role through multisig", async () => {
+      const holder = anchor.web3.Keypair.generate().publicKey;
+      const roleAssignment = tbtc.getRoleAssignmentPDA("minterAdmin", holder);
+      await proposeAndExecute(
+        { grantRole: { role: { minterAdmin: {} }, holder } },
+        await tbtc.grantRoleIx(
+          { authority: multisigAuthority, holder },
+          "minterAdmin"
+        )
+      );
+      const assignment = await program.account.roleAssignment.fetch(
+        roleAssignment
+      );
+      expect(assignment.holder).to.eql(holder);
+
+      await proposeAndExecute(
+        { revokeRole: { role: { minterAdmin: {} }, holder } },
+        await tbtc.revokeRoleIx({ authority: multisigAuthority, roleAssignment })
+      );
+      const info = await program.provider.connection.getAccountInfo(
+        roleAssignment
+      );
+      expect(info).to.be.null;
+    });
+
+    it("extend pause and unpause through multisig", async () => {
+      const pauseIx = await tbtc.pauseIx({ guardian: guardian.publicKey });
+      await expectIxSuccess([pauseIx], [guardian]);
+
+      const { pauseExpiresAt } = await tbtc.getConfigData();
+      await proposeAndExecute(
+        { extendPause: { extension: 60 } },
+        await tbtc.extendPauseIx({ authority: multisigAuthority }, 60)
+      );
+      const config = await tbtc.getConfigData();
+      expect(config.pauseExpiresAt.sub(pauseExpiresAt).toNumber()).to.equal(
+        60
+      );
+
+      await proposeAndExecute(
+        { unpause: {} },
+        await tbtc.unpauseIx({ authority: multisigAuthority })
+      );
+      expect((await tbtc.getConfigData()).paused).to.be.false;
+    });
+
+    it("update minter limits and unpause minter through multisig", async () => {
+      await proposeAndExecute(
+        { addMinter: { minter: minter.publicKey } },
+        await tbtc.addMinterIx({
+          authority: multisigAuthority,
+          minter: minter.publicKey,
+        })
+      );
+
+      const args = {
+        allowance: new anchor.BN(1000),
+        rateLimitAmount: new anchor.BN(100),
+        rateLimitPeriod: 60,
+      };
+      await proposeAndExecute(
+        { updateMinterLimits: { minter: minter.publicKey, ...args } },
+        await tbtc.updateMinterLimitsIx(
+          { authority: multisigAuthority, minter: minter.publicKey },
+          args
+        )
+      );
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.allowance.toNumber()).to.equal(1000);
+      expect(minterInfo.rateLimitAmount.toNumber()).to.equal(100);
+      expect(minterInfo.rateLimitPeriod).to.equal(60);
+
+      const pauseIx = await tbtc.pauseMinterIx({
+        guardian: guardian.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([pauseIx], [guardian]);
+
+      await proposeAndExecute(
+        { unpauseMinter: { minter: minter.publicKey } },
+        await tbtc.unpauseMinterIx({
+          authority: multisigAuthority,
+          minter: minter.publicKey,
+        })
+      );
+      expect((await tbtc.getMinterInfo(minter.publicKey)).paused).to.be.false;
+    });
+
+    it("freeze actions through multisig", async () => {
+      const holder = anchor.web3.Keypair.generate();
+      const token = await getOrCreateAta(
+        authority,
+        tbtc.getMintPDA(),
+        holder.publicKey
+      );
+      const freezeIx = await tbtc.freezeAccountIx({
+        guardian: guardian.publicKey,
+        token,
+      });
+      await expectIxSuccess([freezeIx], [guardian]);
+
+      const frozenAccount = tbtc.getFrozenAccountPDA(token);
+      const { expiresAt } = await program.account.frozenAccount.fetch(
+        frozenAccount
+      );
+      await proposeAndExecute(
+        { extendFreeze: { token, extension: 60 } },
+        await tbtc.extendFreezeIx({ authority: multisigAuthority, token }, 60)
+      );
+      const record = await program.account.frozenAccount.fetch(frozenAccount);
+      expect(record.expiresAt.sub(expiresAt).toNumber()).to.equal(60);
+
+      await proposeAndExecute(
+        { thawAccount: { token } },
+        await tbtc.thawAccountIx({ authority: multisigAuthority, token })
+      );
+      const account = await spl.getAccount(program.provider.connection, token);
+      expect(account.isFrozen).to.be.false;
+    });
+
+    it("migrate actions through multisig", async () => {
+      // Without legacy accounts, each migration gets past the authority check
+      // and fails on the account it would migrate.
+      await proposeAndExecute(
+        {
+          migrateMinterInfo: {
+            minter: minter.publicKey,
+            mintedTotal: new anchor.BN(0),
+          },
+        },
+        await tbtc.migrateMinterInfoIx({
+          authority: multisigAuthority,
+          minter: minter.publicKey,
+        }),
+        "AccountNotMigratable"
+      );
+      await proposeAndExecute(
+        { migrateGuardianInfo: { guardian: guardian.publicKey } },
+        await tbtc.migrateGuardianInfoIx({
+          authority: multisigAuthority,
+          guardian: guardian.publicKey,
+        }),
+        "AccountNotMigratable"
+      );
+
+      const registries: [any, anchor.web3.PublicKey][] = [
+        [{ migrateMinters: {} }, tbtc.getMintersPDA()],
+        [{ migrateGuardians: {} }, tbtc.getGuardiansPDA()],
+      ];
+      for (const [action, legacy] of registries) {
+        const keys = [
+          { pubkey: tbtc.getConfigPDA(), isSigner: false, isWritable: true },
+          { pubkey: legacy, isSigner: false, isWritable: true },
+        ];
+        await proposeAndExecute(action, { keys }, "AccountNotInitialized");
+      }
+    });
+
+    it("remove guardian and minter through multisig", async () => {
+      await proposeAndExecute(
+        { removeGuardian: { guardian: guardian.publicKey } },
+        await tbtc.removeGuardianIx({
+          authority: multisigAuthority,
+          guardian: guardian.publicKey,
+        })
+      );
+      await proposeAndExecute(
+        { removeMinter: { minter: minter.publicKey } },
+        await tbtc.removeMinterIx({
+          authority: multisigAuthority,
+          minter: minter.publicKey,
+        })
+      );
+
+      const config = await tbtc.getConfigData();
+      expect(config.numGuardians).to.equal(0);
+      expect(config.numMinters).to.equal(0);
+    });
+
+    it("hand authority back", async () => {
+      const proposal = await propose(signers[0], {
+        changeAuthority: { newAuthority: authority.publicKey },
+      });
+      await approve(proposal);
+
+      const executeIx = await tbtc.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        [
+          { pubkey: tbtc.getConfigPDA(), isSigner: false, isWritable: true },
+          { pubkey: authority.publicKey, isSigner: false, isWritable: false },
+          {
+            pubkey: tbtc.getEventAuthorityPDA(),
+            isSigner: false,
+            isWritable: false,
+          },
+        ]
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+
+      const takeIx = await tbtc.takeAuthorityIx({
+        pendingAuthority: authority.publicKey,
+      });
+      await expectIxSuccess([takeIx], [authority]);
+
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("timelock", () => {
+    const delay = 2;
+
+    async function addMinterAccounts(minterKey: anchor.web3.PublicKey) {
+      return [
+        { pubkey: tbtc.getConfigPDA(), isSigner: false, isWritable: true },
+        {
+          pubkey: await tbtc.getNextMintersPagePDA(),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: tbtc.getMinterInfoPDA(minterKey),
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: minterKey, isSigner: false, isWritable: false },
+        {
+          pubkey: anchor.web3.SystemProgram.programId,
+          isSigner: false,
+          isWritable: false,
+        },
+      ];
+    }
+
+    async function schedule(action: any) {
+      const { numScheduledChanges } = await tbtc.getConfigData();
+      const scheduledChange = tbtc.getScheduledChangePDA(numScheduledChanges);
+      const ix = await tbtc.scheduleChangeIx(
+        { scheduledChange, authority: authority.publicKey },
+        action
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      return scheduledChange;
+    }
+
+    it("set up timelock", async () => {
+      // The timelock authority pays for accounts created by executed changes.
+      await transferLamports(
+        authority,
+        tbtc.getTimelockAuthorityPDA(),
+        1000000000
+      );
+
+      const addGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([addGuardianIx], [authority]);
+
+      const ix = await tbtc.updateTimelockDelayIx(
+        { authority: authority.publicKey },
+        delay
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const config = await tbtc.getConfigData();
+      expect(config.timelockDelay).to.equal(delay);
+    });
+
+    it("cannot add minter directly", async () => {
+      const ix = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxFail([ix], [authority], "TimelockRequired");
+    });
+
+    it("cannot shorten timelock delay directly", async () => {
+      const ix = await tbtc.updateTimelockDelayIx(
+        { authority: authority.publicKey },
+        0
+      );
+      await expectIxFail([ix], [authority], "TimelockRequired");
+    });
+
+    it("cannot schedule change (not authority)", async () => {
+      const ix = await tbtc.scheduleChangeIx(
+        { authority: imposter.publicKey },
+        { addMinter: { minter: minter.publicKey } }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("add minter after delay", async () => {
+      const scheduledChange = await schedule({
+        addMinter: { minter: minter.publicKey },
+      });
+
+      const earlyIx = await tbtc.executeScheduledChangeIx(
+        { scheduledChange, scheduledBy: authority.publicKey },
+        await addMinterAccounts(minter.publicKey)
+      );
+      await expectIxFail([earlyIx], [txPayer], "ScheduledChangeNotReady");
+
+      await sleep((delay + 1) * 1000);
+
+      const ix = await tbtc.executeScheduledChangeIx(
+        { scheduledChange, scheduledBy: authority.publicKey },
+        await addMinterAccounts(minter.publicKey)
+      );
+      await expectIxSuccess([ix], [txPayer]);
+      await tbtc.checkMinterInfo(minter.publicKey);
+    });
+
+    it("guardian cancels scheduled change", async () => {
+      const scheduledChange = await schedule({
+        addMinter: { minter: anotherMinter.publicKey },
+      });
+
+      const ix = await tbtc.cancelScheduledChangeIx({
+        guardian: guardian.publicKey,
+        scheduledChange,
+        scheduledBy: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [txPayer, guardian]);
+
+      const scheduledChangeInfo =
+        await program.provider.connection.getAccountInfo(scheduledChange);
+      expect(scheduledChangeInfo).to.be.null;
+    });
+
+    it("shorten timelock delay after delay", async () => {
+      const scheduledChange = await schedule({
+        updateTimelockDelay: { delay: 0 },
+      });
+
+      await sleep((delay + 1) * 1000);
+
+      const ix = await tbtc.executeScheduledChangeIx(
+        { scheduledChange, scheduledBy: authority.publicKey },
+        [{ pubkey: tbtc.getConfigPDA(), isSigner: false, isWritable: true }]
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      const config = await tbtc.getConfigData();
+      expect(config.timelockDelay).to.equal(0);
+    });
+
+    it("clean up", async () => {
+      const removeMinterIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([removeMinterIx, removeGuardianIx], [authority]);
+
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("pause expiry", () => {
+    const maxPauseDuration = 2;
+
+    it("set up guardians and pause params", async () => {
+      const addGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      const addAnotherGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: anotherGuardian.publicKey,
+      });
+      const updateParamsIx = await tbtc.updatePauseParamsIx(
+        { authority: authority.publicKey },
+        { maxPauseDuration, pauseCooldown: 60 }
+      );
+      await expectIxSuccess(
+        [addGuardianIx, addAnotherGuardianIx, updateParamsIx],
+        [authority]
+      );
+
+      const config = await tbtc.getConfigData();
+      expect(config.maxPauseDuration).to.equal(maxPauseDuration);
+      expect(config.pauseCooldown).to.equal(60);
+    });
+
+    it("cannot update pause params (zero max duration)", async () => {
+      const ix = await tbtc.updatePauseParamsIx(
+        { authority: authority.publicKey },
+        { maxPauseDuration: 0, pauseCooldown: 60 }
+      );
+      await expectIxFail([ix], [authority], "ZeroMaxPauseDuration");
+    });
+
+    it("pause records guardian and reason", async () => {
+      const ix = await tbtc.pauseIx({ guardian: guardian.publicKey }, 7);
+      await expectIxSuccess([ix], [txPayer, guardian]);
+
+      const config = await tbtc.getConfigData();
+      expect(config.paused).to.be.true;
+      expect(config.pauseGuardian).to.eql(guardian.publicKey);
+      expect(config.pauseReason).to.equal(7);
+      expect(config.pauseExpiresAt.sub(config.pausedAt).toNumber()).to.equal(
+        maxPauseDuration
+      );
+
+      const guardianInfo = await tbtc.getGuardianInfo(guardian.publicKey);
+      expect(guardianInfo.lastPausedAt.eq(config.pausedAt)).to.be.true;
+    });
+
+    it("cannot extend pause (not authority)", async () => {
+      const ix = await tbtc.extendPauseIx({ authority: imposter.publicKey }, 1);
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("extend pause", async () => {
+      const { pauseExpiresAt } = await tbtc.getConfigData();
+
+      const ix = await tbtc.extendPauseIx(
+        { authority: authority.publicKey },
+        1
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const config = await tbtc.getConfigData();
+      expect(config.pauseExpiresAt.sub(pauseExpiresAt).toNumber()).to.equal(1);
+    });
+
+    it("cannot pause again during cooldown", async () => {
+      // Wait for the pause to expire.
+      await sleep((maxPauseDuration + 2) * 1000);
+
+      const ix = await tbtc.pauseIx({ guardian: guardian.publicKey });
+      await expectIxFail([ix], [txPayer, guardian], "PauseCooldown");
+    });
+
+    it("unpause expired pause", async () => {
+      const { pauseExpiresAt } = await tbtc.getConfigData();
+
+      const ix = await tbtc.unpauseIx({ authority: authority.publicKey });
+      await expectIxSuccess([ix], [authority]);
+
+      const config = await tbtc.getConfigData();
+      expect(config.paused).to.be.false;
+      expect(config.pauseExpiresAt.eq(pauseExpiresAt)).to.be.true;
+    });
+
+    it("still cannot pause again during cooldown", async () => {
+      const ix = await tbtc.pauseIx({ guardian: guardian.publicKey });
+      await expectIxFail([ix], [txPayer, guardian], "PauseCooldown");
+    });
+
+    it("pause as another guardian after expiry", async () => {
+      const pauseIx = await tbtc.pauseIx({
+        guardian: anotherGuardian.publicKey,
+      });
+      await expectIxSuccess([pauseIx], [txPayer, anotherGuardian]);
+
+      const unpauseIx = await tbtc.unpauseIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([unpauseIx], [authority]);
+
+      const config = await tbtc.getConfigData();
+      expect(config.pauseGuardian).to.be.null;
+    });
+
+    it("clean up", async () => {
+      const updateParamsIx = await tbtc.updatePauseParamsIx(
+        { authority: authority.publicKey },
+        { maxPauseDuration: 7 * 24 * 60 * 60, pauseCooldown: 0 }
+      );
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      const removeAnotherGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: anotherGuardian.publicKey,
+      });
+      await expectIxSuccess(
+        [updateParamsIx, removeGuardianIx, removeAnotherGuardianIx],
+        [authority]
+      );
+
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("minter pausing", () => {
+    const recipientToken = spl.getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      recipient.publicKey
+    );
+
+    it("set up minter and guardian", async () => {
+      const addMinterIx = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const addGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([addMinterIx, addGuardianIx], [authority]);
+    });
+
+    it("pause minter", async () => {
+      const ix = await tbtc.pauseMinterIx({
+        guardian: guardian.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([ix], [txPayer, guardian]);
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.paused).to.be.true;
+    });
+
+    it("cannot pause minter again", async () => {
+      const ix = await tbtc.pauseMinterIx({
+        guardian: guardian.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxFail([ix], [txPayer, guardian], "MinterPaused");
+    });
+
+    it("cannot mint while minter is paused", async () => {
+      const ix = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(10)
+      );
+      await expectIxFail([ix], [txPayer, minter], "MinterPaused");
+    });
+
+    it("cannot unpause minter (not authority)", async () => {
+      const ix = await tbtc.unpauseMinterIx({
+        authority: imposter.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("unpause minter and mint", async () => {
+      const unpauseIx = await tbtc.unpauseMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([unpauseIx], [authority]);
+
+      const minterInfo = await tbtc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.paused).to.be.false;
+
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(10)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+    });
+
+    it("cannot unpause minter again", async () => {
+      const ix = await tbtc.unpauseMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxFail([ix], [authority], "IsNotPaused");
+    });
+
+    it("clean up", async () => {
+      // Burn what was minted so the supply is back to where it was.
+      const burnIx = await tbtc.burnIx(
+        {
+          ownerToken: recipientToken,
+          owner: recipient.publicKey,
+          minter: minter.publicKey,
+        },
+        new anchor.BN(10)
+      );
+      await expectIxSuccess([burnIx], [txPayer, recipient, minter]);
+
+      const removeMinterIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([removeMinterIx, removeGuardianIx], [authority]);
+
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("paged registries", () => {
+    // Enough minters to fill a page and spill over into the next one.
+    const pagedMinters = Array.from(
+      { length: tbtc.REGISTRY_PAGE_SIZE + 1 },
+      () => anchor.web3.Keypair.generate().publicKey
+    );
+
+    it("add minters across pages", async () => {
+      const { minterRegistryLen } = await tbtc.getConfigData();
+
+      for (const pagedMinter of pagedMinters) {
+        const ix = await tbtc.addMinterIx({
+          authority: authority.publicKey,
+          minter: pagedMinter,
+        });
+        await expectIxSuccess([ix], [authority]);
+      }
+
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: pagedMinters.length,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const config = await tbtc.getConfigData();
+      expect(config.minterRegistryLen).to.equal(
+        minterRegistryLen + pagedMinters.length
+      );
+
+      const minters = await tbtc.getMinters();
+      expect(minters.map((key) => key.toBase58())).to.have.members(
+        pagedMinters.map((key) => key.toBase58())
+      );
+
+      const first = await tbtc.getMinterInfo(pagedMinters[0]);
+      const last = await tbtc.getMinterInfo(
+        pagedMinters[pagedMinters.length - 1]
+      );
+      expect(last.registryPage).to.be.greaterThan(first.registryPage);
+    });
+
+    it("cannot remove minter from another page", async () => {
+      const { registryPage } = await tbtc.getMinterInfo(pagedMinters[0]);
+      const ix = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: pagedMinters[0],
+        mintersPage: tbtc.getMintersPagePDA(registryPage + 1),
+      });
+      await expectIxFail([ix], [authority], "ConstraintSeeds");
+    });
+
+    it("cannot remove minter without its page", async () => {
+      const ix = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: pagedMinters[0],
+        mintersPage: null,
+        minters: null,
+      });
+      await expectIxFail([ix], [authority], "MinterNonexistent");
+    });
+
+    it("remove minters across pages", async () => {
+      const { minterRegistryLen } = await tbtc.getConfigData();
+
+      for (const pagedMinter of pagedMinters) {
+        const ix = await tbtc.removeMinterIx({
+          authority: authority.publicKey,
+          minter: pagedMinter,
+        });
+        await expectIxSuccess([ix], [authority]);
+      }
+
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      // Freed slots are not reused, so the registry length does not shrink.
+      const config = await tbtc.getConfigData();
+      expect(config.minterRegistryLen).to.equal(minterRegistryLen);
+      expect(await tbtc.getMinters()).to.be.empty;
+    });
+  });
+
+  describe("roles", () => {
+    const roleHolder = anchor.web3.Keypair.generate();
+    const roleMinter = anchor.web3.Keypair.generate();
+    const minterAdmin = tbtc.getRoleAssignmentPDA(
+      "minterAdmin",
+      roleHolder.publicKey
+    );
+
+    it("set up role holder", async () => {
+      await transferLamports(authority, roleHolder.publicKey, 1000000000);
+    });
+
+    it("cannot grant role without authority", async () => {
+      const ix = await tbtc.grantRoleIx(
+        { authority: imposter.publicKey, holder: roleHolder.publicKey },
+        "minterAdmin"
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("grant minter admin role", async () => {
+      const ix = await tbtc.grantRoleIx(
+        { authority: authority.publicKey, holder: roleHolder.publicKey },
+        "minterAdmin"
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const assignment = await program.account.roleAssignment.fetch(
+        minterAdmin
+      );
+      expect(assignment.holder).to.eql(roleHolder.publicKey);
+      expect(assignment.role).to.eql({ minterAdmin: {} });
+    });
+
+    it("cannot add minter without passing role", async () => {
+      const ix = await tbtc.addMinterIx({
+        authority: roleHolder.publicKey,
+        minter: roleMinter.publicKey,
+      });
+      await expectIxFail([ix], [roleHolder], "IsNotAuthority");
+    });
+
+    it("cannot add guardian with minter admin role", async () => {
+      const ix = await tbtc.addGuardianIx({
+        authority: roleHolder.publicKey,
+        roleAssignment: minterAdmin,
+        guardian: guardian.publicKey,
+      });
+      await expectIxFail([ix], [roleHolder], "IsNotAuthority");
+    });
+
+    it("add and remove minter as minter admin", async () => {
+      const addIx = await tbtc.addMinterIx({
+        authority: roleHolder.publicKey,
+        roleAssignment: minterAdmin,
+        minter: roleMinter.publicKey,
+      });
+      await expectIxSuccess([addIx], [roleHolder]);
+      await tbtc.checkMinterInfo(roleMinter.publicKey);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const removeIx = await tbtc.removeMinterIx({
+        authority: roleHolder.publicKey,
+        roleAssignment: minterAdmin,
+        minter: roleMinter.publicKey,
+      });
+      await expectIxSuccess([removeIx], [roleHolder]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot revoke role without authority", async () => {
+      const ix = await tbtc.revokeRoleIx({
+        authority: imposter.publicKey,
+        roleAssignment: minterAdmin,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("revoke minter admin role", async () => {
+      const ix = await tbtc.revokeRoleIx({
+        authority: authority.publicKey,
+        roleAssignment: minterAdmin,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const assignment = await program.provider.connection.getAccountInfo(
+        minterAdmin
+      );
+      expect(assignment).to.be.null;
+
+      const addIx = await tbtc.addMinterIx({
+        authority: roleHolder.publicKey,
+        roleAssignment: minterAdmin,
+        minter: roleMinter.publicKey,
+      });
+      await expectIxFail([addIx], [roleHolder], "AccountNotInitialized");
+    });
+  });
+
+  describe("account versions", () => {
+    const versionedMinter = anchor.web3.Keypair.generate();
+
+    it("accounts are created at the current version", async () => {
+      const config = await tbtc.getConfigData();
+      expect(config.version).to.equal(3);
+
+      const addIx = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: versionedMinter.publicKey,
+      });
+      await expectIxSuccess([addIx], [authority]);
+
+      const minterInfo = await tbtc.getMinterInfo(versionedMinter.publicKey);
+      expect(minterInfo.version).to.equal(1);
+    });
+
+    it("cannot migrate current config", async () => {
+      const ix = await tbtc.migrateConfigIx({
+        authority: authority.publicKey,
+      });
+      await expectIxFail([ix], [authority], "AccountNotMigratable");
+    });
+
+    it("cannot migrate current minter info", async () => {
+      const ix = await tbtc.migrateMinterInfoIx({
+        authority: authority.publicKey,
+        minter: versionedMinter.publicKey,
+      });
+      await expectIxFail([ix], [authority], "AccountNotMigratable");
+
+      const removeIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: versionedMinter.publicKey,
+      });
+      await expectIxSuccess([removeIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("reconciliation", () => {
+    it("supply falls short of minted and burned totals", async () => {
+      // The minter limits tests burned 600 through the token program, which
+      // is not recorded.
+      const config = await tbtc.getConfigData();
+      const mint = await spl.getMint(
+        program.provider.connection,
+        config.mint
+      );
+      expect(
+        config.mintedTotal.sub(config.burnedTotal).subn(600).toString()
+      ).to.equal(mint.supply.toString());
+    });
+
+    it("reconcile without a guardian", async () => {
+      const ix = await tbtc.reconcileIx();
+      const txSig = await expectIxSuccess([ix], [txPayer]);
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(1);
+      expect(events[0].name).to.equal("SupplyReconciled");
+      expect(events[0].data.supply.toString()).to.equal("2000");
+      expect(events[0].data.discrepancy).to.be.true;
+      expect(events[0].data.pausedBy).to.be.null;
+    });
+
+    it("cannot reconcile with another guardian's info", async () => {
+      const addIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([addIx], [authority]);
+
+      const ix = await tbtc.reconcileIx({
+        guardianInfo: tbtc.getGuardianInfoPDA(guardian.publicKey),
+        guardian: imposter.publicKey,
+      });
+      await expectIxFail([ix], [txPayer, imposter], "GuardianInfoMismatch");
+    });
+
+    it("guardian does not pause on a shortfall", async () => {
+      const ix = await tbtc.reconcileIx({ guardian: guardian.publicKey });
+      const txSig = await expectIxSuccess([ix], [txPayer, guardian]);
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(1);
+      expect(events[0].data.discrepancy).to.be.true;
+      expect(events[0].data.pausedBy).to.be.null;
+
+      const removeIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([removeIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("minting to wallets", () => {
+    const walletMinter = anchor.web3.Keypair.generate();
+    const wallet = anchor.web3.Keypair.generate();
+    const walletToken = spl.getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      wallet.publicKey
+    );
+
+    it("mint to wallet without token account", async () => {
+      const addIx = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: walletMinter.publicKey,
+      });
+      await expectIxSuccess([addIx], [authority]);
+
+      const tokenBefore = await program.provider.connection.getAccountInfo(
+        walletToken
+      );
+      expect(tokenBefore).to.be.null;
+
+      const ix = await tbtc.mintToWalletIx(
+        {
+          minter: walletMinter.publicKey,
+          payer: txPayer.publicKey,
+          recipient: wallet.publicKey,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([ix], [txPayer, walletMinter]);
+
+      expect(await getTokenBalance(walletToken)).to.equal(BigInt(100));
+    });
+
+    it("mint to wallet with token account", async () => {
+      const ix = await tbtc.mintToWalletIx(
+        {
+          minter: walletMinter.publicKey,
+          payer: txPayer.publicKey,
+          recipient: wallet.publicKey,
+        },
+        new anchor.BN(50)
+      );
+      await expectIxSuccess([ix], [txPayer, walletMinter]);
+
+      expect(await getTokenBalance(walletToken)).to.equal(BigInt(150));
+    });
+
+    it("cannot mint to wallet without minter", async () => {
+      const ix = await tbtc.mintToWalletIx(
+        {
+          minter: imposter.publicKey,
+          payer: txPayer.publicKey,
+          recipient: wallet.publicKey,
+        },
+        new anchor.BN(50)
+      );
+      await expectIxFail([ix], [txPayer, imposter], "AccountNotInitialized");
+
+      // Burn what was minted so the supply is back to where it was.
+      const burnIx = await tbtc.burnIx(
+        {
+          ownerToken: walletToken,
+          owner: wallet.publicKey,
+          minter: walletMinter.publicKey,
+        },
+        new anchor.BN(150)
+      );
+      await expectIxSuccess([burnIx], [txPayer, wallet, walletMinter]);
+
+      const removeIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: walletMinter.publicKey,
+      });
+      await expectIxSuccess([removeIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("batch minting", () => {
+    const batchMinter = anchor.web3.Keypair.generate();
+    const batchRecipients = [
+      anchor.web3.Keypair.generate(),
+      anchor.web3.Keypair.generate(),
+    ];
+    let batchTokens: anchor.web3.PublicKey[];
+
+    it("mint batch", async () => {
+      const addIx = await tbtc.addMinterIx({
+        authority: authority.publicKey,
+        minter: batchMinter.publicKey,
+      });
+      await expectIxSuccess([addIx], [authority]);
+
+      batchTokens = await Promise.all(
+        batchRecipients.map((recipient) =>
+          getOrCreateAta(authority, tbtc.getMintPDA(), recipient.publicKey)
+        )
+      );
+
+      const ix = await tbtc.mintBatchIx(
+        { minter: batchMinter.publicKey },
+        [new anchor.BN(30), new anchor.BN(70)],
+        batchTokens
+      );
+      const txSig = await expectIxSuccess([ix], [txPayer, batchMinter]);
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(2);
+      expect(events[0].name).to.equal("TbtcMinted");
+      expect(events[0].data.recipientToken).to.eql(batchTokens[0]);
+      expect(events[0].data.amount.toNumber()).to.equal(30);
+      expect(events[1].data.recipientToken).to.eql(batchTokens[1]);
+      expect(events[1].data.amount.toNumber()).to.equal(70);
+
+      expect(await getTokenBalance(batchTokens[0])).to.equal(BigInt(30));
+      expect(await getTokenBalance(batchTokens[1])).to.equal(BigInt(70));
+
+      const minterInfo = await tbtc.getMinterInfo(batchMinter.publicKey);
+      expect(minterInfo.mintedTotal.toNumber()).to.equal(100);
+    });
+
+    it("cannot mint batch with mismatched amounts", async () => {
+      const ix = await tbtc.mintBatchIx(
+        { minter: batchMinter.publicKey },
+        [new anchor.BN(30)],
+        batchTokens
+      );
+      await expectIxFail(
+        [ix],
+        [txPayer, batchMinter],
+        "MintBatchLengthMismatch"
+      );
+    });
+
+    it("cannot mint batch over allowance", async () => {
+      const limitsIx = await tbtc.updateMinterLimitsIx(
+        {
+          authority: authority.publicKey,
+          minter: batchMinter.publicKey,
+        },
+        {
+          allowance: new anchor.BN(150),
+          rateLimitAmount: new anchor.BN(0),
+          rateLimitPeriod: 0,
+        }
+      );
+      await expectIxSuccess([limitsIx], [authority]);
+
+      const ix = await tbtc.mintBatchIx(
+        { minter: batchMinter.publicKey },
+        [new anchor.BN(30), new anchor.BN(30)],
+        batchTokens
+      );
+      await expectIxFail(
+        [ix],
+        [txPayer, batchMinter],
+        "MinterAllowanceExceeded"
+      );
+
+      // Burn what was minted so the supply is back to where it was.
+      const burnIxs = await Promise.all(
+        batchRecipients.map((recipient, i) =>
+          tbtc.burnIx(
+            {
+              ownerToken: batchTokens[i],
+              owner: recipient.publicKey,
+              minter: batchMinter.publicKey,
+            },
+            new anchor.BN(i === 0 ? 30 : 70)
+          )
+        )
+      );
+      await expectIxSuccess(burnIxs, [
+        txPayer,
+        ...batchRecipients,
+        batchMinter,
+      ]);
+
+      const removeIx = await tbtc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: batchMinter.publicKey,
+      });
+      await expectIxSuccess([removeIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("account freezing", () => {
+    const holder = anchor.web3.Keypair.generate();
+    let holderToken: anchor.web3.PublicKey;
+
+    it("mint has the config as freeze authority", async () => {
+      const mint = await spl.getMint(
+        program.provider.connection,
+        tbtc.getMintPDA()
+      );
+      expect(mint.freezeAuthority).to.eql(tbtc.getConfigPDA());
+
+      const config = await tbtc.getConfigData();
+      expect(config.maxFreezeDuration).to.equal(7 * 24 * 60 * 60);
+    });
+
+    it("cannot freeze without guardian", async () => {
+      holderToken = await getOrCreateAta(
+        authority,
+        tbtc.getMintPDA(),
+        holder.publicKey
+      );
+
+      const ix = await tbtc.freezeAccountIx({
+        guardian: imposter.publicKey,
+        token: holderToken,
+      });
+      await expectIxFail([ix], [imposter], "AccountNotInitialized");
+    });
+
+    it("guardian freezes account", async () => {
+      await transferLamports(authority, guardian.publicKey, 1000000000);
+      const addIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([addIx], [authority]);
+
+      const ix = await tbtc.freezeAccountIx({
+        guardian: guardian.publicKey,
+        token: holderToken,
+      });
+      const txSig = await expectIxSuccess([ix], [guardian]);
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(1);
+      expect(events[0].name).to.equal("AccountFrozen");
+      expect(events[0].data.token).to.eql(holderToken);
+      expect(events[0].data.guardian).to.eql(guardian.publicKey);
+
+      const account = await spl.getAccount(
+        program.provider.connection,
+        holderToken
+      );
+      expect(account.isFrozen).to.be.true;
+    });
+
+    it("cannot thaw unexpired freeze without authority", async () => {
+      const ix = await tbtc.thawAccountIx({
+        authority: imposter.publicKey,
+        token: holderToken,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("guardian cannot extend freeze", async () => {
+      const freezeIx = await tbtc.freezeAccountIx({
+        guardian: guardian.publicKey,
+        token: holderToken,
+      });
+      await expectIxFail([freezeIx], [guardian], "FreezeCooldown");
+
+      const extendIx = await tbtc.extendFreezeIx(
+        { authority: guardian.publicKey, token: holderToken },
+        60
+      );
+      await expectIxFail([extendIx], [guardian], "IsNotAuthority");
+    });
+
+    it("authority extends freeze", async () => {
+      const { expiresAt } = await program.account.frozenAccount.fetch(
+        tbtc.getFrozenAccountPDA(holderToken)
+      );
+
+      const ix = await tbtc.extendFreezeIx(
+        { authority: authority.publicKey, token: holderToken },
+        60
+      );
+      const txSig = await expectIxSuccess([ix], [authority]);
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(1);
+      expect(events[0].name).to.equal("FreezeExtended");
+      expect(events[0].data.token).to.eql(holderToken);
+
+      const record = await program.account.frozenAccount.fetch(
+        tbtc.getFrozenAccountPDA(holderToken)
+      );
+      expect(record.expiresAt.toNumber()).to.equal(expiresAt.toNumber() + 60);
+      expect(events[0].data.expiresAt.toNumber()).to.equal(
+        record.expiresAt.toNumber()
+      );
+    });
+
+    it("authority thaws account", async () => {
+      const ix = await tbtc.thawAccountIx({
+        authority: authority.publicKey,
+        token: holderToken,
+      });
+      const txSig = await expectIxSuccess([ix], [authority]);
+
+      const events = await tbtc.getCpiEvents(txSig);
+      expect(events).has.length(1);
+      expect(events[0].name).to.equal("AccountThawed");
+
+      const account = await spl.getAccount(
+        program.provider.connection,
+        holderToken
+      );
+      expect(account.isFrozen).to.be.false;
+
+      // The record is kept, with the freeze ending now.
+      const record = await program.account.frozenAccount.fetch(
+        tbtc.getFrozenAccountPDA(holderToken)
+      );
+      expect(record.frozenBy).to.eql(guardian.publicKey);
+      expect(record.expiresAt.toNumber()).to.be.at.most(
+        Math.floor(Date.now() / 1000) + 5
+      );
+    });
+
+    it("cannot extend ended freeze", async () => {
+      const ix = await tbtc.extendFreezeIx(
+        { authority: authority.publicKey, token: holderToken },
+        60
+      );
+      await expectIxFail([ix], [authority], "FreezeNotActive");
+    });
+
+    it("cannot update max freeze duration to zero", async () => {
+      const ix = await tbtc.updateMaxFreezeDurationIx(
+        { authority: authority.publicKey },
+        0
+      );
+      await expectIxFail([ix], [authority], "ZeroMaxFreezeDuration");
+    });
+
+    it("anyone thaws expired freeze", async () => {
+      // Let the guardian freeze the account again right away, then require a
+      // cooldown for the next freeze.
+      const noCooldownIx = await tbtc.updatePauseParamsIx(
+        { authority: authority.publicKey },
+        { maxPauseDuration: 7 * 24 * 60 * 60, pauseCooldown: 0 }
+      );
+      const updateIx = await tbtc.updateMaxFreezeDurationIx(
+        { authority: authority.publicKey },
+        1
+      );
+      await expectIxSuccess([noCooldownIx, updateIx], [authority]);
+
+      const freezeIx = await tbtc.freezeAccountIx({
+        guardian: guardian.publicKey,
+        token: holderToken,
+      });
+      await expectIxSuccess([freezeIx], [guardian]);
+
+      const cooldownIx = await tbtc.updatePauseParamsIx(
+        { authority: authority.publicKey },
+        { maxPauseDuration: 7 * 24 * 60 * 60, pauseCooldown: 60 }
+      );
+      await expectIxSuccess([cooldownIx], [authority]);
+
+      await sleep(2000);
+
+      const thawIx = await tbtc.thawAccountIx({
+        authority: imposter.publicKey,
+        token: holderToken,
+      });
+
+      // Not even in the same transaction as the thaw.
+      await expectIxFail(
+        [thawIx, freezeIx],
+        [imposter, guardian],
+        "FreezeCooldown"
+      );
+
+      await expectIxSuccess([thawIx], [imposter]);
+    });
+
+    it("guardian cannot re-freeze right after expiry", async () => {
+      const freezeIx = await tbtc.freezeAccountIx({
+        guardian: guardian.publicKey,
+        token: holderToken,
+      });
+      await expectIxFail([freezeIx], [guardian], "FreezeCooldown");
+
+      const account = await spl.getAccount(
+        program.provider.connection,
+        holderToken
+      );
+      expect(account.isFrozen).to.be.false;
+
+      const resetIx = await tbtc.updateMaxFreezeDurationIx(
+        { authority: authority.publicKey },
+        7 * 24 * 60 * 60
+      );
+      const resetCooldownIx = await tbtc.updatePauseParamsIx(
+        { authority: authority.publicKey },
+        { maxPauseDuration: 7 * 24 * 60 * 60, pauseCooldown: 0 }
+      );
+      const removeIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([resetIx, resetCooldownIx, removeIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+      await expectIxSuccess([freezeIx], [guardian]);
+
+      await sleep(2000);
+
+      const thawIx = await tbtc.thawAccountIx({
+        authority: imposter.publicKey,
+        token: holderToken,
+        frozenBy: guardian.publicKey,
+      });
+      await expectIxSuccess([thawIx], [imposter]);
+
+      const resetIx = await tbtc.updateMaxFreezeDurationIx(
+        { authority: authority.publicKey },
+        7 * 24 * 60 * 60
+      );
+      const removeIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([resetIx, removeIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
 });
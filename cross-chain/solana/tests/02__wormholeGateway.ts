@@ -1,12 +1,23 @@
 import { MockEthereumTokenBridge } from "@certusone/wormhole-sdk/lib/cjs/mock";
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
-import { PublicKey } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createTransferInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+} from "@solana/spl-token";
+import { AccountMeta, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
+import { MockCallTarget } from "../target/types/mock_call_target";
 import { WormholeGateway } from "../target/types/wormhole_gateway";
+import { tryNativeToUint8Array } from "@certusone/wormhole-sdk";
 import {
+  ETHEREUM_TBTC_ADDRESS,
   ETHEREUM_TOKEN_BRIDGE_ADDRESS,
+  MOCK_CALL_TARGET_PROGRAM_ID,
+  TBTC_PROGRAM_ID,
   WORMHOLE_GATEWAY_PROGRAM_ID,
   WRAPPED_TBTC_MINT,
   ethereumGatewaySendTbtc,
@@ -15,11 +26,16 @@ import {
   generatePayer,
   getOrCreateAta,
   preloadWrappedTbtc,
+  sleep,
   transferLamports,
 } from "./helpers";
 import * as tbtc from "./helpers/tbtc";
 import * as wormholeGateway from "./helpers/wormholeGateway";
 
+const ETHEREUM_TBTC_TOKEN = Array.from(
+  tryNativeToUint8Array(ETHEREUM_TBTC_ADDRESS, "ethereum")
+);
+
 async function setup(
   program: Program<WormholeGateway>,
   authority,
@@ -31,7 +47,11 @@ async function setup(
   const tokenBridgeSender = wormholeGateway.getTokenBridgeSenderPDA();
 
   await program.methods
-    .initialize(new anchor.BN(mintingLimit.toString()))
+    .initialize({
+      mintingLimit: new anchor.BN(mintingLimit.toString()),
+      tbtcTokenChain: 2,
+      tbtcTokenAddress: ETHEREUM_TBTC_TOKEN,
+    })
     .accounts({
       authority: authority.publicKey,
       custodian,
@@ -98,6 +118,12 @@ describe("wormhole-gateway", () => {
         mintingLimit,
         pendingAuthority: null,
       });
+      await wormholeGateway.checkTbtcToken({
+        chain: 2,
+        address: ETHEREUM_TBTC_TOKEN,
+        wrappedTbtcMint: WRAPPED_TBTC_MINT,
+        wrappedTbtcToken: gatewayWrappedTbtcToken,
+      });
       await tbtc.checkConfig({
         authority: authority.publicKey,
         numMinters: 0,
@@ -117,6 +143,16 @@ describe("wormhole-gateway", () => {
       // Give the impostor some lamports.
       await transferLamports(authority, imposter.publicKey, 100000000000);
     });
+
+    it("cannot migrate current custodian", async () => {
+      const { version } = await wormholeGateway.getCustodianData();
+      expect(version).to.equal(4);
+
+      const ix = await wormholeGateway.migrateCustodianIx({
+        authority: authority.publicKey,
+      });
+      await expectIxFail([ix], [authority], "AccountNotMigratable");
+    });
   });
 
   describe("authority changes", () => {
@@ -307,6 +343,17 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([failingIx], [imposter], "IsNotAuthority");
     });
+
+    it("cannot migrate current gateway info", async () => {
+      const { version } = await wormholeGateway.getGatewayInfo(chain);
+      expect(version).to.equal(4);
+
+      const ix = await wormholeGateway.migrateGatewayInfoIx(
+        { authority: authority.publicKey },
+        chain
+      );
+      await expectIxFail([ix], [authority], "AccountNotMigratable");
+    });
   });
 
   describe("deposit wrapped tbtc", () => {
@@ -598,6 +645,55 @@ describe("wormhole-gateway", () => {
       await expectIxFail([ix], [payer], "TransferAlreadyRedeemed");
     });
 
+    it("receive tbtc (ata doesn't exist)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use a recipient that has never held tBTC.
+      const recipient = anchor.web3.Keypair.generate().publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      expect(await connection.getAccountInfo(recipientToken)).to.be.null;
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Get minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      const sentAmount = BigInt(100);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // The gateway created the recipient's token account.
+      const tbtcAfter = await getAccount(connection, recipientToken);
+      expect(tbtcAfter.owner).to.eql(recipient);
+      expect(tbtcAfter.amount).to.equal(sentAmount);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore + sentAmount);
+    });
+
     it("receive wrapped tbtc (ata doesn't exist)", async () => {
       // Set up new wallet
       const payer = await generatePayer(authority);
@@ -1070,7 +1166,7 @@ describe("wormhole-gateway", () => {
           nonce,
         }
       );
-      await expectIxFail([ix], [commonTokenOwner], "AccountNotInitialized");
+      await expectIxFail([ix], [commonTokenOwner], "GatewayNotRegistered");
     });
   });
 
@@ -1229,4 +1325,2062 @@ describe("wormhole-gateway", () => {
       await expectIxFail([ix], [commonTokenOwner], "ZeroRecipient");
     });
   });
+
+  describe("recover tokens", () => {
+    it("recover surplus wrapped tbtc", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      const payerWrappedToken = await preloadWrappedTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(1000),
+        payer.publicKey
+      );
+
+      // Send wrapped tBTC directly to custody, which does not mint anything.
+      const transferIx = createTransferInstruction(
+        payerWrappedToken,
+        gatewayWrappedTbtcToken,
+        payer.publicKey,
+        100
+      );
+      await expectIxSuccess([transferIx], [payer]);
+
+      const [custodyBefore, mintedAmount] = await Promise.all([
+        getAccount(connection, gatewayWrappedTbtcToken),
+        wormholeGateway.getMintedAmount(),
+      ]);
+      const surplus = custodyBefore.amount - mintedAmount;
+      expect(surplus >= BigInt(100)).to.be.true;
+
+      const ix = await wormholeGateway.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: gatewayWrappedTbtcToken,
+          recipientToken: payerWrappedToken,
+        },
+        surplus
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const custodyAfter = await getAccount(
+        connection,
+        gatewayWrappedTbtcToken
+      );
+      expect(custodyAfter.amount).to.equal(mintedAmount);
+    });
+
+    it("cannot recover wrapped tbtc backing minted tbtc", async () => {
+      const recipientToken = await getOrCreateAta(
+        authority,
+        WRAPPED_TBTC_MINT,
+        authority.publicKey
+      );
+
+      const ix = await wormholeGateway.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: gatewayWrappedTbtcToken,
+          recipientToken,
+        },
+        BigInt(1)
+      );
+      await expectIxFail([ix], [authority], "RecoveryExceedsSurplus");
+    });
+
+    it("cannot recover tokens (not authority)", async () => {
+      const recipientToken = await getOrCreateAta(
+        authority,
+        WRAPPED_TBTC_MINT,
+        imposter.publicKey
+      );
+
+      const ix = await wormholeGateway.recoverTokensIx(
+        {
+          authority: imposter.publicKey,
+          sourceToken: gatewayWrappedTbtcToken,
+          recipientToken,
+        },
+        BigInt(1)
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+  });
+
+  describe("multisig", () => {
+    const signers = [
+      anchor.web3.Keypair.generate(),
+      anchor.web3.Keypair.generate(),
+    ];
+    const multisigAuthority = wormholeGateway.getMultisigAuthorityPDA();
+
+    async function proposeAndApprove(action: any) {
+      const { numProposals } = await wormholeGateway.getMultisigData();
+      const proposal = wormholeGateway.getProposalPDA(numProposals);
+      const createIx = await wormholeGateway.createProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        action
+      );
+      const approveIx = await wormholeGateway.approveProposalIx({
+        proposal,
+        signer: signers[1].publicKey,
+      });
+      await expectIxSuccess([createIx, approveIx], [signers[0], signers[1]]);
+
+      return proposal;
+    }
+
+    it("set up multisig", async () => {
+      for (const signer of signers) {
+        await transferLamports(authority, signer.publicKey, 1000000000);
+      }
+
+      // The multisig authority pays for accounts created by executed proposals.
+      await transferLamports(authority, multisigAuthority, 1000000000);
+
+      const ix = await wormholeGateway.initializeMultisigIx(
+        { authority: authority.publicKey },
+        {
+          signers: signers.map((signer) => signer.publicKey),
+          threshold: 2,
+        }
+      );
+      await expectIxSuccess([ix], [authority]);
+    });
+
+    it("hand authority to multisig", async () => {
+      const changeIx = await wormholeGateway.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: multisigAuthority,
+      });
+      await expectIxSuccess([changeIx], [authority]);
+
+      const proposal = await proposeAndApprove({ takeAuthority: {} });
+      const executeIx = await wormholeGateway.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        [{ pubkey: custodian, isSigner: false, isWritable: true }]
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+
+      const { authority: custodianAuthority } =
+        await wormholeGateway.getCustodianData();
+      expect(custodianAuthority).to.eql(multisigAuthority);
+    });
+
+    it("cannot update minting limit directly (not authority)", async () => {
+      const ix = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(1)
+      );
+      await expectIxFail([ix], [authority], "IsNotAuthority");
+    });
+
+    it("update minting limit through multisig", async () => {
+      const newLimit = BigInt(42000);
+      const proposal = await proposeAndApprove({
+        updateMintingLimit: { newLimit: new anchor.BN(newLimit.toString()) },
+      });
+      const executeIx = await wormholeGateway.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        [{ pubkey: custodian, isSigner: false, isWritable: true }]
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+
+      await wormholeGateway.checkCustodian({
+        authority: multisigAuthority,
+        mintingLimit: newLimit,
+        pendingAuthority: null,
+      });
+    });
+
+    it("update gateway address through multisig", async () => {
+      const chain = 10;
+      const address = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const proposal = await proposeAndApprove({
+        updateGatewayAddress: { chain, address },
+      });
+      const executeIx = await wormholeGateway.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        [
+          { pubkey: custodian, isSigner: false, isWritable: false },
+          {
+            pubkey: wormholeGateway.getGatewayInfoPDA(chain),
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: anchor.web3.SystemProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+        ]
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+      await wormholeGateway.checkGateway(chain, address);
+    });
+
+    // Executes an approved proposal, passing the accounts of the instruction
+    // the multisig authority would otherwise sign.
+    async function proposeAndExecute(
+      action: any,
+      ix: { keys: AccountMeta[] },
+      expectedError?: string
+    ) {
+      const proposal = await proposeAndApprove(action);
+      const executeIx = await wormholeGateway.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        ix.keys.map((key) => ({ ...key, isSigner: false }))
+      );
+      if (expectedError === undefined) {
+        return expectIxSuccess([executeIx], [txPayer]);
+      }
+      return expectIxFail([executeIx], [txPayer], expectedError);
+    }
+
+    it("cancel authority change through multisig", async () => {
+      await proposeAndExecute(
+        { changeAuthority: { newAuthority: newAuthority.publicKey } },
+        await wormholeGateway.changeAuthorityIx({
+          authority: multisigAuthority,
+          newAuthority: newAuthority.publicKey,
+        })
+      );
+      await proposeAndExecute(
+        { cancelAuthorityChange: {} },
+        await wormholeGateway.cancelAuthorityChangeIx({
+          authority: multisigAuthority,
+        })
+      );
+
+      await wormholeGateway.checkCustodian({
+        authority: multisigAuthority,
+        mintingLimit: BigInt(42000),
+        pendingAuthority: null,
+      });
+    });
+
+    it("update multisig through multisig", async () => {
+      const { signerSetSeqno } = await wormholeGateway.getMultisigData();
+      await proposeAndExecute(
+        {
+          updateMultisig: {
+            signers: signers.map((signer) => signer.publicKey),
+            threshold: 2,
+          },
+        },
+        { keys: [] }
+      );
+
+      const multisig = await wormholeGateway.getMultisigData();
+      expect(multisig.signerSetSeqno).to.equal(signerSetSeqno + 1);
+    });
+
+    it("schedule change through multisig", async () => {
+      // Keep the delay at zero, since shortening it later needs the timelock.
+      await proposeAndExecute(
+        { updateTimelockDelay: { delay: 0 } },
+        await wormholeGateway.updateTimelockDelayIx(
+          { authority: multisigAuthority },
+          0
+        )
+      );
+
+      const { numScheduledChanges } = await wormholeGateway.getCustodianData();
+      const scheduledChange =
+        wormholeGateway.getScheduledChangePDA(numScheduledChanges);
+      const action = { updateMintingLimit: { newLimit: new anchor.BN(43000) } };
+      await proposeAndExecute(
+        { scheduleChange: { action } },
+        await wormholeGateway.scheduleChangeIx(
+          { scheduledChange, authority: multisigAuthority },
+          action
+        )
+      );
+
+      const ix = await wormholeGateway.executeScheduledChangeIx(
+        { scheduledChange, scheduledBy: multisigAuthority },
+        [{ pubkey: custodian, isSigner: false, isWritable: true }]
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      await wormholeGateway.checkCustodian({
+        authority: multisigAuthority,
+        mintingLimit: BigInt(43000),
+        pendingAuthority: null,
+      });
+
+      await proposeAndExecute(
+        { updateMintingLimit: { newLimit: new anchor.BN(42000) } },
+        { keys: [{ pubkey: custodian, isSigner: false, isWritable: true }] }
+      );
+    });
+
+    it("update inbound limit through multisig", async () => {
+      const { inboundLimitAmount, inboundLimitPeriod } =
+        await wormholeGateway.getCustodianData();
+      await proposeAndExecute(
+        { updateInboundLimit: { amount: new anchor.BN(1000), period: 60 } },
+        await wormholeGateway.updateInboundLimitIx(
+          { authority: multisigAuthority },
+          { amount: BigInt(1000), period: 60 }
+        )
+      );
+
+      const custodianData = await wormholeGateway.getCustodianData();
+      expect(custodianData.inboundLimitAmount.toNumber()).to.equal(1000);
+      expect(custodianData.inboundLimitPeriod).to.equal(60);
+
+      await proposeAndExecute(
+        {
+          updateInboundLimit: {
+            amount: inboundLimitAmount,
+            period: inboundLimitPeriod,
+          },
+        },
+        await wormholeGateway.updateInboundLimitIx(
+          { authority: multisigAuthority },
+          {
+            amount: BigInt(inboundLimitAmount.toString()),
+            period: inboundLimitPeriod,
+          }
+        )
+      );
+    });
+
+    it("update gateway limits through multisig", async () => {
+      const chain = 10;
+      await proposeAndExecute(
+        {
+          updateGatewayLimits: {
+            chain,
+            outboundLimitAmount: new anchor.BN(1000),
+            outboundLimitPeriod: 60,
+            minTransferAmount: new anchor.BN(1),
+            maxTransferAmount: new anchor.BN(500),
+          },
+        },
+        await wormholeGateway.updateGatewayLimitsIx(
+          { authority: multisigAuthority },
+          {
+            chain,
+            outboundLimitAmount: BigInt(1000),
+            outboundLimitPeriod: 60,
+            minTransferAmount: BigInt(1),
+            maxTransferAmount: BigInt(500),
+          }
+        )
+      );
+
+      const gatewayInfo = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfo.outboundLimitAmount.toNumber()).to.equal(1000);
+      expect(gatewayInfo.outboundLimitPeriod).to.equal(60);
+      expect(gatewayInfo.minTransferAmount.toNumber()).to.equal(1);
+      expect(gatewayInfo.maxTransferAmount.toNumber()).to.equal(500);
+    });
+
+    it("treasury actions through multisig", async () => {
+      const chain = 10;
+      const fees = {
+        chain,
+        inboundFeeBps: 10,
+        inboundMinFee: BigInt(1),
+        outboundFeeBps: 20,
+        outboundMinFee: BigInt(2),
+      };
+      const feesAction = {
+        updateGatewayFees: {
+          ...fees,
+          inboundMinFee: new anchor.BN(1),
+          outboundMinFee: new anchor.BN(2),
+        },
+      };
+      const feesIx = await wormholeGateway.updateGatewayFeesIx(
+        { authority: multisigAuthority },
+        fees
+      );
+
+      // Fees cannot be set before the treasury exists.
+      await proposeAndExecute(feesAction, feesIx, "AccountNotInitialized");
+
+      await proposeAndExecute(
+        { initializeTreasury: {} },
+        await wormholeGateway.initializeTreasuryIx({
+          authority: multisigAuthority,
+        })
+      );
+      const treasuryToken = wormholeGateway.getTreasuryTokenPDA();
+      const { amount } = await getAccount(connection, treasuryToken);
+      expect(amount).to.equal(BigInt(0));
+
+      await proposeAndExecute(feesAction, feesIx);
+      const gatewayInfo = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfo.inboundFeeBps).to.equal(10);
+      expect(gatewayInfo.inboundMinFee.toNumber()).to.equal(1);
+      expect(gatewayInfo.outboundFeeBps).to.equal(20);
+      expect(gatewayInfo.outboundMinFee.toNumber()).to.equal(2);
+
+      const recipientToken = await getOrCreateAta(
+        authority,
+        tbtcMint,
+        authority.publicKey
+      );
+      await proposeAndExecute(
+        { withdrawFees: { recipientToken, amount: new anchor.BN(0) } },
+        await wormholeGateway.withdrawFeesIx(
+          { authority: multisigAuthority, recipientToken },
+          BigInt(0)
+        )
+      );
+    });
+
+    it("gateway guardian actions through multisig", async () => {
+      const guardian = anchor.web3.Keypair.generate().publicKey;
+      const gatewayGuardian = wormholeGateway.getGatewayGuardianPDA(guardian);
+      await proposeAndExecute(
+        { addGatewayGuardian: { guardian } },
+        await wormholeGateway.addGatewayGuardianIx({
+          authority: multisigAuthority,
+          guardian,
+        })
+      );
+      expect(await connection.getAccountInfo(gatewayGuardian)).to.not.be.null;
+
+      for (const mode of [{ paused: {} }, { active: {} }]) {
+        await proposeAndExecute(
+          { setGatewayMode: { mode } },
+          await wormholeGateway.setGatewayModeIx(
+            { signer: multisigAuthority },
+            mode
+          )
+        );
+        const custodianData = await wormholeGateway.getCustodianData();
+        expect(custodianData.mode).to.eql(mode);
+      }
+
+      await proposeAndExecute(
+        { removeGatewayGuardian: { guardian } },
+        await wormholeGateway.removeGatewayGuardianIx({
+          authority: multisigAuthority,
+          guardian,
+        })
+      );
+      expect(await connection.getAccountInfo(gatewayGuardian)).to.be.null;
+    });
+
+    it("call target actions through multisig", async () => {
+      const callTarget = wormholeGateway.getCallTargetPDA(TBTC_PROGRAM_ID);
+      await proposeAndExecute(
+        { addCallTarget: { program: TBTC_PROGRAM_ID } },
+        await wormholeGateway.addCallTargetIx({
+          authority: multisigAuthority,
+          program: TBTC_PROGRAM_ID,
+        })
+      );
+      expect(await connection.getAccountInfo(callTarget)).to.not.be.null;
+
+      await proposeAndExecute(
+        { removeCallTarget: { program: TBTC_PROGRAM_ID } },
+        await wormholeGateway.removeCallTargetIx({
+          authority: multisigAuthority,
+          program: TBTC_PROGRAM_ID,
+        })
+      );
+      expect(await connection.getAccountInfo(callTarget)).to.be.null;
+    });
+
+    it("recover tokens through multisig", async () => {
+      const payer = await generatePayer(authority);
+      const payerWrappedToken = await preloadWrappedTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(100),
+        payer.publicKey
+      );
+      const transferIx = createTransferInstruction(
+        payerWrappedToken,
+        gatewayWrappedTbtcToken,
+        payer.publicKey,
+        100
+      );
+      await expectIxSuccess([transferIx], [payer]);
+
+      await proposeAndExecute(
+        {
+          recoverTokens: {
+            sourceToken: gatewayWrappedTbtcToken,
+            recipientToken: payerWrappedToken,
+            amount: new anchor.BN(100),
+          },
+        },
+        await wormholeGateway.recoverTokensIx(
+          {
+            authority: multisigAuthority,
+            sourceToken: gatewayWrappedTbtcToken,
+            recipientToken: payerWrappedToken,
+          },
+          BigInt(100)
+        )
+      );
+
+      const { amount } = await getAccount(connection, payerWrappedToken);
+      expect(amount).to.equal(BigInt(100));
+    });
+
+    it("update tbtc token through multisig", async () => {
+      // Wrapped tBTC backing minted tBTC is still in custody.
+      await proposeAndExecute(
+        { updateTbtcToken: { chain: 2, address: ETHEREUM_TBTC_TOKEN } },
+        await wormholeGateway.updateTbtcTokenIx(
+          { authority: multisigAuthority },
+          { chain: 2, address: ETHEREUM_TBTC_TOKEN }
+        ),
+        "WrappedTbtcInCustody"
+      );
+    });
+
+    it("migrate gateway info through multisig", async () => {
+      // Current gateway infos get past the authority check and are rejected.
+      await proposeAndExecute(
+        { migrateGatewayInfo: { chain: 10 } },
+        await wormholeGateway.migrateGatewayInfoIx(
+          { authority: multisigAuthority },
+          10
+        ),
+        "AccountNotMigratable"
+      );
+    });
+
+    it("disable gateway through multisig", async () => {
+      await proposeAndExecute(
+        { disableGateway: { chain: 10 } },
+        await wormholeGateway.disableGatewayIx(
+          { authority: multisigAuthority },
+          10
+        )
+      );
+
+      const { disabled } = await wormholeGateway.getGatewayInfo(10);
+      expect(disabled).to.be.true;
+    });
+
+    it("cannot approve proposal (not signer)", async () => {
+      const { numProposals } = await wormholeGateway.getMultisigData();
+      const proposal = wormholeGateway.getProposalPDA(numProposals);
+      const createIx = await wormholeGateway.createProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        { takeAuthority: {} }
+      );
+      await expectIxSuccess([createIx], [signers[0]]);
+
+      const approveIx = await wormholeGateway.approveProposalIx({
+        proposal,
+        signer: imposter.publicKey,
+      });
+      await expectIxFail([approveIx], [imposter], "IsNotMultisigSigner");
+    });
+
+    it("hand authority back", async () => {
+      const proposal = await proposeAndApprove({
+        changeAuthority: { newAuthority: authority.publicKey },
+      });
+      const executeIx = await wormholeGateway.executeProposalIx(
+        { proposal, proposer: signers[0].publicKey },
+        [
+          { pubkey: custodian, isSigner: false, isWritable: true },
+          { pubkey: authority.publicKey, isSigner: false, isWritable: false },
+        ]
+      );
+      await expectIxSuccess([executeIx], [txPayer]);
+
+      const takeIx = await wormholeGateway.takeAuthorityIx({
+        pendingAuthority: authority.publicKey,
+      });
+      await expectIxSuccess([takeIx], [authority]);
+
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: BigInt(42000),
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("timelock", () => {
+    const delay = 2;
+
+    async function schedule(action: any) {
+      const { numScheduledChanges } = await wormholeGateway.getCustodianData();
+      const scheduledChange =
+        wormholeGateway.getScheduledChangePDA(numScheduledChanges);
+      const ix = await wormholeGateway.scheduleChangeIx(
+        { scheduledChange, authority: authority.publicKey },
+        action
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      return scheduledChange;
+    }
+
+    it("set up timelock", async () => {
+      const addGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([addGuardianIx], [authority]);
+
+      const ix = await wormholeGateway.updateTimelockDelayIx(
+        { authority: authority.publicKey },
+        delay
+      );
+      await expectIxSuccess([ix], [authority]);
+    });
+
+    it("cannot update minting limit directly", async () => {
+      const ix = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(1)
+      );
+      await expectIxFail([ix], [authority], "TimelockRequired");
+    });
+
+    it("cannot update gateway address directly", async () => {
+      const ix = await wormholeGateway.updateGatewayAddress(
+        {
+          authority: authority.publicKey,
+        },
+        { chain: 2, address: Array.from(Buffer.alloc(32)) }
+      );
+      await expectIxFail([ix], [authority], "TimelockRequired");
+    });
+
+    it("cannot add call target directly", async () => {
+      const ix = await wormholeGateway.addCallTargetIx({
+        authority: authority.publicKey,
+        program: TBTC_PROGRAM_ID,
+      });
+      await expectIxFail([ix], [authority], "TimelockRequired");
+    });
+
+    it("tbtc guardian cancels scheduled change", async () => {
+      const scheduledChange = await schedule({
+        updateGatewayAddress: {
+          chain: 2,
+          address: Array.from(Buffer.alloc(32)),
+        },
+      });
+
+      const ix = await wormholeGateway.cancelScheduledChangeIx({
+        guardian: guardianKeys.publicKey,
+        scheduledChange,
+        scheduledBy: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [txPayer, guardianKeys]);
+
+      const scheduledChangeInfo = await connection.getAccountInfo(
+        scheduledChange
+      );
+      expect(scheduledChangeInfo).to.be.null;
+    });
+
+    it("update minting limit after delay", async () => {
+      const newLimit = BigInt(50000);
+      const scheduledChange = await schedule({
+        updateMintingLimit: { newLimit: new anchor.BN(newLimit.toString()) },
+      });
+      const remainingAccounts = [
+        { pubkey: custodian, isSigner: false, isWritable: true },
+      ];
+
+      const earlyIx = await wormholeGateway.executeScheduledChangeIx(
+        { scheduledChange, scheduledBy: authority.publicKey },
+        remainingAccounts
+      );
+      await expectIxFail([earlyIx], [txPayer], "ScheduledChangeNotReady");
+
+      await sleep((delay + 1) * 1000);
+
+      const ix = await wormholeGateway.executeScheduledChangeIx(
+        { scheduledChange, scheduledBy: authority.publicKey },
+        remainingAccounts
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: newLimit,
+        pendingAuthority: null,
+      });
+    });
+
+    it("remove timelock delay after delay", async () => {
+      const scheduledChange = await schedule({
+        updateTimelockDelay: { delay: 0 },
+      });
+
+      await sleep((delay + 1) * 1000);
+
+      const ix = await wormholeGateway.executeScheduledChangeIx(
+        { scheduledChange, scheduledBy: authority.publicKey },
+        [{ pubkey: custodian, isSigner: false, isWritable: true }]
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      const { timelockDelay } = await wormholeGateway.getCustodianData();
+      expect(timelockDelay).to.equal(0);
+
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([removeGuardianIx], [authority]);
+    });
+  });
+
+  describe("reconciliation", () => {
+    it("minted amount matches tbtc outstanding", async () => {
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const minterInfo = await tbtc.getMinterInfo(custodian);
+      expect(
+        minterInfo.mintedTotal.sub(minterInfo.burnedTotal).toString()
+      ).to.equal(mintedAmount.toString());
+
+      const ix = await wormholeGateway.reconcileIx();
+      await expectIxSuccess([ix], [txPayer]);
+    });
+
+    it("tbtc and gateway reconcile together", async () => {
+      // The tbtc reconcile ties the mint's supply to the totals over all
+      // minters, and the gateway reconcile ties the custodian's minted amount
+      // to its own minter's totals. Together they check the whole invariant.
+      const tbtcIx = await tbtc.reconcileIx();
+      const gatewayIx = await wormholeGateway.reconcileIx();
+      const txSig = await expectIxSuccess([tbtcIx, gatewayIx], [txPayer]);
+
+      const [config, minterInfo, mint, mintedAmount] = await Promise.all([
+        tbtc.getConfigData(),
+        tbtc.getMinterInfo(custodian),
+        getMint(connection, tbtcMint),
+        wormholeGateway.getMintedAmount(),
+      ]);
+      const expectedSupply = config.mintedTotal.sub(config.burnedTotal);
+      const outstanding = minterInfo.mintedTotal.sub(minterInfo.burnedTotal);
+
+      const [supplyReconciled] = await tbtc.getCpiEvents(txSig);
+      expect(supplyReconciled.name).to.equal("SupplyReconciled");
+      expect(supplyReconciled.data.supply.toString()).to.equal(
+        mint.supply.toString()
+      );
+      expect(supplyReconciled.data.mintedTotal.toString()).to.equal(
+        config.mintedTotal.toString()
+      );
+      expect(supplyReconciled.data.burnedTotal.toString()).to.equal(
+        config.burnedTotal.toString()
+      );
+      expect(supplyReconciled.data.discrepancy).to.equal(
+        expectedSupply.toString() !== mint.supply.toString()
+      );
+
+      const tx = await connection.getTransaction(txSig, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, program.coder);
+      const [mintedAmountReconciled] = Array.from(
+        parser.parseLogs(tx.meta.logMessages)
+      );
+      expect(mintedAmountReconciled.name).to.equal("MintedAmountReconciled");
+      expect(mintedAmountReconciled.data.mintedAmount.toString()).to.equal(
+        mintedAmount.toString()
+      );
+      expect(mintedAmountReconciled.data.tbtcOutstanding.toString()).to.equal(
+        outstanding.toString()
+      );
+      expect(mintedAmountReconciled.data.discrepancy).to.be.false;
+
+      // The gateway's outstanding tBTC is part of the expected supply.
+      expect(expectedSupply.gte(outstanding)).to.be.true;
+    });
+
+    it("cannot reconcile with another guardian's info", async () => {
+      const addGuardianIx = await tbtc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([addGuardianIx], [authority]);
+
+      const ix = await wormholeGateway.reconcileIx({
+        tbtcGuardianInfo: tbtc.getGuardianInfoPDA(guardianKeys.publicKey),
+        tbtcGuardian: imposter.publicKey,
+      });
+      await expectIxFail([ix], [txPayer, imposter], "GuardianInfoMismatch");
+    });
+
+    it("guardian does not pause without a discrepancy", async () => {
+      const ix = await wormholeGateway.reconcileIx({
+        tbtcGuardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([ix], [txPayer, guardianKeys]);
+
+      const { paused } = await tbtc.getConfigData();
+      expect(paused).to.be.false;
+
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([removeGuardianIx], [authority]);
+    });
+  });
+
+  describe("tbtc token", () => {
+    it("cannot update tbtc token as imposter", async () => {
+      const ix = await wormholeGateway.updateTbtcTokenIx(
+        { authority: imposter.publicKey },
+        { chain: 2, address: ETHEREUM_TBTC_TOKEN }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot update tbtc token to an unattested token", async () => {
+      const ix = await wormholeGateway.updateTbtcTokenIx(
+        { authority: authority.publicKey },
+        { chain: 2, address: Array.from(Buffer.alloc(32, 1)) }
+      );
+      await expectIxFail([ix], [authority], "AccountNotInitialized");
+    });
+
+    it("cannot update tbtc token with wrapped tbtc in custody", async () => {
+      const payer = await generatePayer(authority);
+      const payerWrappedToken = await preloadWrappedTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(100),
+        payer.publicKey
+      );
+      const transferIx = createTransferInstruction(
+        payerWrappedToken,
+        gatewayWrappedTbtcToken,
+        payer.publicKey,
+        100
+      );
+      await expectIxSuccess([transferIx], [payer]);
+
+      const ix = await wormholeGateway.updateTbtcTokenIx(
+        { authority: authority.publicKey },
+        { chain: 2, address: ETHEREUM_TBTC_TOKEN }
+      );
+      await expectIxFail([ix], [authority], "WrappedTbtcInCustody");
+
+      await wormholeGateway.checkTbtcToken({
+        chain: 2,
+        address: ETHEREUM_TBTC_TOKEN,
+        wrappedTbtcMint: WRAPPED_TBTC_MINT,
+        wrappedTbtcToken: gatewayWrappedTbtcToken,
+      });
+    });
+  });
+
+  describe("inbound limit", () => {
+    const inboundLimit = BigInt(1000);
+
+    async function now() {
+      const slot = await connection.getSlot();
+      return connection.getBlockTime(slot);
+    }
+
+    it("cannot update inbound limit (not authority)", async () => {
+      const ix = await wormholeGateway.updateInboundLimitIx(
+        { authority: imposter.publicKey },
+        { amount: inboundLimit, period: 3600 }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("update inbound limit", async () => {
+      // Make sure only the inbound limit constrains minting.
+      const mintingLimitIx = await wormholeGateway.updateMintingLimitIx(
+        { authority: authority.publicKey },
+        BigInt("18446744073709551615")
+      );
+      const ix = await wormholeGateway.updateInboundLimitIx(
+        { authority: authority.publicKey },
+        { amount: inboundLimit, period: 3600 }
+      );
+      await expectIxSuccess([mintingLimitIx, ix], [authority]);
+
+      const remaining = await wormholeGateway.getRemainingInboundCapacity(
+        await now()
+      );
+      expect(remaining).to.equal(inboundLimit);
+    });
+
+    it("receive wrapped tbtc (inbound limit exceeded)", async () => {
+      const payer = await generatePayer(authority);
+
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      const recipientWrappedToken = await getOrCreateAta(
+        payer,
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      const sentAmount = inboundLimit + BigInt(1);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const [tbtcBefore, wrappedTbtcBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tbtcAfter, wrappedTbtcAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      // Wrapped tBTC is delivered instead, and nothing is counted.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
+      expect(wrappedTbtcAfter.amount).to.equal(
+        wrappedTbtcBefore.amount + sentAmount
+      );
+      expect(await wormholeGateway.getMintedAmount()).to.equal(
+        mintedAmountBefore
+      );
+
+      const remaining = await wormholeGateway.getRemainingInboundCapacity(
+        await now()
+      );
+      expect(remaining).to.equal(inboundLimit);
+    });
+
+    it("cannot deposit wrapped tbtc (inbound limit exceeded)", async () => {
+      const payer = await generatePayer(authority);
+      const recipientWrappedToken = await preloadWrappedTbtc(
+        payer,
+        ethereumTokenBridge,
+        inboundLimit + BigInt(1),
+        payer.publicKey
+      );
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtcMint,
+        payer.publicKey
+      );
+
+      const accounts = {
+        recipientWrappedToken,
+        recipientToken,
+        recipient: payer.publicKey,
+      };
+      const failingIx = await wormholeGateway.depositWormholeTbtcIx(
+        accounts,
+        inboundLimit + BigInt(1)
+      );
+      await expectIxFail([failingIx], [payer], "InboundLimitExceeded");
+
+      // The whole limit can still be used.
+      const ix = await wormholeGateway.depositWormholeTbtcIx(
+        accounts,
+        inboundLimit
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // The whole limit is counted as of the deposit, and decays from there.
+      const { inboundAmount, inboundUpdatedAt } =
+        await wormholeGateway.getCustodianData();
+      expect(inboundAmount.toString()).to.equal(inboundLimit.toString());
+
+      const depositedAt = inboundUpdatedAt.toNumber();
+      const capacityAt = (elapsed: number) =>
+        wormholeGateway.getRemainingInboundCapacity(depositedAt + elapsed);
+      expect(await capacityAt(0)).to.equal(BigInt(0));
+      expect(await capacityAt(36)).to.equal(BigInt(10));
+      expect(await capacityAt(3600)).to.equal(inboundLimit);
+    });
+
+    it("disable inbound limit", async () => {
+      const ix = await wormholeGateway.updateInboundLimitIx(
+        { authority: authority.publicKey },
+        { amount: BigInt(0), period: 0 }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const remaining = await wormholeGateway.getRemainingInboundCapacity(
+        await now()
+      );
+      expect(remaining).to.equal(BigInt("18446744073709551615"));
+    });
+  });
+
+  describe("outbound limits", () => {
+    const chain = 2;
+    const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+    const sender = commonTokenOwner.publicKey;
+    const senderToken = getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      sender
+    );
+
+    function sendGatewayIx(amount: bigint) {
+      return wormholeGateway.sendTbtcGatewayIx(
+        { senderToken, sender },
+        {
+          amount: new anchor.BN(amount.toString()),
+          recipientChain: chain,
+          recipient,
+          nonce: 0,
+        }
+      );
+    }
+
+    function updateLimitsIx(limits: {
+      outboundLimitAmount: bigint;
+      outboundLimitPeriod: number;
+      minTransferAmount: bigint;
+      maxTransferAmount: bigint;
+    }) {
+      return wormholeGateway.updateGatewayLimitsIx(
+        { authority: authority.publicKey },
+        { chain, ...limits }
+      );
+    }
+
+    it("cannot update gateway limits (not authority)", async () => {
+      const ix = await wormholeGateway.updateGatewayLimitsIx(
+        { authority: imposter.publicKey },
+        {
+          chain,
+          outboundLimitAmount: BigInt(0),
+          outboundLimitPeriod: 0,
+          minTransferAmount: BigInt(0),
+          maxTransferAmount: BigInt(0),
+        }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot update gateway limits (minimum above maximum)", async () => {
+      const ix = await updateLimitsIx({
+        outboundLimitAmount: BigInt(600),
+        outboundLimitPeriod: 3600,
+        minTransferAmount: BigInt(501),
+        maxTransferAmount: BigInt(500),
+      });
+      await expectIxFail([ix], [authority], "InvalidTransferBounds");
+    });
+
+    it("update gateway limits", async () => {
+      const ix = await updateLimitsIx({
+        outboundLimitAmount: BigInt(600),
+        outboundLimitPeriod: 3600,
+        minTransferAmount: BigInt(100),
+        maxTransferAmount: BigInt(500),
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const gatewayInfo = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfo.outboundLimitAmount.toString()).to.equal("600");
+      expect(gatewayInfo.outboundLimitPeriod).to.equal(3600);
+      expect(gatewayInfo.minTransferAmount.toString()).to.equal("100");
+      expect(gatewayInfo.maxTransferAmount.toString()).to.equal("500");
+    });
+
+    it("cannot send tbtc to gateway (below minimum)", async () => {
+      const ix = await sendGatewayIx(BigInt(99));
+      await expectIxFail([ix], [commonTokenOwner], "TransferAmountTooSmall");
+    });
+
+    it("cannot send tbtc to gateway (above maximum)", async () => {
+      const ix = await sendGatewayIx(BigInt(501));
+      await expectIxFail([ix], [commonTokenOwner], "TransferAmountTooLarge");
+    });
+
+    it("cannot send wrapped tbtc (outbound limit exceeded)", async () => {
+      const ix = await sendGatewayIx(BigInt(500));
+      await expectIxSuccess([ix], [commonTokenOwner]);
+
+      // Wrapped transfers to the chain count against the same limit.
+      const wrappedIx = await wormholeGateway.sendTbtcWrappedIx(
+        { senderToken, sender },
+        {
+          amount: new anchor.BN(200),
+          recipientChain: chain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce: 0,
+        }
+      );
+      await expectIxFail(
+        [wrappedIx],
+        [commonTokenOwner],
+        "OutboundLimitExceeded"
+      );
+
+      const { outboundAmount } = await wormholeGateway.getGatewayInfo(chain);
+      expect(outboundAmount.toString()).to.equal("500");
+    });
+
+    it("remove gateway limits", async () => {
+      const ix = await updateLimitsIx({
+        outboundLimitAmount: BigInt(0),
+        outboundLimitPeriod: 0,
+        minTransferAmount: BigInt(0),
+        maxTransferAmount: BigInt(0),
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const sendIx = await sendGatewayIx(BigInt(501));
+      await expectIxSuccess([sendIx], [commonTokenOwner]);
+    });
+  });
+
+  describe("disable gateway", () => {
+    const chain = 70;
+    const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+    const sender = commonTokenOwner.publicKey;
+    const senderToken = getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      sender
+    );
+
+    it("set up gateway", async () => {
+      const ix = await wormholeGateway.updateGatewayAddress(
+        { authority: authority.publicKey },
+        { chain, address: Array.from(Buffer.alloc(32, "beef", "hex")) }
+      );
+      await expectIxSuccess([ix], [authority]);
+    });
+
+    it("cannot disable gateway (not authority)", async () => {
+      const ix = await wormholeGateway.disableGatewayIx(
+        { authority: imposter.publicKey },
+        chain
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("disable gateway", async () => {
+      const limitsIx = await wormholeGateway.updateGatewayLimitsIx(
+        { authority: authority.publicKey },
+        {
+          chain,
+          outboundLimitAmount: BigInt(0),
+          outboundLimitPeriod: 0,
+          minTransferAmount: BigInt(0),
+          maxTransferAmount: BigInt(50),
+        }
+      );
+      await expectIxSuccess([limitsIx], [authority]);
+
+      const ix = await wormholeGateway.disableGatewayIx(
+        { authority: authority.publicKey },
+        chain
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const { disabled } = await wormholeGateway.getGatewayInfo(chain);
+      expect(disabled).to.be.true;
+    });
+
+    it("cannot send tbtc to gateway (gateway disabled)", async () => {
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        { senderToken, sender },
+        {
+          amount: new anchor.BN(100),
+          recipientChain: chain,
+          recipient,
+          nonce: 0,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "GatewayDisabled");
+    });
+
+    it("cannot send wrapped tbtc (disabled gateway limits)", async () => {
+      // The disabled chain's limits still apply to wrapped transfers.
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        { senderToken, sender },
+        {
+          amount: new anchor.BN(51),
+          recipientChain: chain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce: 0,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "TransferAmountTooLarge");
+    });
+
+    it("enable gateway again", async () => {
+      const ix = await wormholeGateway.updateGatewayAddress(
+        { authority: authority.publicKey },
+        { chain, address: Array.from(Buffer.alloc(32, "beef", "hex")) }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const { disabled, maxTransferAmount } =
+        await wormholeGateway.getGatewayInfo(chain);
+      expect(disabled).to.be.false;
+      expect(maxTransferAmount.toString()).to.equal("50");
+    });
+  });
+
+  describe("gateway mode", () => {
+    const gatewayGuardianKeys = anchor.web3.Keypair.generate();
+    const gatewayGuardian = wormholeGateway.getGatewayGuardianPDA(
+      gatewayGuardianKeys.publicKey
+    );
+    const sender = commonTokenOwner.publicKey;
+    const senderToken = getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      sender
+    );
+
+    function guardianSetModeIx(mode: any) {
+      return wormholeGateway.setGatewayModeIx(
+        { signer: gatewayGuardianKeys.publicKey, gatewayGuardian },
+        mode
+      );
+    }
+
+    it("cannot add gateway guardian (not authority)", async () => {
+      const ix = await wormholeGateway.addGatewayGuardianIx({
+        authority: imposter.publicKey,
+        guardian: gatewayGuardianKeys.publicKey,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("add gateway guardian", async () => {
+      const ix = await wormholeGateway.addGatewayGuardianIx({
+        authority: authority.publicKey,
+        guardian: gatewayGuardianKeys.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+    });
+
+    it("cannot set mode (not guardian)", async () => {
+      const ix = await wormholeGateway.setGatewayModeIx(
+        { signer: imposter.publicKey },
+        { paused: {} }
+      );
+      await expectIxFail([ix], [imposter], "IsNotGatewayGuardian");
+    });
+
+    it("guardian disables outbound transfers", async () => {
+      const ix = await guardianSetModeIx({ inboundOnly: {} });
+      await expectIxSuccess([ix], [txPayer, gatewayGuardianKeys]);
+
+      const { mode } = await wormholeGateway.getCustodianData();
+      expect(mode).to.eql({ inboundOnly: {} });
+
+      const sendIx = await wormholeGateway.sendTbtcWrappedIx(
+        { senderToken, sender },
+        {
+          amount: new anchor.BN(100),
+          recipientChain: 69,
+          recipient: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+          arbiterFee: new anchor.BN(0),
+          nonce: 0,
+        }
+      );
+      await expectIxFail([sendIx], [commonTokenOwner], "OutboundDisabled");
+    });
+
+    it("guardian cannot relax mode", async () => {
+      const ix = await guardianSetModeIx({ outboundOnly: {} });
+      await expectIxFail(
+        [ix],
+        [txPayer, gatewayGuardianKeys],
+        "GatewayModeNotRestricted"
+      );
+    });
+
+    it("guardian pauses gateway", async () => {
+      const ix = await guardianSetModeIx({ paused: {} });
+      await expectIxSuccess([ix], [txPayer, gatewayGuardianKeys]);
+
+      const payer = await generatePayer(authority);
+      const recipientWrappedToken = await preloadWrappedTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(100),
+        payer.publicKey
+      );
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtcMint,
+        payer.publicKey
+      );
+      const depositIx = await wormholeGateway.depositWormholeTbtcIx(
+        {
+          recipientWrappedToken,
+          recipientToken,
+          recipient: payer.publicKey,
+        },
+        BigInt(100)
+      );
+      await expectIxFail([depositIx], [payer], "InboundDisabled");
+    });
+
+    it("authority resumes gateway", async () => {
+      const ix = await wormholeGateway.setGatewayModeIx(
+        { signer: authority.publicKey },
+        { active: {} }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const { mode } = await wormholeGateway.getCustodianData();
+      expect(mode).to.eql({ active: {} });
+    });
+
+    it("remove gateway guardian", async () => {
+      const ix = await wormholeGateway.removeGatewayGuardianIx({
+        authority: authority.publicKey,
+        guardian: gatewayGuardianKeys.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      expect(await connection.getAccountInfo(gatewayGuardian)).to.be.null;
+    });
+  });
+
+  describe("fees", () => {
+    const chain = 2;
+    const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+    const sender = commonTokenOwner.publicKey;
+    const senderToken = getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      sender
+    );
+    const treasuryToken = wormholeGateway.getTreasuryTokenPDA();
+
+    function updateFeesIx(fees: {
+      inboundFeeBps: number;
+      inboundMinFee: bigint;
+      outboundFeeBps: number;
+      outboundMinFee: bigint;
+    }) {
+      return wormholeGateway.updateGatewayFeesIx(
+        { authority: authority.publicKey },
+        { chain, ...fees }
+      );
+    }
+
+    function sendGatewayIx(amount: bigint) {
+      return wormholeGateway.sendTbtcGatewayIx(
+        { senderToken, sender },
+        {
+          amount: new anchor.BN(amount.toString()),
+          recipientChain: chain,
+          recipient,
+          nonce: 0,
+        }
+      );
+    }
+
+    it("cannot initialize treasury (not authority)", async () => {
+      const ix = await wormholeGateway.initializeTreasuryIx({
+        authority: imposter.publicKey,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot recover tokens from treasury", async () => {
+      const ix = await wormholeGateway.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: treasuryToken,
+          recipientToken: senderToken,
+        },
+        BigInt(0)
+      );
+      await expectIxFail([ix], [authority], "TokenAccountNotRecoverable");
+    });
+
+    it("cannot update gateway fees (invalid fee bps)", async () => {
+      const ix = await updateFeesIx({
+        inboundFeeBps: 10001,
+        inboundMinFee: BigInt(0),
+        outboundFeeBps: 0,
+        outboundMinFee: BigInt(0),
+      });
+      await expectIxFail([ix], [authority], "InvalidFeeBps");
+    });
+
+    it("update gateway fees", async () => {
+      const ix = await updateFeesIx({
+        inboundFeeBps: 50,
+        inboundMinFee: BigInt(5),
+        outboundFeeBps: 100,
+        outboundMinFee: BigInt(5),
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const gatewayInfo = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfo.inboundFeeBps).to.equal(50);
+      expect(gatewayInfo.inboundMinFee.toString()).to.equal("5");
+      expect(gatewayInfo.outboundFeeBps).to.equal(100);
+      expect(gatewayInfo.outboundMinFee.toString()).to.equal("5");
+    });
+
+    it("send tbtc to gateway (outbound fee)", async () => {
+      const [senderBefore, treasuryBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, treasuryToken),
+      ]);
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // 1% of 1000 is above the minimum fee.
+      const ix = await sendGatewayIx(BigInt(1000));
+      await expectIxSuccess([ix], [commonTokenOwner]);
+
+      const [senderAfter, treasuryAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, treasuryToken),
+      ]);
+
+      // Only the amount net of the fee is burned and bridged.
+      expect(senderAfter.amount).to.equal(senderBefore.amount - BigInt(1000));
+      expect(treasuryAfter.amount).to.equal(treasuryBefore.amount + BigInt(10));
+      expect(await wormholeGateway.getMintedAmount()).to.equal(
+        mintedAmountBefore - BigInt(990)
+      );
+    });
+
+    it("cannot send tbtc to gateway (amount does not cover fee)", async () => {
+      const ix = await sendGatewayIx(BigInt(5));
+      await expectIxFail([ix], [commonTokenOwner], "AmountDoesNotCoverFee");
+    });
+
+    it("receive tbtc (inbound fee)", async () => {
+      const payer = await generatePayer(authority);
+
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(chain)
+        .then((info) => info.address);
+
+      // 0.5% of 200 is below the minimum fee.
+      const sentAmount = BigInt(200);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const [tbtcBefore, treasuryBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, treasuryToken),
+      ]);
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tbtcAfter, treasuryAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, treasuryToken),
+      ]);
+
+      // The whole amount is minted, with the fee going to the treasury.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + BigInt(195));
+      expect(treasuryAfter.amount).to.equal(treasuryBefore.amount + BigInt(5));
+      expect(await wormholeGateway.getMintedAmount()).to.equal(
+        mintedAmountBefore + sentAmount
+      );
+    });
+
+    it("receive wrapped tbtc (amount does not cover inbound fee)", async () => {
+      const payer = await generatePayer(authority);
+
+      const recipient = commonTokenOwner.publicKey;
+      const recipientWrappedToken = await getOrCreateAta(
+        payer,
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(chain)
+        .then((info) => info.address);
+
+      // The minimum fee would take the whole amount.
+      const sentAmount = BigInt(5);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const [wrappedTbtcBefore, treasuryBefore] = await Promise.all([
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, treasuryToken),
+      ]);
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken: getAssociatedTokenAddressSync(
+            tbtc.getMintPDA(),
+            recipient
+          ),
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [wrappedTbtcAfter, treasuryAfter] = await Promise.all([
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, treasuryToken),
+      ]);
+
+      // Wrapped tBTC is delivered instead, without a fee.
+      expect(wrappedTbtcAfter.amount).to.equal(
+        wrappedTbtcBefore.amount + sentAmount
+      );
+      expect(treasuryAfter.amount).to.equal(treasuryBefore.amount);
+      expect(await wormholeGateway.getMintedAmount()).to.equal(
+        mintedAmountBefore
+      );
+    });
+
+    it("cannot withdraw fees (not authority)", async () => {
+      const ix = await wormholeGateway.withdrawFeesIx(
+        { authority: imposter.publicKey, recipientToken: senderToken },
+        BigInt(1)
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("withdraw fees", async () => {
+      const [senderBefore, treasuryBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, treasuryToken),
+      ]);
+
+      const ix = await wormholeGateway.withdrawFeesIx(
+        { authority: authority.publicKey, recipientToken: senderToken },
+        treasuryBefore.amount
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const [senderAfter, treasuryAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, treasuryToken),
+      ]);
+      expect(treasuryAfter.amount).to.equal(BigInt(0));
+      expect(senderAfter.amount).to.equal(
+        senderBefore.amount + treasuryBefore.amount
+      );
+    });
+
+    it("remove gateway fees", async () => {
+      const ix = await updateFeesIx({
+        inboundFeeBps: 0,
+        inboundMinFee: BigInt(0),
+        outboundFeeBps: 0,
+        outboundMinFee: BigInt(0),
+      });
+      await expectIxSuccess([ix], [authority]);
+    });
+  });
+
+  describe("relayer fee", () => {
+    const recipient = commonTokenOwner.publicKey;
+    const recipientToken = getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      recipient
+    );
+
+    async function sendFromEthereum(payer, message: Buffer) {
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      return ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(300),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        message
+      );
+    }
+
+    it("receive tbtc (relayer fee)", async () => {
+      const relayer = await generatePayer(authority);
+      const relayerToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        relayer.publicKey
+      );
+
+      const signedVaa = await sendFromEthereum(
+        relayer,
+        wormholeGateway.encodeGatewayMessage(recipient, BigInt(20))
+      );
+
+      const tbtcBefore = await getAccount(connection, recipientToken);
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: relayer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [relayer]);
+
+      // The relayer's token account is created for the fee.
+      const [tbtcAfter, relayerTokenAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, relayerToken),
+      ]);
+      expect(relayerTokenAfter.amount).to.equal(BigInt(20));
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + BigInt(280));
+      expect(await wormholeGateway.getMintedAmount()).to.equal(
+        mintedAmountBefore + BigInt(300)
+      );
+    });
+
+    it("receive tbtc (relayer fee exceeds amount)", async () => {
+      const relayer = await generatePayer(authority);
+      const relayerToken = await getOrCreateAta(
+        relayer,
+        tbtc.getMintPDA(),
+        relayer.publicKey
+      );
+
+      const signedVaa = await sendFromEthereum(
+        relayer,
+        wormholeGateway.encodeGatewayMessage(recipient, BigInt(1000))
+      );
+
+      const tbtcBefore = await getAccount(connection, recipientToken);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: relayer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [relayer]);
+
+      // The relayer fee is capped at the amount.
+      const [tbtcAfter, relayerTokenAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, relayerToken),
+      ]);
+      expect(relayerTokenAfter.amount).to.equal(BigInt(300));
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
+    });
+
+    it("cannot receive tbtc (invalid gateway message)", async () => {
+      const payer = await generatePayer(authority);
+
+      // Version 3 is unknown.
+      const message = wormholeGateway.encodeGatewayMessage(
+        recipient,
+        BigInt(20)
+      );
+      message[0] = 3;
+      const signedVaa = await sendFromEthereum(payer, message);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "InvalidGatewayMessage");
+    });
+  });
+
+  describe("receive and call", () => {
+    const recipient = commonTokenOwner.publicKey;
+    const recipientToken = getAssociatedTokenAddressSync(
+      tbtc.getMintPDA(),
+      recipient
+    );
+
+    // The tBTC program stands in for a target program. Calling it without
+    // instruction data always fails.
+    const targetProgram = TBTC_PROGRAM_ID;
+
+    let failedCallVaa: Buffer;
+
+    async function sendFromEthereum(
+      payer,
+      message: Buffer,
+      timestamp?: number
+    ) {
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      return ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(300),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        message,
+        undefined,
+        undefined,
+        timestamp
+      );
+    }
+
+    it("cannot add call target (not authority)", async () => {
+      const ix = await wormholeGateway.addCallTargetIx({
+        authority: imposter.publicKey,
+        program: targetProgram,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("add call target", async () => {
+      const ix = await wormholeGateway.addCallTargetIx({
+        authority: authority.publicKey,
+        program: targetProgram,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const callTarget = await program.account.callTarget.fetch(
+        wormholeGateway.getCallTargetPDA(targetProgram)
+      );
+      expect(callTarget.program).to.eql(targetProgram);
+    });
+
+    it("cannot receive tbtc (call target missing)", async () => {
+      const payer = await generatePayer(authority);
+
+      const signedVaa = await sendFromEthereum(
+        payer,
+        wormholeGateway.encodeGatewayMessage(recipient, BigInt(0), {
+          program: targetProgram,
+          data: Buffer.alloc(0),
+        })
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "CallAccountsMismatch");
+    });
+
+    it("receive tbtc (call target not allowed)", async () => {
+      const payer = await generatePayer(authority);
+      const notAllowed = anchor.web3.Keypair.generate().publicKey;
+
+      const signedVaa = await sendFromEthereum(
+        payer,
+        wormholeGateway.encodeGatewayMessage(recipient, BigInt(0), {
+          program: notAllowed,
+          data: Buffer.alloc(0),
+        })
+      );
+
+      const tbtcBefore = await getAccount(connection, recipientToken);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram: notAllowed,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // The program is not called, so the recipient gets the tBTC.
+      const tbtcAfter = await getAccount(connection, recipientToken);
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + BigInt(300));
+    });
+
+    it("cannot receive tbtc (call fails)", async () => {
+      const payer = await generatePayer(authority);
+
+      failedCallVaa = await sendFromEthereum(
+        payer,
+        wormholeGateway.encodeGatewayMessage(recipient, BigInt(0), {
+          program: targetProgram,
+          data: Buffer.alloc(0),
+        })
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram,
+        },
+        failedCallVaa
+      );
+      await expectIxFail([ix], [payer], "InstructionMissing");
+    });
+
+    it("receive tbtc (recipient skips call)", async () => {
+      const payer = await generatePayer(authority);
+
+      const tbtcBefore = await getAccount(connection, recipientToken);
+
+      // The recipient signs to redeem the transfer without the call.
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram,
+        },
+        failedCallVaa
+      );
+      ix.keys
+        .filter((meta) => meta.pubkey.equals(recipient))
+        .forEach((meta) => (meta.isSigner = true));
+      await expectIxSuccess([ix], [payer, commonTokenOwner]);
+
+      const tbtcAfter = await getAccount(connection, recipientToken);
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + BigInt(300));
+    });
+
+    it("receive tbtc (call timed out)", async () => {
+      const payer = await generatePayer(authority);
+
+      // Sent more than a day ago, so anyone can redeem it without the call.
+      const signedVaa = await sendFromEthereum(
+        payer,
+        wormholeGateway.encodeGatewayMessage(recipient, BigInt(0), {
+          program: targetProgram,
+          data: Buffer.alloc(0),
+        }),
+        Math.floor(Date.now() / 1000) - 2 * 24 * 60 * 60
+      );
+
+      const tbtcBefore = await getAccount(connection, recipientToken);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const tbtcAfter = await getAccount(connection, recipientToken);
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + BigInt(300));
+    });
+
+    describe("mock call target", () => {
+      const mockCallTarget = anchor.workspace
+        .MockCallTarget as Program<MockCallTarget>;
+      const callEscrow = wormholeGateway.getCallEscrowPDA();
+
+      function spendCall(amount: bigint, destinationToken: PublicKey) {
+        const accounts: AccountMeta[] = [
+          { pubkey: destinationToken, isSigner: false, isWritable: true },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ];
+        const data = mockCallTarget.coder.instruction.encode("spend", {
+          amount: new anchor.BN(amount.toString()),
+        });
+        return {
+          accounts,
+          message: wormholeGateway.encodeGatewayMessage(recipient, BigInt(0), {
+            program: MOCK_CALL_TARGET_PROGRAM_ID,
+            accounts,
+            data,
+          }),
+        };
+      }
+
+      async function receiveAndCall(
+        spendAmount: bigint,
+        destinationToken: PublicKey
+      ) {
+        const payer = await generatePayer(authority);
+        const { accounts, message } = spendCall(spendAmount, destinationToken);
+        const signedVaa = await sendFromEthereum(payer, message);
+
+        const ix = await wormholeGateway.receiveTbtcIx(
+          {
+            payer: payer.publicKey,
+            recipientToken,
+            recipient,
+            targetProgram: MOCK_CALL_TARGET_PROGRAM_ID,
+          },
+          signedVaa,
+          accounts
+        );
+        await expectIxSuccess([ix], [payer]);
+      }
+
+      let destinationToken: PublicKey;
+
+      it("add mock call target", async () => {
+        const ix = await wormholeGateway.addCallTargetIx({
+          authority: authority.publicKey,
+          program: MOCK_CALL_TARGET_PROGRAM_ID,
+        });
+        await expectIxSuccess([ix], [authority]);
+
+        destinationToken = await getOrCreateAta(
+          authority,
+          tbtc.getMintPDA(),
+          anchor.web3.Keypair.generate().publicKey
+        );
+      });
+
+      it("receive tbtc (call spends everything)", async () => {
+        const [tbtcBefore, destinationBefore] = await Promise.all([
+          getAccount(connection, recipientToken),
+          getAccount(connection, destinationToken),
+        ]);
+
+        await receiveAndCall(BigInt(300), destinationToken);
+
+        const [tbtcAfter, destinationAfter, escrowAfter] = await Promise.all([
+          getAccount(connection, recipientToken),
+          getAccount(connection, destinationToken),
+          getAccount(connection, callEscrow),
+        ]);
+        expect(destinationAfter.amount).to.equal(
+          destinationBefore.amount + BigInt(300)
+        );
+        expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
+        expect(escrowAfter.amount).to.equal(BigInt(0));
+      });
+
+      it("receive tbtc (call spends part)", async () => {
+        const [tbtcBefore, destinationBefore] = await Promise.all([
+          getAccount(connection, recipientToken),
+          getAccount(connection, destinationToken),
+        ]);
+
+        await receiveAndCall(BigInt(100), destinationToken);
+
+        // What the program leaves in the escrow goes to the recipient.
+        const [tbtcAfter, destinationAfter, escrowAfter] = await Promise.all([
+          getAccount(connection, recipientToken),
+          getAccount(connection, destinationToken),
+          getAccount(connection, callEscrow),
+        ]);
+        expect(destinationAfter.amount).to.equal(
+          destinationBefore.amount + BigInt(100)
+        );
+        expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + BigInt(200));
+        expect(escrowAfter.amount).to.equal(BigInt(0));
+      });
+
+      it("cannot receive tbtc (call accounts mismatch)", async () => {
+        const payer = await generatePayer(authority);
+        const { message } = spendCall(BigInt(300), destinationToken);
+        const signedVaa = await sendFromEthereum(payer, message);
+
+        // Whoever redeems the transfer cannot send the tBTC elsewhere.
+        const otherToken = await getOrCreateAta(
+          payer,
+          tbtc.getMintPDA(),
+          payer.publicKey
+        );
+        const { accounts } = spendCall(BigInt(300), otherToken);
+
+        const ix = await wormholeGateway.receiveTbtcIx(
+          {
+            payer: payer.publicKey,
+            recipientToken,
+            recipient,
+            targetProgram: MOCK_CALL_TARGET_PROGRAM_ID,
+          },
+          signedVaa,
+          accounts
+        );
+        await expectIxFail([ix], [payer], "CallAccountsMismatch");
+      });
+    });
+
+    it("cannot remove call target (not authority)", async () => {
+      const ix = await wormholeGateway.removeCallTargetIx({
+        authority: imposter.publicKey,
+        program: targetProgram,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("remove call target", async () => {
+      const ix = await wormholeGateway.removeCallTargetIx({
+        authority: authority.publicKey,
+        program: targetProgram,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const callTarget = await connection.getAccountInfo(
+        wormholeGateway.getCallTargetPDA(targetProgram)
+      );
+      expect(callTarget).to.be.null;
+    });
+  });
 });
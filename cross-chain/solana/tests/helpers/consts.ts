@@ -6,6 +6,9 @@ export const TBTC_PROGRAM_ID = new PublicKey(
 export const WORMHOLE_GATEWAY_PROGRAM_ID = new PublicKey(
   "8H9F5JGbEMyERycwaGuzLS5MQnV7dn2wm2h6egJ3Leiu"
 );
+export const MOCK_CALL_TARGET_PROGRAM_ID = new PublicKey(
+  "3MzZ8fm9CzoGwtaWx1Lm4ESXATzvTz8mCycsknRfHaUB"
+);
 
 export const CORE_BRIDGE_PROGRAM_ID = new PublicKey(
   "worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth"
@@ -1,6 +1,10 @@
-import { BN, Program, Wallet, workspace } from "@coral-xyz/anchor";
-import { getMint } from "@solana/spl-token";
-import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { BN, Program, Wallet, utils, workspace } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, getMint } from "@solana/spl-token";
+import {
+  AccountMeta,
+  PublicKey,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import { config, expect } from "chai";
 import { Tbtc } from "../../target/types/tbtc";
 import { TBTC_PROGRAM_ID } from "./consts";
@@ -45,6 +49,14 @@ export function getGuardianInfoPDA(guardian: PublicKey): PublicKey {
   )[0];
 }
 
+export function getGuardiansPagePDA(index: number): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("guardians-page"), u32LeBytes(index)],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+// Legacy guardian registry, replaced by the guardians pages.
 export function getGuardiansPDA(): PublicKey {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("guardians")],
@@ -52,6 +64,21 @@ export function getGuardiansPDA(): PublicKey {
   )[0];
 }
 
+export function getEventAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export function getMintersPagePDA(index: number): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("minters-page"), u32LeBytes(index)],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+// Legacy minter registry, replaced by the minters pages.
 export function getMintersPDA(): PublicKey {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("minters")],
@@ -59,6 +86,147 @@ export function getMintersPDA(): PublicKey {
   )[0];
 }
 
+export function getMultisigPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("multisig")],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export function getMultisigAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("multisig-authority")],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export function getProposalPDA(index: BN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("proposal"), index.toArrayLike(Buffer, "le", 8)],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export function getTimelockAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("timelock-authority")],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export function getScheduledChangePDA(index: BN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("scheduled-change"), index.toArrayLike(Buffer, "le", 8)],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+function u32LeBytes(value: number): Buffer {
+  const buf = Buffer.alloc(4);
+  buf.writeUInt32LE(value);
+  return buf;
+}
+
+// Must match the page size of the minter and guardian registries.
+export const REGISTRY_PAGE_SIZE = 32;
+
+export async function getNextMintersPagePDA(): Promise<PublicKey> {
+  const { minterRegistryLen } = await getConfigData();
+  return getMintersPagePDA(
+    Math.floor(minterRegistryLen / REGISTRY_PAGE_SIZE)
+  );
+}
+
+export async function getNextGuardiansPagePDA(): Promise<PublicKey> {
+  const { guardianRegistryLen } = await getConfigData();
+  return getGuardiansPagePDA(
+    Math.floor(guardianRegistryLen / REGISTRY_PAGE_SIZE)
+  );
+}
+
+export async function getMinters(): Promise<PublicKey[]> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+  const { minterRegistryLen } = await getConfigData();
+
+  const minters: PublicKey[] = [];
+  const numPages = Math.ceil(minterRegistryLen / REGISTRY_PAGE_SIZE);
+  for (let index = 0; index < numPages; ++index) {
+    const page = await program.account.mintersPage.fetch(
+      getMintersPagePDA(index)
+    );
+    minters.push(...page.keys);
+  }
+
+  // Keys that have not been migrated yet are still in the legacy account.
+  const legacy = await program.account.minters.fetchNullable(getMintersPDA());
+  if (legacy !== null) {
+    minters.push(...legacy.keys);
+  }
+
+  return minters;
+}
+
+export async function getGuardians(): Promise<PublicKey[]> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+  const { guardianRegistryLen } = await getConfigData();
+
+  const guardians: PublicKey[] = [];
+  const numPages = Math.ceil(guardianRegistryLen / REGISTRY_PAGE_SIZE);
+  for (let index = 0; index < numPages; ++index) {
+    const page = await program.account.guardiansPage.fetch(
+      getGuardiansPagePDA(index)
+    );
+    guardians.push(...page.keys);
+  }
+
+  // Keys that have not been migrated yet are still in the legacy account.
+  const legacy = await program.account.guardians.fetchNullable(
+    getGuardiansPDA()
+  );
+  if (legacy !== null) {
+    guardians.push(...legacy.keys);
+  }
+
+  return guardians;
+}
+
+// Must match the order of the program's `Role` enum.
+export const ROLES = [
+  "minterAdmin",
+  "guardianAdmin",
+  "metadataAdmin",
+  "limitAdmin",
+  "pauseAdmin",
+] as const;
+
+export type Role = (typeof ROLES)[number];
+
+export function getRoleAssignmentPDA(
+  role: Role,
+  holder: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("role"),
+      Buffer.from([ROLES.indexOf(role)]),
+      holder.toBuffer(),
+    ],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export function getFrozenAccountPDA(token: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("frozen-account"), token.toBuffer()],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export async function getMultisigData() {
+  const program = workspace.Tbtc as Program<Tbtc>;
+  return program.account.multisig.fetch(getMultisigPDA());
+}
+
 export async function getConfigData() {
   const program = workspace.Tbtc as Program<Tbtc>;
   const config = getConfigPDA();
@@ -96,13 +264,45 @@ export async function checkConfig(expected: {
   );
   expect(mintState.supply).to.equal(supply);
 
-  const guardians = getGuardiansPDA();
-  const guardiansState = await program.account.guardians.fetch(guardians);
-  expect(guardiansState.keys).has.length(numGuardians);
+  expect(await getGuardians()).has.length(numGuardians);
+  expect(await getMinters()).has.length(numMinters);
+}
+
+export async function getCpiEvents(txSig: string) {
+  const program = workspace.Tbtc as Program<Tbtc>;
+  const tx = await program.provider.connection.getTransaction(txSig, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+
+  // Events are emitted as self-CPIs signed by the event authority, prefixed with Anchor's event
+  // instruction tag.
+  const accountKeys = tx.transaction.message.getAccountKeys();
+  const eventAuthority = getEventAuthorityPDA();
+  const events = [];
+  for (const inner of tx.meta.innerInstructions) {
+    for (const ix of inner.instructions) {
+      const programId = accountKeys.get(ix.programIdIndex);
+      const firstAccount = accountKeys.get(ix.accounts[0]);
+      if (
+        !programId.equals(TBTC_PROGRAM_ID) ||
+        firstAccount === undefined ||
+        !firstAccount.equals(eventAuthority)
+      ) {
+        continue;
+      }
+
+      const data = Buffer.from(utils.bytes.bs58.decode(ix.data));
+      const event = program.coder.events.decode(
+        data.subarray(8).toString("base64")
+      );
+      if (event !== null) {
+        events.push(event);
+      }
+    }
+  }
 
-  const minters = getMintersPDA();
-  const mintersState = await program.account.minters.fetch(minters);
-  expect(mintersState.keys).has.length(numMinters);
+  return events;
 }
 
 export async function getMinterInfo(minter: PublicKey) {
@@ -130,7 +330,9 @@ export async function checkGuardianInfo(guardian: PublicKey) {
 type AddGuardianContext = {
   config?: PublicKey;
   authority: PublicKey;
-  guardians?: PublicKey;
+  roleAssignment?: PublicKey | null;
+  guardiansPage?: PublicKey | null;
+  guardians?: PublicKey | null;
   guardianInfo?: PublicKey;
   guardian: PublicKey;
 };
@@ -140,13 +342,14 @@ export async function addGuardianIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, guardians, guardianInfo, guardian } = accounts;
+  let { config, authority, guardiansPage, guardians, guardianInfo, guardian } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (guardians === undefined) {
-    guardians = getGuardiansPDA();
+  if (guardiansPage === undefined) {
+    guardiansPage = await getNextGuardiansPagePDA();
   }
 
   if (guardianInfo === undefined) {
@@ -158,6 +361,8 @@ export async function addGuardianIx(
     .accounts({
       config,
       authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      guardiansPage,
       guardians,
       guardianInfo,
       guardian,
@@ -168,7 +373,9 @@ export async function addGuardianIx(
 type AddMinterContext = {
   config?: PublicKey;
   authority: PublicKey;
-  minters?: PublicKey;
+  roleAssignment?: PublicKey | null;
+  mintersPage?: PublicKey | null;
+  minters?: PublicKey | null;
   minterInfo?: PublicKey;
   minter: PublicKey;
 };
@@ -178,13 +385,14 @@ export async function addMinterIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, minters, minterInfo, minter } = accounts;
+  let { config, authority, mintersPage, minters, minterInfo, minter } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (minters === undefined) {
-    minters = getMintersPDA();
+  if (mintersPage === undefined) {
+    mintersPage = await getNextMintersPagePDA();
   }
 
   if (minterInfo === undefined) {
@@ -196,6 +404,8 @@ export async function addMinterIx(
     .accounts({
       config,
       authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      mintersPage,
       minters,
       minterInfo,
       minter,
@@ -203,6 +413,49 @@ export async function addMinterIx(
     .instruction();
 }
 
+type UpdateMinterLimitsContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+type UpdateMinterLimitsArgs = {
+  allowance: BN;
+  rateLimitAmount: BN;
+  rateLimitPeriod: number;
+};
+
+export async function updateMinterLimitsIx(
+  accounts: UpdateMinterLimitsContext,
+  args: UpdateMinterLimitsArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, minterInfo, minter } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .updateMinterLimits(args)
+    .accounts({
+      config,
+      authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
 type CancelAuthorityChange = {
   config?: PublicKey;
   authority: PublicKey;
@@ -223,6 +476,8 @@ export async function cancelAuthorityChangeIx(
     .accounts({
       config,
       authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
     })
     .instruction();
 }
@@ -249,6 +504,8 @@ export async function changeAuthorityIx(
       config,
       authority,
       newAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
     })
     .instruction();
 }
@@ -256,8 +513,6 @@ export async function changeAuthorityIx(
 type InitializeContext = {
   mint?: PublicKey;
   config?: PublicKey;
-  guardians?: PublicKey;
-  minters?: PublicKey;
   authority: PublicKey;
   tbtcMetadata?: PublicKey;
   mplTokenMetadataProgram?: PublicKey;
@@ -271,8 +526,6 @@ export async function initializeIx(
   let {
     mint,
     config,
-    guardians,
-    minters,
     authority,
     tbtcMetadata,
     mplTokenMetadataProgram,
@@ -286,14 +539,6 @@ export async function initializeIx(
     config = getConfigPDA();
   }
 
-  if (guardians === undefined) {
-    guardians = getGuardiansPDA();
-  }
-
-  if (minters === undefined) {
-    minters = getMintersPDA();
-  }
-
   if (tbtcMetadata === undefined) {
     tbtcMetadata = getTbtcMetadataPDA();
   }
@@ -307,8 +552,6 @@ export async function initializeIx(
     .accounts({
       mint,
       config,
-      guardians,
-      minters,
       authority,
       tbtcMetadata,
       mplTokenMetadataProgram,
@@ -323,7 +566,8 @@ type PauseContext = {
 };
 
 export async function pauseIx(
-  accounts: PauseContext
+  accounts: PauseContext,
+  reason: number = 0
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
@@ -337,11 +581,13 @@ export async function pauseIx(
   }
 
   return program.methods
-    .pause()
+    .pause(reason)
     .accounts({
       config,
       guardianInfo,
       guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
     })
     .instruction();
 }
@@ -349,7 +595,9 @@ export async function pauseIx(
 type RemoveGuardianContext = {
   config?: PublicKey;
   authority: PublicKey;
-  guardians?: PublicKey;
+  roleAssignment?: PublicKey | null;
+  guardiansPage?: PublicKey | null;
+  guardians?: PublicKey | null;
   guardianInfo?: PublicKey;
   guardian: PublicKey;
 };
@@ -359,24 +607,42 @@ export async function removeGuardianIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, guardians, guardianInfo, guardian } = accounts;
+  let { config, authority, guardiansPage, guardians, guardianInfo, guardian } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (guardians === undefined) {
-    guardians = getGuardiansPDA();
-  }
-
   if (guardianInfo === undefined) {
     guardianInfo = getGuardianInfoPDA(guardian);
   }
 
+  if (guardiansPage === undefined) {
+    const { registryPage } = await program.account.guardianInfo.fetch(
+      guardianInfo
+    );
+    guardiansPage = getGuardiansPagePDA(registryPage);
+
+    // Unmigrated guardians are removed from the legacy account instead.
+    const page = await program.account.guardiansPage.fetchNullable(
+      guardiansPage
+    );
+    if (page === null || !page.keys.some((key) => key.equals(guardian))) {
+      guardiansPage = null;
+    }
+  }
+
+  if (guardians === undefined) {
+    guardians = guardiansPage === null ? getGuardiansPDA() : null;
+  }
+
   return program.methods
     .removeGuardian()
     .accounts({
       config,
       authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      guardiansPage,
       guardians,
       guardianInfo,
       guardian,
@@ -387,7 +653,9 @@ export async function removeGuardianIx(
 type RemoveMinterContext = {
   config?: PublicKey;
   authority: PublicKey;
-  minters?: PublicKey;
+  roleAssignment?: PublicKey | null;
+  mintersPage?: PublicKey | null;
+  minters?: PublicKey | null;
   minterInfo?: PublicKey;
   minter: PublicKey;
 };
@@ -397,24 +665,42 @@ export async function removeMinterIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, minters, minterInfo, minter } = accounts;
+  let { config, authority, mintersPage, minters, minterInfo, minter } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (minters === undefined) {
-    minters = getMintersPDA();
-  }
-
   if (minterInfo === undefined) {
     minterInfo = getMinterInfoPDA(minter);
   }
 
+  if (mintersPage === undefined) {
+    const { registryPage } = await program.account.minterInfo.fetch(
+      minterInfo
+    );
+    mintersPage = getMintersPagePDA(registryPage);
+
+    // Unmigrated minters are removed from the legacy account instead.
+    const page = await program.account.mintersPage.fetchNullable(
+      mintersPage
+    );
+    if (page === null || !page.keys.some((key) => key.equals(minter))) {
+      mintersPage = null;
+    }
+  }
+
+  if (minters === undefined) {
+    minters = mintersPage === null ? getMintersPDA() : null;
+  }
+
   return program.methods
     .removeMinter()
     .accounts({
       config,
       authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      mintersPage,
       minters,
       minterInfo,
       minter,
@@ -442,6 +728,8 @@ export async function takeAuthorityIx(
     .accounts({
       config,
       pendingAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
     })
     .instruction();
 }
@@ -449,6 +737,7 @@ export async function takeAuthorityIx(
 type UnpauseContext = {
   config?: PublicKey;
   authority: PublicKey;
+  roleAssignment?: PublicKey | null;
 };
 
 export async function unpauseIx(
@@ -466,29 +755,61 @@ export async function unpauseIx(
     .accounts({
       config,
       authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
     })
     .instruction();
 }
 
-type MintContext = {
-  mint?: PublicKey;
-  config?: PublicKey;
+type PauseMinterContext = {
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
   minterInfo?: PublicKey;
   minter: PublicKey;
-  recipientToken: PublicKey;
 };
 
-export async function mintIx(
-  accounts: MintContext,
-  amount: BN
+export async function pauseMinterIx(
+  accounts: PauseMinterContext
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { mint, config, minterInfo, minter, recipientToken } = accounts;
-  if (mint === undefined) {
-    mint = getMintPDA();
+  let { guardianInfo, guardian, minterInfo, minter } = accounts;
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
   }
 
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .pauseMinter()
+    .accounts({
+      guardianInfo,
+      guardian,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type UnpauseMinterContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function unpauseMinterIx(
+  accounts: UnpauseMinterContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, minterInfo, minter } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
@@ -498,13 +819,1008 @@ export async function mintIx(
   }
 
   return program.methods
-    .mint(amount)
+    .unpauseMinter()
     .accounts({
-      mint,
       config,
+      authority,
+      roleAssignment: accounts.roleAssignment ?? null,
       minterInfo,
       minter,
-      recipientToken,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ExtendPauseContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+};
+
+export async function extendPauseIx(
+  accounts: ExtendPauseContext,
+  extension: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .extendPause(extension)
+    .accounts({
+      config,
+      authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type FreezeAccountContext = {
+  config?: PublicKey;
+  mint?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+  token: PublicKey;
+  frozenAccount?: PublicKey;
+};
+
+export async function freezeAccountIx(
+  accounts: FreezeAccountContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, mint, guardianInfo, guardian, token, frozenAccount } =
+    accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  if (frozenAccount === undefined) {
+    frozenAccount = getFrozenAccountPDA(token);
+  }
+
+  return program.methods
+    .freezeAccount()
+    .accounts({
+      config,
+      mint,
+      guardianInfo,
+      guardian,
+      token,
+      frozenAccount,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ThawAccountContext = {
+  config?: PublicKey;
+  mint?: PublicKey;
+  authority: PublicKey;
+  token: PublicKey;
+  frozenAccount?: PublicKey;
+};
+
+export async function thawAccountIx(
+  accounts: ThawAccountContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, mint, authority, token, frozenAccount } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (frozenAccount === undefined) {
+    frozenAccount = getFrozenAccountPDA(token);
+  }
+
+  return program.methods
+    .thawAccount()
+    .accounts({
+      config,
+      mint,
+      authority,
+      token,
+      frozenAccount,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ExtendFreezeContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  token: PublicKey;
+  frozenAccount?: PublicKey;
+};
+
+export async function extendFreezeIx(
+  accounts: ExtendFreezeContext,
+  extension: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, token, frozenAccount } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (frozenAccount === undefined) {
+    frozenAccount = getFrozenAccountPDA(token);
+  }
+
+  return program.methods
+    .extendFreeze(extension)
+    .accounts({
+      config,
+      authority,
+      token,
+      frozenAccount,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type UpdateMaxFreezeDurationContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+};
+
+export async function updateMaxFreezeDurationIx(
+  accounts: UpdateMaxFreezeDurationContext,
+  maxFreezeDuration: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .updateMaxFreezeDuration(maxFreezeDuration)
+    .accounts({
+      config,
+      authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type UpdatePauseParamsContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+};
+
+type UpdatePauseParamsArgs = {
+  maxPauseDuration: number;
+  pauseCooldown: number;
+};
+
+export async function updatePauseParamsIx(
+  accounts: UpdatePauseParamsContext,
+  args: UpdatePauseParamsArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .updatePauseParams(args)
+    .accounts({
+      config,
+      authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type MintContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+  recipientToken: PublicKey;
+};
+
+export async function mintIx(
+  accounts: MintContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, minterInfo, minter, recipientToken } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .mint(amount)
+    .accounts({
+      mint,
+      config,
+      minterInfo,
+      minter,
+      recipientToken,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type MintBatchContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function mintBatchIx(
+  accounts: MintBatchContext,
+  amounts: BN[],
+  recipientTokens: PublicKey[]
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, minterInfo, minter } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .mintBatch(amounts)
+    .accounts({
+      mint,
+      config,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .remainingAccounts(
+      recipientTokens.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      }))
+    )
+    .instruction();
+}
+
+type MintToWalletContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+  payer: PublicKey;
+  recipient: PublicKey;
+  recipientToken?: PublicKey;
+};
+
+export async function mintToWalletIx(
+  accounts: MintToWalletContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, minterInfo, minter, payer, recipient, recipientToken } =
+    accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  if (recipientToken === undefined) {
+    recipientToken = getAssociatedTokenAddressSync(mint, recipient);
+  }
+
+  return program.methods
+    .mintToWallet(amount)
+    .accounts({
+      mint,
+      config,
+      minterInfo,
+      minter,
+      payer,
+      recipient,
+      recipientToken,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type BurnContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  ownerToken: PublicKey;
+  owner: PublicKey;
+  minterInfo?: PublicKey | null;
+  minter?: PublicKey | null;
+};
+
+export async function burnIx(
+  accounts: BurnContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, ownerToken, owner, minterInfo, minter } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minter === undefined) {
+    minter = null;
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = minter === null ? null : getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .burn(amount)
+    .accounts({
+      mint,
+      config,
+      ownerToken,
+      owner,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type BurnFromContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  fromToken: PublicKey;
+  spender: PublicKey;
+  minterInfo?: PublicKey | null;
+  minter?: PublicKey | null;
+};
+
+export async function burnFromIx(
+  accounts: BurnFromContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, fromToken, spender, minterInfo, minter } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minter === undefined) {
+    minter = null;
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = minter === null ? null : getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .burnFrom(amount)
+    .accounts({
+      mint,
+      config,
+      fromToken,
+      spender,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ReconcileContext = {
+  config?: PublicKey;
+  mint?: PublicKey;
+  guardianInfo?: PublicKey | null;
+  guardian?: PublicKey | null;
+};
+
+export async function reconcileIx(
+  accounts: ReconcileContext = {}
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, mint, guardianInfo, guardian } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (guardian === undefined) {
+    guardian = null;
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = guardian === null ? null : getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .reconcile()
+    .accounts({
+      config,
+      mint,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type RecoverTokensContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  sourceToken: PublicKey;
+  recipientToken: PublicKey;
+};
+
+export async function recoverTokensIx(
+  accounts: RecoverTokensContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, sourceToken, recipientToken } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .recoverTokens(amount)
+    .accounts({
+      config,
+      authority,
+      sourceToken,
+      recipientToken,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type UpdateMetadataContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+  tbtcMetadata?: PublicKey;
+  mplTokenMetadataProgram?: PublicKey;
+};
+
+type UpdateMetadataArgs = {
+  name: string;
+  symbol: string;
+  uri: string;
+};
+
+export async function updateMetadataIx(
+  accounts: UpdateMetadataContext,
+  args: UpdateMetadataArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, tbtcMetadata, mplTokenMetadataProgram } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (tbtcMetadata === undefined) {
+    tbtcMetadata = getTbtcMetadataPDA();
+  }
+
+  if (mplTokenMetadataProgram === undefined) {
+    mplTokenMetadataProgram = METADATA_PROGRAM_ID;
+  }
+
+  return program.methods
+    .updateMetadata(args)
+    .accounts({
+      config,
+      authority,
+      roleAssignment: accounts.roleAssignment ?? null,
+      tbtcMetadata,
+      mplTokenMetadataProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type GrantRoleContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey;
+  holder: PublicKey;
+};
+
+export async function grantRoleIx(
+  accounts: GrantRoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, roleAssignment, holder } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = getRoleAssignmentPDA(role, holder);
+  }
+
+  return program.methods
+    .grantRole({ [role]: {} } as any)
+    .accounts({
+      config,
+      authority,
+      roleAssignment,
+      holder,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type RevokeRoleContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleAssignment: PublicKey;
+};
+
+export async function revokeRoleIx(
+  accounts: RevokeRoleContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, roleAssignment } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .revokeRole()
+    .accounts({
+      config,
+      authority,
+      roleAssignment,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type MigrateConfigContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  mint?: PublicKey;
+};
+
+export async function migrateConfigIx(
+  accounts: MigrateConfigContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, mint } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  return program.methods
+    .migrateConfig()
+    .accounts({
+      config,
+      authority,
+      mint,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type MigrateMinterInfoContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function migrateMinterInfoIx(
+  accounts: MigrateMinterInfoContext,
+  mintedTotal: BN = new BN(0)
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, minterInfo, minter } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .migrateMinterInfo(mintedTotal)
+    .accounts({
+      config,
+      authority,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type MigrateGuardianInfoContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+};
+
+export async function migrateGuardianInfoIx(
+  accounts: MigrateGuardianInfoContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, guardianInfo, guardian } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .migrateGuardianInfo()
+    .accounts({
+      config,
+      authority,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type InitializeMultisigContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  multisig?: PublicKey;
+  multisigAuthority?: PublicKey;
+};
+
+type InitializeMultisigArgs = {
+  signers: PublicKey[];
+  threshold: number;
+};
+
+export async function initializeMultisigIx(
+  accounts: InitializeMultisigContext,
+  args: InitializeMultisigArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, multisig, multisigAuthority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  if (multisigAuthority === undefined) {
+    multisigAuthority = getMultisigAuthorityPDA();
+  }
+
+  return program.methods
+    .initializeMultisig(args)
+    .accounts({
+      config,
+      authority,
+      multisig,
+      multisigAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type CreateProposalContext = {
+  multisig?: PublicKey;
+  proposal?: PublicKey;
+  proposer: PublicKey;
+};
+
+export async function createProposalIx(
+  accounts: CreateProposalContext,
+  action: any
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { multisig, proposal, proposer } = accounts;
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  if (proposal === undefined) {
+    const { numProposals } = await getMultisigData();
+    proposal = getProposalPDA(numProposals);
+  }
+
+  return program.methods
+    .createProposal(action)
+    .accounts({
+      multisig,
+      proposal,
+      proposer,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ApproveProposalContext = {
+  multisig?: PublicKey;
+  proposal: PublicKey;
+  signer: PublicKey;
+};
+
+export async function approveProposalIx(
+  accounts: ApproveProposalContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { multisig, proposal, signer } = accounts;
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  return program.methods
+    .approveProposal()
+    .accounts({
+      multisig,
+      proposal,
+      signer,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ExecuteProposalContext = {
+  multisig?: PublicKey;
+  proposal: PublicKey;
+  proposer: PublicKey;
+  multisigAuthority?: PublicKey;
+};
+
+export async function executeProposalIx(
+  accounts: ExecuteProposalContext,
+  remainingAccounts: AccountMeta[]
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { multisig, proposal, proposer, multisigAuthority } = accounts;
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  if (multisigAuthority === undefined) {
+    multisigAuthority = getMultisigAuthorityPDA();
+  }
+
+  return program.methods
+    .executeProposal()
+    .accounts({
+      multisig,
+      proposal,
+      proposer,
+      multisigAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+}
+
+type UpdateTimelockDelayContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function updateTimelockDelayIx(
+  accounts: UpdateTimelockDelayContext,
+  delay: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .updateTimelockDelay(delay)
+    .accounts({
+      config,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ScheduleChangeContext = {
+  config?: PublicKey;
+  scheduledChange?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function scheduleChangeIx(
+  accounts: ScheduleChangeContext,
+  action: any
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, scheduledChange, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (scheduledChange === undefined) {
+    const { numScheduledChanges } = await getConfigData();
+    scheduledChange = getScheduledChangePDA(numScheduledChanges);
+  }
+
+  return program.methods
+    .scheduleChange(action)
+    .accounts({
+      config,
+      scheduledChange,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type CancelScheduledChangeContext = {
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+  scheduledChange: PublicKey;
+  scheduledBy: PublicKey;
+};
+
+export async function cancelScheduledChangeIx(
+  accounts: CancelScheduledChangeContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { guardianInfo, guardian, scheduledChange, scheduledBy } = accounts;
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .cancelScheduledChange()
+    .accounts({
+      guardianInfo,
+      guardian,
+      scheduledChange,
+      scheduledBy,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ExecuteScheduledChangeContext = {
+  scheduledChange: PublicKey;
+  scheduledBy: PublicKey;
+  timelockAuthority?: PublicKey;
+};
+
+export async function executeScheduledChangeIx(
+  accounts: ExecuteScheduledChangeContext,
+  remainingAccounts: AccountMeta[]
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { scheduledChange, scheduledBy, timelockAuthority } = accounts;
+  if (timelockAuthority === undefined) {
+    timelockAuthority = getTimelockAuthorityPDA();
+  }
+
+  return program.methods
+    .executeScheduledChange()
+    .accounts({
+      scheduledChange,
+      scheduledBy,
+      timelockAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: TBTC_PROGRAM_ID,
     })
+    .remainingAccounts(remainingAccounts)
     .instruction();
 }
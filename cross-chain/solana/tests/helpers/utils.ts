@@ -183,9 +183,10 @@ export async function ethereumGatewaySendTbtc(
   amount: bigint,
   fromGateway: number[],
   toGateway: PublicKey,
-  recipient: PublicKey,
+  recipient: PublicKey | Buffer,
   tokenAddress?: string,
-  tokenChain?: number
+  tokenChain?: number,
+  timestamp?: number
 ) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
@@ -196,9 +197,11 @@ export async function ethereumGatewaySendTbtc(
     1,
     toGateway.toBuffer().toString("hex"),
     Buffer.from(fromGateway),
-    recipient.toBuffer(),
+    // A buffer is an encoded gateway message.
+    Buffer.isBuffer(recipient) ? recipient : recipient.toBuffer(),
     0,
-    0
+    // Target programs are only called for recently sent transfers.
+    timestamp ?? Math.floor(Date.now() / 1000)
   );
 
   const guardians = new MockGuardians(
@@ -226,7 +229,7 @@ export async function expectIxSuccess(
   signers: Keypair[]
 ) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  await sendAndConfirmTransaction(
+  return sendAndConfirmTransaction(
     program.provider.connection,
     new Transaction().add(...ixes),
     signers
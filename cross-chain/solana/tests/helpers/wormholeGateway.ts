@@ -4,12 +4,14 @@ import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
 import { BN, Program, workspace } from "@coral-xyz/anchor";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import {
+  AccountMeta,
   PublicKey,
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_RENT_PUBKEY,
   TransactionInstruction,
 } from "@solana/web3.js";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import { WormholeGateway } from "../../target/types/wormhole_gateway";
 import {
   CORE_BRIDGE_DATA,
@@ -56,6 +58,50 @@ export function getWrappedTbtcTokenPDA(): PublicKey {
   )[0];
 }
 
+export function getTreasuryTokenPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getCallTargetPDA(program: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("call-target"), program.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getCallAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("call-authority")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getCallEscrowPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("call-escrow")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getUpdatedWrappedTbtcTokenPDA(
+  wrappedTbtcMint: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("wrapped-token"), wrappedTbtcMint.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getGatewayGuardianPDA(guardian: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("gateway-guardian"), guardian.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
 export function getTokenBridgeSenderPDA(): PublicKey {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("sender")],
@@ -70,6 +116,46 @@ export function getTokenBridgeRedeemerPDA(): PublicKey {
   )[0];
 }
 
+export function getMultisigPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("multisig")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getMultisigAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("multisig-authority")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getProposalPDA(index: BN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("proposal"), index.toArrayLike(Buffer, "le", 8)],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getTimelockAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("timelock-authority")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getScheduledChangePDA(index: BN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("scheduled-change"), index.toArrayLike(Buffer, "le", 8)],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export async function getMultisigData() {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  return program.account.multisig.fetch(getMultisigPDA());
+}
+
 export async function getCustodianData() {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   const custodian = getCustodianPDA();
@@ -90,11 +176,93 @@ export async function checkCustodian(expected: {
   expect(custodianState.pendingAuthority).to.eql(pendingAuthority);
 }
 
+export async function checkTbtcToken(expected: {
+  chain: number;
+  address: number[];
+  wrappedTbtcMint: PublicKey;
+  wrappedTbtcToken: PublicKey;
+}) {
+  const { chain, address, wrappedTbtcMint, wrappedTbtcToken } = expected;
+  const custodianState = await getCustodianData();
+
+  expect(custodianState.tbtcTokenChain).to.equal(chain);
+  expect(custodianState.tbtcTokenAddress).to.eql(address);
+  expect(custodianState.wrappedTbtcMint).to.eql(wrappedTbtcMint);
+  expect(custodianState.wrappedTbtcToken).to.eql(wrappedTbtcToken);
+}
+
 export async function getMintedAmount(): Promise<bigint> {
   const custodianState = await getCustodianData();
   return BigInt(custodianState.mintedAmount.toString());
 }
 
+// Mirrors `Custodian::remaining_inbound_capacity`.
+export async function getRemainingInboundCapacity(
+  now: number
+): Promise<bigint> {
+  const {
+    inboundLimitAmount,
+    inboundLimitPeriod,
+    inboundUpdatedAt,
+    inboundAmount,
+  } = await getCustodianData();
+
+  if (inboundLimitPeriod === 0) {
+    return BigInt("18446744073709551615");
+  }
+
+  // The counted amount decays linearly, freeing up the limit over one period.
+  const limit = BigInt(inboundLimitAmount.toString());
+  const elapsed = BigInt(Math.max(now - inboundUpdatedAt.toNumber(), 0));
+  const decayed = (elapsed * limit) / BigInt(inboundLimitPeriod);
+  const amount = BigInt(inboundAmount.toString());
+  const used = amount > decayed ? amount - decayed : BigInt(0);
+  return used > limit ? BigInt(0) : limit - used;
+}
+
+// Hashes the accounts a target program is called with after the call
+// authority, call escrow and recipient, as committed to in a gateway message.
+export function hashCallAccounts(accounts: AccountMeta[]): Buffer {
+  return createHash("sha256")
+    .update(
+      Buffer.concat(
+        accounts.map((meta) =>
+          Buffer.concat([
+            meta.pubkey.toBuffer(),
+            Buffer.from([meta.isWritable ? 1 : 0]),
+          ])
+        )
+      )
+    )
+    .digest();
+}
+
+// Encodes a gateway message carrying a relayer fee. Legacy messages are just
+// the recipient.
+export function encodeGatewayMessage(
+  recipient: PublicKey,
+  relayerFee: bigint,
+  call?: { program: PublicKey; accounts?: AccountMeta[]; data: Buffer }
+): Buffer {
+  const relayerFeeBytes = Buffer.alloc(8);
+  relayerFeeBytes.writeBigUInt64BE(relayerFee);
+  if (call === undefined) {
+    return Buffer.concat([
+      Buffer.from([1]),
+      recipient.toBuffer(),
+      relayerFeeBytes,
+    ]);
+  }
+  return Buffer.concat([
+    Buffer.from([2]),
+    recipient.toBuffer(),
+    relayerFeeBytes,
+    call.program.toBuffer(),
+    hashCallAccounts(call.accounts ?? []),
+    call.data,
+  ]);
+}
+
 export async function getGatewayInfo(chain: number) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   const gatewayInfo = getGatewayInfoPDA(chain);
@@ -205,220 +373,757 @@ export async function updateMintingLimitIx(
     .instruction();
 }
 
-type UpdateGatewayAddressContext = {
+type GatewayGuardianContext = {
   custodian?: PublicKey;
-  gatewayInfo?: PublicKey;
   authority: PublicKey;
+  gatewayGuardian?: PublicKey;
+  guardian: PublicKey;
 };
 
-type UpdateGatewayAddressArgs = {
-  chain: number;
-  address: number[];
-};
-
-export async function updateGatewayAddress(
-  accounts: UpdateGatewayAddressContext,
-  args: UpdateGatewayAddressArgs
-) {
+export async function addGatewayGuardianIx(
+  accounts: GatewayGuardianContext
+): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  let { custodian, gatewayInfo, authority } = accounts;
 
+  let { custodian, authority, gatewayGuardian, guardian } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
-  if (gatewayInfo === undefined) {
-    gatewayInfo = getGatewayInfoPDA(args.chain);
+  if (gatewayGuardian === undefined) {
+    gatewayGuardian = getGatewayGuardianPDA(guardian);
   }
 
   return program.methods
-    .updateGatewayAddress(args)
+    .addGatewayGuardian()
     .accounts({
       custodian,
-      gatewayInfo,
       authority,
+      gatewayGuardian,
+      guardian,
     })
     .instruction();
 }
 
-type DepositWormholeTbtcContext = {
-  custodian?: PublicKey;
-  wrappedTbtcToken?: PublicKey;
-  wrappedTbtcMint?: PublicKey;
-  tbtcMint?: PublicKey;
-  recipientWrappedToken: PublicKey;
-  recipientToken: PublicKey;
-  recipient: PublicKey;
-  tbtcConfig?: PublicKey;
-  tbtcMinterInfo?: PublicKey;
-  tbtcProgram?: PublicKey;
-};
-
-export async function depositWormholeTbtcIx(
-  accounts: DepositWormholeTbtcContext,
-  amount: bigint
+export async function removeGatewayGuardianIx(
+  accounts: GatewayGuardianContext
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  let {
-    custodian,
-    wrappedTbtcToken,
-    wrappedTbtcMint,
-    tbtcMint,
-    recipientWrappedToken,
-    recipientToken,
-    recipient,
-    tbtcConfig,
-    tbtcMinterInfo,
-    tbtcProgram,
-  } = accounts;
 
+  let { custodian, authority, gatewayGuardian, guardian } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
-  if (wrappedTbtcToken === undefined) {
-    wrappedTbtcToken = getWrappedTbtcTokenPDA();
+  if (gatewayGuardian === undefined) {
+    gatewayGuardian = getGatewayGuardianPDA(guardian);
   }
 
-  if (wrappedTbtcMint === undefined) {
-    wrappedTbtcMint = WRAPPED_TBTC_MINT;
-  }
+  return program.methods
+    .removeGatewayGuardian()
+    .accounts({
+      custodian,
+      authority,
+      gatewayGuardian,
+      guardian,
+    })
+    .instruction();
+}
 
-  if (tbtcMint === undefined) {
-    tbtcMint = tbtc.getMintPDA();
-  }
+type SetGatewayModeContext = {
+  custodian?: PublicKey;
+  signer: PublicKey;
+  gatewayGuardian?: PublicKey | null;
+};
 
-  if (tbtcConfig === undefined) {
-    tbtcConfig = tbtc.getConfigPDA();
-  }
+export async function setGatewayModeIx(
+  accounts: SetGatewayModeContext,
+  mode: any
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
-  if (tbtcMinterInfo === undefined) {
-    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  let { custodian, signer, gatewayGuardian } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
   }
 
-  if (tbtcProgram === undefined) {
-    tbtcProgram = TBTC_PROGRAM_ID;
+  if (gatewayGuardian === undefined) {
+    gatewayGuardian = null;
   }
 
   return program.methods
-    .depositWormholeTbtc(new BN(amount.toString()))
+    .setGatewayMode(mode)
     .accounts({
       custodian,
-      wrappedTbtcToken,
-      wrappedTbtcMint,
-      tbtcMint,
-      recipientWrappedToken,
-      recipientToken,
-      recipient,
-      tbtcConfig,
-      tbtcMinterInfo,
-      tbtcProgram,
+      signer,
+      gatewayGuardian,
     })
     .instruction();
 }
 
-type ReceiveTbtcContext = {
-  payer: PublicKey;
+type DisableGatewayContext = {
   custodian?: PublicKey;
-  postedVaa?: PublicKey;
-  tokenBridgeClaim?: PublicKey;
-  wrappedTbtcToken?: PublicKey;
-  wrappedTbtcMint?: PublicKey;
-  tbtcMint?: PublicKey;
-  recipientToken: PublicKey;
-  recipient: PublicKey;
-  recipientWrappedToken?: PublicKey;
-  tbtcConfig?: PublicKey;
-  tbtcMinterInfo?: PublicKey;
-  tokenBridgeConfig?: PublicKey;
-  tokenBridgeRegisteredEmitter?: PublicKey;
-  //tokenBridgeRedeemer?: PublicKey;
-  tokenBridgeWrappedAsset?: PublicKey;
-  tokenBridgeMintAuthority?: PublicKey;
-  rent?: PublicKey;
-  tbtcProgram?: PublicKey;
-  tokenBridgeProgram?: PublicKey;
-  coreBridgeProgram?: PublicKey;
+  gatewayInfo?: PublicKey;
+  authority: PublicKey;
 };
 
-export async function receiveTbtcIx(
-  accounts: ReceiveTbtcContext,
-  signedVaa: Buffer
+export async function disableGatewayIx(
+  accounts: DisableGatewayContext,
+  chain: number
 ): Promise<TransactionInstruction> {
-  const parsed = parseVaa(signedVaa);
-
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  let {
-    payer,
-    custodian,
-    postedVaa,
-    tokenBridgeClaim,
-    wrappedTbtcToken,
-    wrappedTbtcMint,
-    tbtcMint,
-    recipientToken,
-    recipient,
-    recipientWrappedToken,
-    tbtcConfig,
-    tbtcMinterInfo,
-    tokenBridgeConfig,
-    tokenBridgeRegisteredEmitter,
-    //tokenBridgeRedeemer,
-    tokenBridgeWrappedAsset,
-    tokenBridgeMintAuthority,
-    rent,
-    tbtcProgram,
-    tokenBridgeProgram,
-    coreBridgeProgram,
-  } = accounts;
 
+  let { custodian, gatewayInfo, authority } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
-  if (postedVaa === undefined) {
-    postedVaa = coreBridge.derivePostedVaaKey(
-      CORE_BRIDGE_PROGRAM_ID,
-      parsed.hash
-    );
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(chain);
   }
 
-  if (tokenBridgeClaim === undefined) {
-    tokenBridgeClaim = coreBridge.deriveClaimKey(
-      TOKEN_BRIDGE_PROGRAM_ID,
-      parsed.emitterAddress,
-      parsed.emitterChain,
-      parsed.sequence
-    );
-  }
+  return program.methods
+    .disableGateway(chain)
+    .accounts({
+      custodian,
+      gatewayInfo,
+      authority,
+    })
+    .instruction();
+}
 
-  if (wrappedTbtcToken === undefined) {
-    wrappedTbtcToken = getWrappedTbtcTokenPDA();
-  }
+type UpdateGatewayLimitsContext = {
+  custodian?: PublicKey;
+  gatewayInfo?: PublicKey;
+  authority: PublicKey;
+};
 
-  if (wrappedTbtcMint === undefined) {
-    wrappedTbtcMint = WRAPPED_TBTC_MINT;
-  }
+type UpdateGatewayLimitsArgs = {
+  chain: number;
+  outboundLimitAmount: bigint;
+  outboundLimitPeriod: number;
+  minTransferAmount: bigint;
+  maxTransferAmount: bigint;
+};
 
-  if (tbtcMint === undefined) {
-    tbtcMint = tbtc.getMintPDA();
-  }
+export async function updateGatewayLimitsIx(
+  accounts: UpdateGatewayLimitsContext,
+  args: UpdateGatewayLimitsArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
-  if (recipientWrappedToken == undefined) {
-    recipientWrappedToken = getAssociatedTokenAddressSync(
-      wrappedTbtcMint,
-      recipient
-    );
+  let { custodian, gatewayInfo, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
   }
 
-  if (tbtcConfig === undefined) {
-    tbtcConfig = tbtc.getConfigPDA();
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  return program.methods
+    .updateGatewayLimits({
+      chain: args.chain,
+      outboundLimitAmount: new BN(args.outboundLimitAmount.toString()),
+      outboundLimitPeriod: args.outboundLimitPeriod,
+      minTransferAmount: new BN(args.minTransferAmount.toString()),
+      maxTransferAmount: new BN(args.maxTransferAmount.toString()),
+    })
+    .accounts({
+      custodian,
+      gatewayInfo,
+      authority,
+    })
+    .instruction();
+}
+
+type UpdateGatewayFeesContext = {
+  custodian?: PublicKey;
+  gatewayInfo?: PublicKey;
+  treasuryToken?: PublicKey;
+  authority: PublicKey;
+};
+
+type UpdateGatewayFeesArgs = {
+  chain: number;
+  inboundFeeBps: number;
+  inboundMinFee: bigint;
+  outboundFeeBps: number;
+  outboundMinFee: bigint;
+};
+
+export async function updateGatewayFeesIx(
+  accounts: UpdateGatewayFeesContext,
+  args: UpdateGatewayFeesArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, gatewayInfo, treasuryToken, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  if (treasuryToken === undefined) {
+    treasuryToken = getTreasuryTokenPDA();
+  }
+
+  return program.methods
+    .updateGatewayFees({
+      chain: args.chain,
+      inboundFeeBps: args.inboundFeeBps,
+      inboundMinFee: new BN(args.inboundMinFee.toString()),
+      outboundFeeBps: args.outboundFeeBps,
+      outboundMinFee: new BN(args.outboundMinFee.toString()),
+    })
+    .accounts({
+      custodian,
+      gatewayInfo,
+      treasuryToken,
+      authority,
+    })
+    .instruction();
+}
+
+type CallTargetContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  callTarget?: PublicKey;
+  program: PublicKey;
+};
+
+export async function addCallTargetIx(
+  accounts: CallTargetContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, callTarget, program: target } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (callTarget === undefined) {
+    callTarget = getCallTargetPDA(target);
+  }
+
+  return program.methods
+    .addCallTarget()
+    .accounts({
+      custodian,
+      authority,
+      callTarget,
+      program: target,
+      tbtcMint: tbtc.getMintPDA(),
+      callAuthority: getCallAuthorityPDA(),
+      callEscrow: getCallEscrowPDA(),
+    })
+    .instruction();
+}
+
+export async function removeCallTargetIx(
+  accounts: CallTargetContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, callTarget, program: target } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (callTarget === undefined) {
+    callTarget = getCallTargetPDA(target);
+  }
+
+  return program.methods
+    .removeCallTarget()
+    .accounts({
+      custodian,
+      authority,
+      callTarget,
+      program: target,
+    })
+    .instruction();
+}
+
+type InitializeTreasuryContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  tbtcMint?: PublicKey;
+  treasuryToken?: PublicKey;
+};
+
+export async function initializeTreasuryIx(
+  accounts: InitializeTreasuryContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, tbtcMint, treasuryToken } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (tbtcMint === undefined) {
+    tbtcMint = tbtc.getMintPDA();
+  }
+
+  if (treasuryToken === undefined) {
+    treasuryToken = getTreasuryTokenPDA();
+  }
+
+  return program.methods
+    .initializeTreasury()
+    .accounts({
+      custodian,
+      authority,
+      tbtcMint,
+      treasuryToken,
+    })
+    .instruction();
+}
+
+type WithdrawFeesContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  treasuryToken?: PublicKey;
+  recipientToken: PublicKey;
+};
+
+export async function withdrawFeesIx(
+  accounts: WithdrawFeesContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, treasuryToken, recipientToken } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (treasuryToken === undefined) {
+    treasuryToken = getTreasuryTokenPDA();
+  }
+
+  return program.methods
+    .withdrawFees(new BN(amount.toString()))
+    .accounts({
+      custodian,
+      authority,
+      treasuryToken,
+      recipientToken,
+    })
+    .instruction();
+}
+
+type UpdateInboundLimitContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+};
+
+type UpdateInboundLimitArgs = {
+  amount: bigint;
+  period: number;
+};
+
+export async function updateInboundLimitIx(
+  accounts: UpdateInboundLimitContext,
+  args: UpdateInboundLimitArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  return program.methods
+    .updateInboundLimit({
+      amount: new BN(args.amount.toString()),
+      period: args.period,
+    })
+    .accounts({
+      custodian,
+      authority,
+    })
+    .instruction();
+}
+
+type UpdateGatewayAddressContext = {
+  custodian?: PublicKey;
+  gatewayInfo?: PublicKey;
+  authority: PublicKey;
+};
+
+type UpdateGatewayAddressArgs = {
+  chain: number;
+  address: number[];
+};
+
+export async function updateGatewayAddress(
+  accounts: UpdateGatewayAddressContext,
+  args: UpdateGatewayAddressArgs
+) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, gatewayInfo, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  return program.methods
+    .updateGatewayAddress(args)
+    .accounts({
+      custodian,
+      gatewayInfo,
+      authority,
+    })
+    .instruction();
+}
+
+type RecoverTokensContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  sourceToken: PublicKey;
+  recipientToken: PublicKey;
+};
+
+export async function recoverTokensIx(
+  accounts: RecoverTokensContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, sourceToken, recipientToken } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  return program.methods
+    .recoverTokens(new BN(amount.toString()))
+    .accounts({
+      custodian,
+      authority,
+      sourceToken,
+      recipientToken,
+    })
+    .instruction();
+}
+
+type ReconcileContext = {
+  custodian?: PublicKey;
+  tbtcConfig?: PublicKey;
+  tbtcMinterInfo?: PublicKey;
+  tbtcGuardianInfo?: PublicKey | null;
+  tbtcGuardian?: PublicKey | null;
+  tbtcEventAuthority?: PublicKey;
+  tbtcProgram?: PublicKey;
+};
+
+export async function reconcileIx(
+  accounts: ReconcileContext = {}
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let {
+    custodian,
+    tbtcConfig,
+    tbtcMinterInfo,
+    tbtcGuardianInfo,
+    tbtcGuardian,
+    tbtcEventAuthority,
+    tbtcProgram,
+  } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (tbtcConfig === undefined) {
+    tbtcConfig = tbtc.getConfigPDA();
+  }
+
+  if (tbtcMinterInfo === undefined) {
+    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  }
+
+  if (tbtcGuardian === undefined) {
+    tbtcGuardian = null;
+  }
+
+  if (tbtcGuardianInfo === undefined) {
+    tbtcGuardianInfo =
+      tbtcGuardian === null ? null : tbtc.getGuardianInfoPDA(tbtcGuardian);
+  }
+
+  if (tbtcEventAuthority === undefined) {
+    tbtcEventAuthority = tbtc.getEventAuthorityPDA();
+  }
+
+  if (tbtcProgram === undefined) {
+    tbtcProgram = TBTC_PROGRAM_ID;
+  }
+
+  return program.methods
+    .reconcile()
+    .accounts({
+      custodian,
+      tbtcConfig,
+      tbtcMinterInfo,
+      tbtcGuardianInfo,
+      tbtcGuardian,
+      tbtcEventAuthority,
+      tbtcProgram,
+    })
+    .instruction();
+}
+
+type DepositWormholeTbtcContext = {
+  custodian?: PublicKey;
+  wrappedTbtcToken?: PublicKey;
+  wrappedTbtcMint?: PublicKey;
+  tbtcMint?: PublicKey;
+  recipientWrappedToken: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
+  tbtcConfig?: PublicKey;
+  tbtcMinterInfo?: PublicKey;
+  tbtcEventAuthority?: PublicKey;
+  tbtcProgram?: PublicKey;
+};
+
+export async function depositWormholeTbtcIx(
+  accounts: DepositWormholeTbtcContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    custodian,
+    wrappedTbtcToken,
+    wrappedTbtcMint,
+    tbtcMint,
+    recipientWrappedToken,
+    recipientToken,
+    recipient,
+    tbtcConfig,
+    tbtcMinterInfo,
+    tbtcEventAuthority,
+    tbtcProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (wrappedTbtcToken === undefined) {
+    wrappedTbtcToken = getWrappedTbtcTokenPDA();
+  }
+
+  if (wrappedTbtcMint === undefined) {
+    wrappedTbtcMint = WRAPPED_TBTC_MINT;
+  }
+
+  if (tbtcMint === undefined) {
+    tbtcMint = tbtc.getMintPDA();
+  }
+
+  if (tbtcConfig === undefined) {
+    tbtcConfig = tbtc.getConfigPDA();
   }
 
   if (tbtcMinterInfo === undefined) {
     tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
   }
 
+  if (tbtcEventAuthority === undefined) {
+    tbtcEventAuthority = tbtc.getEventAuthorityPDA();
+  }
+
+  if (tbtcProgram === undefined) {
+    tbtcProgram = TBTC_PROGRAM_ID;
+  }
+
+  return program.methods
+    .depositWormholeTbtc(new BN(amount.toString()))
+    .accounts({
+      custodian,
+      wrappedTbtcToken,
+      wrappedTbtcMint,
+      tbtcMint,
+      recipientWrappedToken,
+      recipientToken,
+      recipient,
+      tbtcConfig,
+      tbtcMinterInfo,
+      tbtcEventAuthority,
+      tbtcProgram,
+    })
+    .instruction();
+}
+
+type ReceiveTbtcContext = {
+  payer: PublicKey;
+  custodian?: PublicKey;
+  postedVaa?: PublicKey;
+  gatewayInfo?: PublicKey;
+  treasuryToken?: PublicKey;
+  tokenBridgeClaim?: PublicKey;
+  wrappedTbtcToken?: PublicKey;
+  wrappedTbtcMint?: PublicKey;
+  tbtcMint?: PublicKey;
+  recipientToken?: PublicKey;
+  recipient: PublicKey;
+  payerToken?: PublicKey;
+  recipientWrappedToken?: PublicKey;
+  callTarget?: PublicKey;
+  callAuthority?: PublicKey;
+  callEscrow?: PublicKey;
+  targetProgram?: PublicKey;
+  tbtcConfig?: PublicKey;
+  tbtcMinterInfo?: PublicKey;
+  tbtcEventAuthority?: PublicKey;
+  tokenBridgeConfig?: PublicKey;
+  tokenBridgeRegisteredEmitter?: PublicKey;
+  //tokenBridgeRedeemer?: PublicKey;
+  tokenBridgeWrappedAsset?: PublicKey;
+  tokenBridgeMintAuthority?: PublicKey;
+  rent?: PublicKey;
+  tbtcProgram?: PublicKey;
+  tokenBridgeProgram?: PublicKey;
+  coreBridgeProgram?: PublicKey;
+};
+
+export async function receiveTbtcIx(
+  accounts: ReceiveTbtcContext,
+  signedVaa: Buffer,
+  callAccounts: AccountMeta[] = []
+): Promise<TransactionInstruction> {
+  const parsed = parseVaa(signedVaa);
+
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    payer,
+    custodian,
+    postedVaa,
+    gatewayInfo,
+    treasuryToken,
+    tokenBridgeClaim,
+    wrappedTbtcToken,
+    wrappedTbtcMint,
+    tbtcMint,
+    recipientToken,
+    recipient,
+    payerToken,
+    recipientWrappedToken,
+    callTarget,
+    callAuthority,
+    callEscrow,
+    targetProgram,
+    tbtcConfig,
+    tbtcMinterInfo,
+    tbtcEventAuthority,
+    tokenBridgeConfig,
+    tokenBridgeRegisteredEmitter,
+    //tokenBridgeRedeemer,
+    tokenBridgeWrappedAsset,
+    tokenBridgeMintAuthority,
+    rent,
+    tbtcProgram,
+    tokenBridgeProgram,
+    coreBridgeProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (postedVaa === undefined) {
+    postedVaa = coreBridge.derivePostedVaaKey(
+      CORE_BRIDGE_PROGRAM_ID,
+      parsed.hash
+    );
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(parsed.emitterChain);
+  }
+
+  if (treasuryToken === undefined) {
+    treasuryToken = getTreasuryTokenPDA();
+  }
+
+  if (tokenBridgeClaim === undefined) {
+    tokenBridgeClaim = coreBridge.deriveClaimKey(
+      TOKEN_BRIDGE_PROGRAM_ID,
+      parsed.emitterAddress,
+      parsed.emitterChain,
+      parsed.sequence
+    );
+  }
+
+  if (wrappedTbtcToken === undefined) {
+    wrappedTbtcToken = getWrappedTbtcTokenPDA();
+  }
+
+  if (wrappedTbtcMint === undefined) {
+    wrappedTbtcMint = WRAPPED_TBTC_MINT;
+  }
+
+  if (tbtcMint === undefined) {
+    tbtcMint = tbtc.getMintPDA();
+  }
+
+  if (recipientToken === undefined) {
+    recipientToken = getAssociatedTokenAddressSync(tbtcMint, recipient);
+  }
+
+  if (payerToken === undefined) {
+    payerToken = getAssociatedTokenAddressSync(tbtcMint, payer);
+  }
+
+  if (recipientWrappedToken == undefined) {
+    recipientWrappedToken = getAssociatedTokenAddressSync(
+      wrappedTbtcMint,
+      recipient
+    );
+  }
+
+  // The call accounts are only needed if the message names a program.
+  if (targetProgram === undefined) {
+    targetProgram = null;
+    callTarget = null;
+    callAuthority = null;
+    callEscrow = null;
+  } else {
+    if (callTarget === undefined) {
+      callTarget = getCallTargetPDA(targetProgram);
+    }
+
+    if (callAuthority === undefined) {
+      callAuthority = getCallAuthorityPDA();
+    }
+
+    if (callEscrow === undefined) {
+      callEscrow = getCallEscrowPDA();
+    }
+  }
+
+  if (tbtcConfig === undefined) {
+    tbtcConfig = tbtc.getConfigPDA();
+  }
+
+  if (tbtcMinterInfo === undefined) {
+    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  }
+
+  if (tbtcEventAuthority === undefined) {
+    tbtcEventAuthority = tbtc.getEventAuthorityPDA();
+  }
+
   if (tokenBridgeConfig === undefined) {
     tokenBridgeConfig = tokenBridge.deriveTokenBridgeConfigKey(
       TOKEN_BRIDGE_PROGRAM_ID
@@ -467,14 +1172,22 @@ export async function receiveTbtcIx(
       payer,
       custodian,
       postedVaa,
+      gatewayInfo,
+      treasuryToken,
       tokenBridgeClaim,
       wrappedTbtcToken,
       tbtcMint,
       recipientToken,
       recipient,
+      payerToken,
       recipientWrappedToken,
+      callTarget,
+      callAuthority,
+      callEscrow,
+      targetProgram,
       tbtcConfig,
       tbtcMinterInfo,
+      tbtcEventAuthority,
       wrappedTbtcMint,
       tokenBridgeConfig,
       tokenBridgeRegisteredEmitter,
@@ -486,6 +1199,7 @@ export async function receiveTbtcIx(
       tokenBridgeProgram,
       coreBridgeProgram,
     })
+    .remainingAccounts(callAccounts)
     .instruction();
 }
 
@@ -497,6 +1211,10 @@ type SendTbtcGatewayContext = {
   tbtcMint?: PublicKey;
   senderToken: PublicKey;
   sender: PublicKey;
+  treasuryToken?: PublicKey;
+  tbtcConfig?: PublicKey;
+  tbtcMinterInfo?: PublicKey;
+  tbtcEventAuthority?: PublicKey;
   tokenBridgeConfig?: PublicKey;
   tokenBridgeWrappedAsset?: PublicKey;
   tokenBridgeTransferAuthority?: PublicKey;
@@ -508,6 +1226,7 @@ type SendTbtcGatewayContext = {
   clock?: PublicKey;
   tokenBridgeSender?: PublicKey;
   rent?: PublicKey;
+  tbtcProgram?: PublicKey;
   tokenBridgeProgram?: PublicKey;
   coreBridgeProgram?: PublicKey;
 };
@@ -532,6 +1251,10 @@ export async function sendTbtcGatewayIx(
     tbtcMint,
     senderToken,
     sender,
+    treasuryToken,
+    tbtcConfig,
+    tbtcMinterInfo,
+    tbtcEventAuthority,
     tokenBridgeConfig,
     tokenBridgeWrappedAsset,
     tokenBridgeTransferAuthority,
@@ -543,6 +1266,7 @@ export async function sendTbtcGatewayIx(
     clock,
     tokenBridgeSender,
     rent,
+    tbtcProgram,
     tokenBridgeProgram,
     coreBridgeProgram,
   } = accounts;
@@ -567,6 +1291,22 @@ export async function sendTbtcGatewayIx(
     tbtcMint = tbtc.getMintPDA();
   }
 
+  if (treasuryToken === undefined) {
+    treasuryToken = getTreasuryTokenPDA();
+  }
+
+  if (tbtcConfig === undefined) {
+    tbtcConfig = tbtc.getConfigPDA();
+  }
+
+  if (tbtcMinterInfo === undefined) {
+    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  }
+
+  if (tbtcEventAuthority === undefined) {
+    tbtcEventAuthority = tbtc.getEventAuthorityPDA();
+  }
+
   if (tokenBridgeConfig === undefined) {
     tokenBridgeConfig = tokenBridge.deriveTokenBridgeConfigKey(
       TOKEN_BRIDGE_PROGRAM_ID
@@ -621,6 +1361,10 @@ export async function sendTbtcGatewayIx(
     rent = SYSVAR_RENT_PUBKEY;
   }
 
+  if (tbtcProgram === undefined) {
+    tbtcProgram = TBTC_PROGRAM_ID;
+  }
+
   if (tokenBridgeProgram === undefined) {
     tokenBridgeProgram = TOKEN_BRIDGE_PROGRAM_ID;
   }
@@ -639,6 +1383,10 @@ export async function sendTbtcGatewayIx(
       tbtcMint,
       senderToken,
       sender,
+      treasuryToken,
+      tbtcConfig,
+      tbtcMinterInfo,
+      tbtcEventAuthority,
       tokenBridgeConfig,
       tokenBridgeWrappedAsset,
       tokenBridgeTransferAuthority,
@@ -650,6 +1398,7 @@ export async function sendTbtcGatewayIx(
       clock,
       tokenBridgeSender,
       rent,
+      tbtcProgram,
       tokenBridgeProgram,
       coreBridgeProgram,
     })
@@ -658,11 +1407,16 @@ export async function sendTbtcGatewayIx(
 
 type SendTbtcWrappedContext = {
   custodian?: PublicKey;
+  gatewayInfo?: PublicKey;
   wrappedTbtcToken?: PublicKey;
   wrappedTbtcMint?: PublicKey;
   tbtcMint?: PublicKey;
   senderToken: PublicKey;
   sender: PublicKey;
+  treasuryToken?: PublicKey;
+  tbtcConfig?: PublicKey;
+  tbtcMinterInfo?: PublicKey;
+  tbtcEventAuthority?: PublicKey;
   tokenBridgeConfig?: PublicKey;
   tokenBridgeWrappedAsset?: PublicKey;
   tokenBridgeTransferAuthority?: PublicKey;
@@ -673,6 +1427,7 @@ type SendTbtcWrappedContext = {
   coreFeeCollector?: PublicKey;
   clock?: PublicKey;
   rent?: PublicKey;
+  tbtcProgram?: PublicKey;
   tokenBridgeProgram?: PublicKey;
   coreBridgeProgram?: PublicKey;
 };
@@ -692,11 +1447,16 @@ export async function sendTbtcWrappedIx(
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   let {
     custodian,
+    gatewayInfo,
     wrappedTbtcToken,
     wrappedTbtcMint,
     tbtcMint,
     senderToken,
     sender,
+    treasuryToken,
+    tbtcConfig,
+    tbtcMinterInfo,
+    tbtcEventAuthority,
     tokenBridgeConfig,
     tokenBridgeWrappedAsset,
     tokenBridgeTransferAuthority,
@@ -707,6 +1467,7 @@ export async function sendTbtcWrappedIx(
     coreFeeCollector,
     clock,
     rent,
+    tbtcProgram,
     tokenBridgeProgram,
     coreBridgeProgram,
   } = accounts;
@@ -715,6 +1476,10 @@ export async function sendTbtcWrappedIx(
     custodian = getCustodianPDA();
   }
 
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.recipientChain);
+  }
+
   if (wrappedTbtcToken === undefined) {
     wrappedTbtcToken = getWrappedTbtcTokenPDA();
   }
@@ -727,6 +1492,22 @@ export async function sendTbtcWrappedIx(
     tbtcMint = tbtc.getMintPDA();
   }
 
+  if (treasuryToken === undefined) {
+    treasuryToken = getTreasuryTokenPDA();
+  }
+
+  if (tbtcConfig === undefined) {
+    tbtcConfig = tbtc.getConfigPDA();
+  }
+
+  if (tbtcMinterInfo === undefined) {
+    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  }
+
+  if (tbtcEventAuthority === undefined) {
+    tbtcEventAuthority = tbtc.getEventAuthorityPDA();
+  }
+
   if (tokenBridgeConfig === undefined) {
     tokenBridgeConfig = tokenBridge.deriveTokenBridgeConfigKey(
       TOKEN_BRIDGE_PROGRAM_ID
@@ -775,6 +1556,10 @@ export async function sendTbtcWrappedIx(
     rent = SYSVAR_RENT_PUBKEY;
   }
 
+  if (tbtcProgram === undefined) {
+    tbtcProgram = TBTC_PROGRAM_ID;
+  }
+
   if (tokenBridgeProgram === undefined) {
     tokenBridgeProgram = TOKEN_BRIDGE_PROGRAM_ID;
   }
@@ -787,11 +1572,16 @@ export async function sendTbtcWrappedIx(
     .sendTbtcWrapped(args)
     .accounts({
       custodian,
+      gatewayInfo,
       wrappedTbtcToken,
       wrappedTbtcMint,
       tbtcMint,
       senderToken,
       sender,
+      treasuryToken,
+      tbtcConfig,
+      tbtcMinterInfo,
+      tbtcEventAuthority,
       tokenBridgeConfig,
       tokenBridgeWrappedAsset,
       tokenBridgeTransferAuthority,
@@ -802,8 +1592,377 @@ export async function sendTbtcWrappedIx(
       coreFeeCollector,
       clock,
       rent,
+      tbtcProgram,
       tokenBridgeProgram,
       coreBridgeProgram,
     })
     .instruction();
 }
+
+type MigrateCustodianContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  tokenBridgeWrappedAsset?: PublicKey;
+};
+
+export async function migrateCustodianIx(
+  accounts: MigrateCustodianContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, tokenBridgeWrappedAsset } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (tokenBridgeWrappedAsset === undefined) {
+    tokenBridgeWrappedAsset = WRAPPED_TBTC_ASSET;
+  }
+
+  return program.methods
+    .migrateCustodian()
+    .accounts({
+      custodian,
+      authority,
+      tokenBridgeWrappedAsset,
+    })
+    .instruction();
+}
+
+type UpdateTbtcTokenContext = {
+  custodian?: PublicKey;
+  wrappedTbtcToken?: PublicKey;
+  newWrappedTbtcMint?: PublicKey;
+  newWrappedTbtcToken?: PublicKey;
+  authority: PublicKey;
+};
+
+type UpdateTbtcTokenArgs = {
+  chain: number;
+  address: number[];
+};
+
+export async function updateTbtcTokenIx(
+  accounts: UpdateTbtcTokenContext,
+  args: UpdateTbtcTokenArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let {
+    custodian,
+    wrappedTbtcToken,
+    newWrappedTbtcMint,
+    newWrappedTbtcToken,
+    authority,
+  } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (wrappedTbtcToken === undefined) {
+    wrappedTbtcToken = getWrappedTbtcTokenPDA();
+  }
+
+  if (newWrappedTbtcMint === undefined) {
+    newWrappedTbtcMint = tokenBridge.deriveWrappedMintKey(
+      TOKEN_BRIDGE_PROGRAM_ID,
+      args.chain,
+      Buffer.from(args.address)
+    );
+  }
+
+  if (newWrappedTbtcToken === undefined) {
+    newWrappedTbtcToken = getUpdatedWrappedTbtcTokenPDA(newWrappedTbtcMint);
+  }
+
+  return program.methods
+    .updateTbtcToken(args)
+    .accounts({
+      custodian,
+      wrappedTbtcToken,
+      newWrappedTbtcMint,
+      newWrappedTbtcToken,
+      authority,
+    })
+    .instruction();
+}
+
+type MigrateGatewayInfoContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  gatewayInfo?: PublicKey;
+};
+
+export async function migrateGatewayInfoIx(
+  accounts: MigrateGatewayInfoContext,
+  chain: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, gatewayInfo } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(chain);
+  }
+
+  return program.methods
+    .migrateGatewayInfo(chain)
+    .accounts({
+      custodian,
+      authority,
+      gatewayInfo,
+    })
+    .instruction();
+}
+
+type InitializeMultisigContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  multisig?: PublicKey;
+  multisigAuthority?: PublicKey;
+};
+
+type InitializeMultisigArgs = {
+  signers: PublicKey[];
+  threshold: number;
+};
+
+export async function initializeMultisigIx(
+  accounts: InitializeMultisigContext,
+  args: InitializeMultisigArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, multisig, multisigAuthority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  if (multisigAuthority === undefined) {
+    multisigAuthority = getMultisigAuthorityPDA();
+  }
+
+  return program.methods
+    .initializeMultisig(args)
+    .accounts({
+      custodian,
+      authority,
+      multisig,
+      multisigAuthority,
+    })
+    .instruction();
+}
+
+type CreateProposalContext = {
+  multisig?: PublicKey;
+  proposal?: PublicKey;
+  proposer: PublicKey;
+};
+
+export async function createProposalIx(
+  accounts: CreateProposalContext,
+  action: any
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { multisig, proposal, proposer } = accounts;
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  if (proposal === undefined) {
+    const { numProposals } = await getMultisigData();
+    proposal = getProposalPDA(numProposals);
+  }
+
+  return program.methods
+    .createProposal(action)
+    .accounts({
+      multisig,
+      proposal,
+      proposer,
+    })
+    .instruction();
+}
+
+type ApproveProposalContext = {
+  multisig?: PublicKey;
+  proposal: PublicKey;
+  signer: PublicKey;
+};
+
+export async function approveProposalIx(
+  accounts: ApproveProposalContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { multisig, proposal, signer } = accounts;
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  return program.methods
+    .approveProposal()
+    .accounts({
+      multisig,
+      proposal,
+      signer,
+    })
+    .instruction();
+}
+
+type ExecuteProposalContext = {
+  multisig?: PublicKey;
+  proposal: PublicKey;
+  proposer: PublicKey;
+  multisigAuthority?: PublicKey;
+};
+
+export async function executeProposalIx(
+  accounts: ExecuteProposalContext,
+  remainingAccounts: AccountMeta[]
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { multisig, proposal, proposer, multisigAuthority } = accounts;
+  if (multisig === undefined) {
+    multisig = getMultisigPDA();
+  }
+
+  if (multisigAuthority === undefined) {
+    multisigAuthority = getMultisigAuthorityPDA();
+  }
+
+  return program.methods
+    .executeProposal()
+    .accounts({
+      multisig,
+      proposal,
+      proposer,
+      multisigAuthority,
+      program: WORMHOLE_GATEWAY_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+}
+
+type UpdateTimelockDelayContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function updateTimelockDelayIx(
+  accounts: UpdateTimelockDelayContext,
+  delay: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  return program.methods
+    .updateTimelockDelay(delay)
+    .accounts({
+      custodian,
+      authority,
+    })
+    .instruction();
+}
+
+type ScheduleChangeContext = {
+  custodian?: PublicKey;
+  scheduledChange?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function scheduleChangeIx(
+  accounts: ScheduleChangeContext,
+  action: any
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, scheduledChange, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (scheduledChange === undefined) {
+    const { numScheduledChanges } = await getCustodianData();
+    scheduledChange = getScheduledChangePDA(numScheduledChanges);
+  }
+
+  return program.methods
+    .scheduleChange(action)
+    .accounts({
+      custodian,
+      scheduledChange,
+      authority,
+    })
+    .instruction();
+}
+
+type CancelScheduledChangeContext = {
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+  scheduledChange: PublicKey;
+  scheduledBy: PublicKey;
+};
+
+export async function cancelScheduledChangeIx(
+  accounts: CancelScheduledChangeContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { guardianInfo, guardian, scheduledChange, scheduledBy } = accounts;
+  if (guardianInfo === undefined) {
+    guardianInfo = tbtc.getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .cancelScheduledChange()
+    .accounts({
+      guardianInfo,
+      guardian,
+      scheduledChange,
+      scheduledBy,
+    })
+    .instruction();
+}
+
+type ExecuteScheduledChangeContext = {
+  scheduledChange: PublicKey;
+  scheduledBy: PublicKey;
+  timelockAuthority?: PublicKey;
+};
+
+export async function executeScheduledChangeIx(
+  accounts: ExecuteScheduledChangeContext,
+  remainingAccounts: AccountMeta[]
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { scheduledChange, scheduledBy, timelockAuthority } = accounts;
+  if (timelockAuthority === undefined) {
+    timelockAuthority = getTimelockAuthorityPDA();
+  }
+
+  return program.methods
+    .executeScheduledChange()
+    .accounts({
+      scheduledChange,
+      scheduledBy,
+      timelockAuthority,
+      program: WORMHOLE_GATEWAY_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+}